@@ -0,0 +1,76 @@
+/// Name patterns of the lib60870 API passed to bindgen's allowlist, which keeps
+/// libc functions and platform constants out of the bindings. Types the
+/// matching items depend on are included automatically.
+const LIB60870_API_PATTERNS: &[&str] = &[
+    // Protocol API
+    "CS101_.*",
+    "CS104_.*",
+    "eCS104_.*",
+    "IEC60870_.*",
+    "IEC_60870_.*",
+    "IMasterConnection.*",
+    "LinkLayer.*",
+    "Frame.*",
+    "LIB60870_.*",
+    "Lib60870.*",
+    "TLS.*",
+    // HAL
+    "Socket.*",
+    "ServerSocket.*",
+    "TcpSocket.*",
+    "TcpServerSocket.*",
+    "UdpSocket.*",
+    "Handleset.*",
+    "HandleSet.*",
+    "SerialPort.*",
+    "Thread.*",
+    "Semaphore.*",
+    "Hal_.*",
+    "(ms|ns)SinceEpoch",
+    // Information objects and their value types
+    "InformationObject.*",
+    "TypeID.*",
+    "QualityDescriptor.*",
+    "Qualifier.*",
+    "StartEvent.*",
+    "OutputCircuitInfo.*",
+    "CauseOfInitialization.*",
+    "SelectAndCallQualifier.*",
+    "SetpointCommandQualifier.*",
+    "DoublePointValue.*",
+    "EventState.*",
+    "StepCommandValue.*",
+    "SingleEvent.*",
+    "StatusAndStatusChangeDetection.*",
+    "NormalizedValue.*",
+    "BinaryCounterReading.*",
+    "CP(16|24|32|56)Time2a.*",
+    "SinglePoint.*",
+    "DoublePoint.*",
+    "StepPosition.*",
+    "BitString32.*",
+    "Bitstring32.*",
+    "MeasuredValue.*",
+    "IntegratedTotals.*",
+    "EventOfProtectionEquipment.*",
+    "PackedStartEventsOfProtectionEquipment.*",
+    "PackedOutputCircuitInfo.*",
+    "PackedSinglePointWithSCD.*",
+    "SingleCommand.*",
+    "DoubleCommand.*",
+    "StepCommand.*",
+    "SetpointCommand.*",
+    "InterrogationCommand.*",
+    "CounterInterrogationCommand.*",
+    "ReadCommand.*",
+    "ClockSynchronizationCommand.*",
+    "TestCommand.*",
+    "ResetProcessCommand.*",
+    "DelayAcquisitionCommand.*",
+    "Parameter(Normalized|Scaled|Float)Value.*",
+    "ParameterActivation.*",
+    "EndOfInitialization.*",
+    "File(Ready|CallOrSelect|LastSegmentOrSection|ACK|Segment|Directory).*",
+    "SectionReady.*",
+    "QueryLog.*",
+];
@@ -1,8 +1,10 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::env;
 use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use flate2::read::GzDecoder;
+use sha2::{Digest, Sha256};
 
 /// Custom ParseCallbacks to convert Doxygen documentation to Rustdoc
 #[derive(Debug)]
@@ -17,96 +19,400 @@ impl bindgen::callbacks::ParseCallbacks for DoxygenCallbacks {
 const LIB60870_VERSION: &str = "2.3.6";
 const LIB60870_URL: &str =
     "https://github.com/mz-automation/lib60870/archive/refs/tags/v2.3.6.tar.gz";
+/// SHA-256 of the archive at `LIB60870_URL`, checked before extraction.
+/// `LIB60870_SHA256` replaces it for archives fetched from `LIB60870_SYS_MIRROR`.
+const LIB60870_SHA256: &str = "";
+
+/// Functions that an installed lib60870 must declare and define to be usable
+/// with these bindings.
+const REQUIRED_API_SYMBOLS: &[&str] = &[
+    "Lib60870_getLibraryVersionInfo",
+    "CS101_ASDU_getElementEx",
+    "CS104_Connection_createSecure",
+    "CS104_Slave_startThreadless",
+    "CS101_Slave_addPlugin",
+];
 
 const MBEDTLS_VERSION: &str = "2.28.9";
 const MBEDTLS_URL: &str = "https://github.com/Mbed-TLS/mbedtls/archive/refs/tags/v2.28.9.tar.gz";
+/// SHA-256 of the archive at `MBEDTLS_URL`, checked before extraction.
+/// `MBEDTLS_SHA256` replaces it for archives fetched from `LIB60870_SYS_MIRROR`.
+const MBEDTLS_SHA256: &str = "";
+
+// LIB60870_API_PATTERNS, shared with tests/api_surface.rs
+include!("bindgen_allowlist.rs");
 
 fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let target = env::var("TARGET").unwrap();
+
     // On docs.rs, skip the native build entirely - we use pre-generated bindings
     // docs.rs sets DOCS_RS=1 and has no network access
     if env::var("DOCS_RS").is_ok() {
         println!("cargo:warning=Skipping native build on docs.rs (using pre-generated bindings)");
-        // Emit the docsrs cfg flag so rustc knows to use pregenerated bindings
+        // Emit the docsrs cfg flag for conditional documentation
         println!("cargo:rustc-cfg=docsrs");
+        use_pregenerated_bindings(&target, &out_dir);
         return;
     }
 
-    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let tls_enabled = env::var("CARGO_FEATURE_TLS").is_ok();
+    let should_update_pregenerated_bindings =
+        env::var("LIB60870_SYS_UPDATE_PREGENERATED_BINDINGS").is_ok();
+    // Feature: pregenerated - skip bindgen (and libclang) entirely
+    let use_pregenerated =
+        env::var("CARGO_FEATURE_PREGENERATED").is_ok() && !should_update_pregenerated_bindings;
+
+    let include_dirs = match probe_system_lib60870(&out_dir) {
+        // Link the installed library and use its headers
+        Some(system) => {
+            assert!(
+                env::var("CARGO_FEATURE_RUST_HAL").is_err(),
+                "The `rust-hal` feature needs lib60870 built from source; a system \
+                 lib60870 already contains the C HAL"
+            );
+            // The options lib60870 was compiled with are not known here
+            println!("cargo:config=unknown");
+            system.include_dirs
+        }
+        None => build_from_source(&out_dir, tls_enabled),
+    };
+
+    if use_pregenerated {
+        use_pregenerated_bindings(&target, &out_dir);
+    } else {
+        generate_bindings(&include_dirs, &out_dir, tls_enabled);
+    }
+
+    // Platform-specific link libraries
+    for lib in platform_link_libs() {
+        println!("cargo:rustc-link-lib={}", lib);
+    }
+
+    // Rerun if build.rs or wrapper.h changes
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=wrapper.h");
+    println!("cargo:rerun-if-changed=bindgen_allowlist.rs");
+
+    println!("cargo:rerun-if-env-changed=LIB60870_SYS_UPDATE_PREGENERATED_BINDINGS");
+    println!("cargo:rerun-if-env-changed=DOCS_RS");
+    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_TLS");
+    println!("cargo:rerun-if-env-changed=LIB60870_SRC_DIR");
+    println!("cargo:rerun-if-env-changed=MBEDTLS_SRC_DIR");
+    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_SYSTEM");
+    println!("cargo:rerun-if-env-changed=LIB60870_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=LIB60870_INCLUDE_DIR");
+    println!("cargo:rerun-if-env-changed=LIB60870_STATIC");
+    println!("cargo:rerun-if-env-changed=LIB60870_SYS_MIRROR");
+    println!("cargo:rerun-if-env-changed=LIB60870_SHA256");
+    println!("cargo:rerun-if-env-changed=MBEDTLS_SHA256");
+
+    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_PREGENERATED");
+    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_RUST_HAL");
+
+    if should_update_pregenerated_bindings {
+        println!(
+            "cargo:warning=Updating pre-generated bindings for {}",
+            target
+        );
+        // Copy bindings.rs to src/bindings/<target>.rs
+        let source_path = out_dir.join("bindings.rs");
+        let target_path = pregenerated_bindings_dir().join(format!("{}.rs", target));
+        std::fs::copy(source_path, &target_path).unwrap_or_else(|e| {
+            panic!(
+                "Failed to copy bindings.rs to {}: {}",
+                target_path.display(),
+                e
+            )
+        });
+    }
+}
+
+/// System libraries lib60870 depends on for the target.
+fn platform_link_libs() -> &'static [&'static str] {
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+    let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+    match target_os.as_str() {
+        // musl provides pthread, rt and m as part of libc itself
+        "linux" if target_env == "musl" => &[],
+        "linux" => &["pthread", "rt", "m"],
+        // Bionic has pthread and clock functions in libc
+        "android" => &["m"],
+        "macos" => &["pthread"],
+        "windows" => &["ws2_32", "iphlpapi", "bcrypt"],
+        _ => &[],
+    }
+}
+
+fn pregenerated_bindings_dir() -> PathBuf {
+    manifest_dir().join("src/bindings")
+}
+
+/// Copies the pre-generated bindings shipped for `target` to `OUT_DIR/bindings.rs`.
+fn use_pregenerated_bindings(target: &str, out_dir: &Path) {
+    let bindings_dir = pregenerated_bindings_dir();
+    let path = bindings_dir.join(format!("{}.rs", target));
+
+    if !path.exists() {
+        let available: Vec<String> = std::fs::read_dir(&bindings_dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                name.strip_suffix(".rs").map(str::to_string)
+            })
+            .collect();
+        panic!(
+            "No pre-generated bindings for target {} (available: {}). Disable the \
+             `pregenerated` feature to generate them with bindgen.",
+            target,
+            available.join(", ")
+        );
+    }
+
+    println!("cargo:rerun-if-changed={}", path.display());
+    std::fs::copy(&path, out_dir.join("bindings.rs"))
+        .expect("Failed to copy pre-generated bindings");
+}
+
+/// Builds lib60870 from source and returns the include directories for bindgen.
+fn build_from_source(out_dir: &Path, tls_enabled: bool) -> Vec<PathBuf> {
     let lib60870_dir = out_dir.join(format!("lib60870-{}", LIB60870_VERSION));
 
-    // Download and extract lib60870 if not already present
-    if !lib60870_dir.exists() {
-        download_and_extract_lib60870(&out_dir, &lib60870_dir);
+    // Use a local source tree if one is configured, otherwise download
+    // and extract lib60870 if not already present
+    if let Some(src_dir) = local_lib60870_source() {
+        copy_local_lib60870(&src_dir, &lib60870_dir);
+    } else if !lib60870_dir.exists() {
+        download_and_extract_lib60870(out_dir, &lib60870_dir);
     }
 
     let lib60870_c_dir = lib60870_dir.join("lib60870-C");
-    let tls_enabled = env::var("CARGO_FEATURE_TLS").is_ok();
 
     // Download and setup mbedtls if TLS feature is enabled
     if tls_enabled {
         let mbedtls_target = lib60870_c_dir.join("dependencies/mbedtls-2.28");
-        if !mbedtls_target.exists() {
-            download_and_extract_mbedtls(&out_dir, &mbedtls_target);
+        if let Some(src_dir) = local_mbedtls_source() {
+            copy_local_mbedtls(&src_dir, &mbedtls_target);
+        } else if !mbedtls_target.exists() {
+            download_and_extract_mbedtls(out_dir, &mbedtls_target);
         }
     }
 
+    // Feature: rust-hal - src/hal provides the platform HAL instead
+    if env::var("CARGO_FEATURE_RUST_HAL").is_ok() {
+        remove_platform_hal(&lib60870_c_dir);
+    }
+
     // Build lib60870 with cmake
     let dst = build_lib60870(&lib60870_c_dir, tls_enabled);
 
-    // Generate bindings
-    generate_bindings(&lib60870_c_dir, &out_dir, tls_enabled);
-
     // Link instructions
     println!("cargo:rustc-link-search=native={}/lib", dst.display());
     println!("cargo:rustc-link-lib=static=lib60870");
 
-    // Platform-specific link libraries
-    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
-    match target_os.as_str() {
-        "linux" => {
-            println!("cargo:rustc-link-lib=pthread");
-            println!("cargo:rustc-link-lib=rt");
-            println!("cargo:rustc-link-lib=m");
+    source_include_dirs(&lib60870_c_dir, tls_enabled)
+}
+
+/// A lib60870 installation provided by the system instead of built from source.
+struct SystemLib60870 {
+    include_dirs: Vec<PathBuf>,
+    link_dirs: Vec<PathBuf>,
+    /// Libraries to link, lib60870 and whatever it depends on
+    libs: Vec<String>,
+}
+
+/// Looks for an installed lib60870 to link against.
+///
+/// Explicit `LIB60870_LIB_DIR`/`LIB60870_INCLUDE_DIR` variables win over
+/// pkg-config, which is only consulted when the `system` feature is enabled.
+/// `LIB60870_STATIC` selects static (`1`) or dynamic (`0`) linking; the
+/// default is static for `LIB60870_LIB_DIR` and dynamic for pkg-config,
+/// which usually finds a shared library.
+fn probe_system_lib60870(out_dir: &Path) -> Option<SystemLib60870> {
+    let link_static = env::var("LIB60870_STATIC").ok().map(|v| v != "0");
+    let lib_dir = env::var_os("LIB60870_LIB_DIR").map(PathBuf::from);
+    let include_dir = env::var_os("LIB60870_INCLUDE_DIR").map(PathBuf::from);
+
+    let system = if lib_dir.is_some() || include_dir.is_some() {
+        let lib_dir = lib_dir.expect("LIB60870_INCLUDE_DIR is set but LIB60870_LIB_DIR is not");
+        let include_dir = include_dir.unwrap_or_else(|| lib_dir.join("../include"));
+
+        // Prefer the version recorded by the installed pkg-config file, if any
+        let pc_file = lib_dir.join("pkgconfig/lib60870.pc");
+        if let Ok(pc) = std::fs::read_to_string(&pc_file) {
+            if let Some(version) = pc.lines().find_map(|l| l.strip_prefix("Version:")) {
+                check_system_version(version.trim());
+            }
         }
-        "macos" => {
-            println!("cargo:rustc-link-lib=pthread");
+
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+        println!(
+            "cargo:rustc-link-lib={}=lib60870",
+            if link_static.unwrap_or(true) {
+                "static"
+            } else {
+                "dylib"
+            }
+        );
+
+        SystemLib60870 {
+            include_dirs: header_dirs(&include_dir),
+            link_dirs: vec![lib_dir],
+            libs: vec!["lib60870".to_string()],
         }
-        "windows" => {
-            println!("cargo:rustc-link-lib=ws2_32");
-            println!("cargo:rustc-link-lib=iphlpapi");
-            println!("cargo:rustc-link-lib=bcrypt");
+    } else if env::var("CARGO_FEATURE_SYSTEM").is_ok() {
+        let library = pkg_config::Config::new()
+            .statik(link_static.unwrap_or(false))
+            .probe("lib60870")
+            .unwrap_or_else(|e| {
+                panic!(
+                    "The `system` feature is enabled but lib60870 was not found via \
+                     pkg-config. Install its development files or set LIB60870_LIB_DIR \
+                     and LIB60870_INCLUDE_DIR.\n{}",
+                    e
+                )
+            });
+        check_system_version(&library.version);
+
+        SystemLib60870 {
+            include_dirs: library
+                .include_paths
+                .iter()
+                .flat_map(|d| header_dirs(d))
+                .collect(),
+            link_dirs: library.link_paths,
+            libs: library.libs,
         }
-        _ => {}
+    } else {
+        return None;
+    };
+
+    check_system_api(&system, out_dir);
+    println!("cargo:warning=Using system lib60870");
+    if env::vars().any(|(key, _)| key.starts_with("LIB60870_CONFIG_")) {
+        println!("cargo:warning=LIB60870_CONFIG_* options are ignored for a system lib60870");
     }
+    Some(system)
+}
 
-    // Rerun if build.rs or wrapper.h changes
-    println!("cargo:rerun-if-changed=build.rs");
-    println!("cargo:rerun-if-changed=wrapper.h");
+/// Installed headers live either directly in the include directory or in a
+/// `lib60870` subdirectory of it.
+fn header_dirs(include_dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = vec![include_dir.to_path_buf()];
+    let nested = include_dir.join("lib60870");
+    if nested.is_dir() {
+        dirs.push(nested);
+    }
+    dirs
+}
 
-    println!("cargo:rerun-if-env-changed=LIB60870_SYS_UPDATE_PREGENERATED_BINDINGS");
-    println!("cargo:rerun-if-env-changed=DOCS_RS");
-    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_TLS");
+fn check_system_version(version: &str) {
+    let api_series = LIB60870_VERSION.rsplit_once('.').unwrap().0;
+    let matches = version == api_series || version.starts_with(&format!("{}.", api_series));
+    assert!(
+        matches,
+        "Installed lib60870 is version {}, but lib60870-sys requires the {}.x API \
+         (bindings are generated against v{}). Install a matching version or build \
+         from source by unsetting LIB60870_LIB_DIR and disabling the `system` feature.",
+        version, api_series, LIB60870_VERSION
+    );
+}
 
-    let should_update_pregenerated_bindings =
-        env::var("LIB60870_SYS_UPDATE_PREGENERATED_BINDINGS").is_ok();
-    if should_update_pregenerated_bindings {
-        println!("cargo:warning=Updating pre-generated bindings");
-        // Copy bindings.rs to src/bindings_pregenerated.rs
-        let source_path = out_dir.join("bindings.rs");
-        let target_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
-            .join("src/bindings_pregenerated.rs");
-        std::fs::copy(source_path, target_path)
-            .expect("Failed to copy bindings.rs to src/bindings_pregenerated.rs");
+/// Checks that the installed headers declare and the library defines the API
+/// these bindings are built around, which catches mismatches when no version
+/// information is available.
+///
+/// A small program referencing every function of `REQUIRED_API_SYMBOLS` is
+/// compiled against the headers and linked against the library.
+fn check_system_api(system: &SystemLib60870, out_dir: &Path) {
+    assert!(
+        system
+            .include_dirs
+            .iter()
+            .any(|dir| dir.join("cs104_connection.h").exists()),
+        "No lib60870 headers found in {:?}; set LIB60870_INCLUDE_DIR to the directory \
+         containing cs104_connection.h",
+        system.include_dirs
+    );
+
+    let probe_dir = out_dir.join("api-probe");
+    std::fs::create_dir_all(&probe_dir).expect("Failed to create the API probe directory");
+    let source = probe_dir.join("probe.c");
+    let mut program = String::from("#include \"wrapper.h\"\n\nint main(void)\n{\n");
+    program.push_str("    const void *volatile symbols[] = {\n");
+    for symbol in REQUIRED_API_SYMBOLS {
+        program.push_str(&format!("        (const void *) &{},\n", symbol));
+    }
+    program.push_str("    };\n    return symbols[0] == 0;\n}\n");
+    std::fs::write(&source, program).expect("Failed to write the API probe");
+
+    let compiler = cc::Build::new().cargo_metadata(false).get_compiler();
+    let mut command = compiler.to_command();
+    // wrapper.h lives in the crate root
+    let manifest_dir = manifest_dir();
+    let include_dirs = system.include_dirs.iter().chain([&manifest_dir]);
+    let libs = system
+        .libs
+        .iter()
+        .map(String::as_str)
+        .chain(platform_link_libs().iter().copied());
+    if compiler.is_like_msvc() {
+        for dir in include_dirs {
+            command.arg(format!("/I{}", dir.display()));
+        }
+        command
+            .arg(&source)
+            .arg(format!("/Fe{}", probe_dir.join("probe.exe").display()))
+            .arg(format!("/Fo{}", probe_dir.join("probe.obj").display()))
+            .arg("/link");
+        for dir in &system.link_dirs {
+            command.arg(format!("/LIBPATH:{}", dir.display()));
+        }
+        command.args(libs.map(|lib| format!("{}.lib", lib)));
+    } else {
+        for dir in include_dirs {
+            command.arg(format!("-I{}", dir.display()));
+        }
+        command.arg(&source).arg("-o").arg(probe_dir.join("probe"));
+        for dir in &system.link_dirs {
+            command.arg(format!("-L{}", dir.display()));
+        }
+        command.args(libs.map(|lib| format!("-l{}", lib)));
     }
+
+    let output = command
+        .output()
+        .unwrap_or_else(|e| panic!("Failed to run the C compiler for the API probe: {}", e));
+    assert!(
+        output.status.success(),
+        "Installed lib60870 does not provide the API expected by lib60870-sys (v{}): \
+         compiling and linking a program using {} failed:\n{}",
+        LIB60870_VERSION,
+        REQUIRED_API_SYMBOLS.join(", "),
+        String::from_utf8_lossy(&output.stderr)
+    );
 }
 
-fn download_and_extract_tarball(url: &str, out_dir: &Path) {
-    let tarball_data = ureq::get(url)
-        .call()
-        .expect("Failed to download tarball")
-        .into_body()
-        .read_to_vec()
-        .expect("Failed to read response");
+/// A source archive downloaded by the build script.
+struct Tarball {
+    /// File name used when fetching from `LIB60870_SYS_MIRROR`
+    file_name: String,
+    url: &'static str,
+    /// Pinned SHA-256 digest (lowercase hex)
+    sha256: &'static str,
+    /// Environment variable with the digest of the mirrored archive
+    sha256_env: &'static str,
+}
+
+fn download_and_extract_tarball(tarball: &Tarball, out_dir: &Path) {
+    // Resolve the digest first so a misconfiguration fails before any download
+    let expected_sha256 = expected_sha256(tarball);
+    let tarball_data = fetch_tarball(tarball);
+
+    // Refuse to unpack anything that does not match the expected digest
+    verify_sha256(tarball, &expected_sha256, &tarball_data);
 
     // Decompress gzip
     let tar_data = {
@@ -125,9 +431,70 @@ fn download_and_extract_tarball(url: &str, out_dir: &Path) {
         .expect("Failed to extract tar archive");
 }
 
+/// Fetches a tarball from its upstream URL, or from `LIB60870_SYS_MIRROR` if set.
+///
+/// The mirror is a base URL (`https://`, `http://` or `file://`) that serves the
+/// archives under their `Tarball::file_name`.
+fn fetch_tarball(tarball: &Tarball) -> Vec<u8> {
+    let url = match env::var("LIB60870_SYS_MIRROR") {
+        Ok(mirror) => format!("{}/{}", mirror.trim_end_matches('/'), tarball.file_name),
+        Err(_) => tarball.url.to_string(),
+    };
+
+    if let Some(path) = url.strip_prefix("file://") {
+        return std::fs::read(path)
+            .unwrap_or_else(|e| panic!("Failed to read tarball {}: {}", path, e));
+    }
+
+    ureq::get(&url)
+        .call()
+        .expect("Failed to download tarball")
+        .into_body()
+        .read_to_vec()
+        .expect("Failed to read response")
+}
+
+/// Returns the digest the archive must match.
+fn expected_sha256(tarball: &Tarball) -> String {
+    // A digest from the environment only describes a mirrored archive; the
+    // upstream archive is always checked against the pinned digest
+    let mirrored = env::var_os("LIB60870_SYS_MIRROR").is_some();
+    match env::var(tarball.sha256_env) {
+        Ok(digest) if mirrored => digest.trim().to_ascii_lowercase(),
+        Ok(_) => panic!(
+            "{} only applies to archives fetched from LIB60870_SYS_MIRROR; unset it \
+             or set LIB60870_SYS_MIRROR",
+            tarball.sha256_env
+        ),
+        Err(_) if !tarball.sha256.is_empty() => tarball.sha256.to_string(),
+        Err(_) => panic!(
+            "No SHA-256 digest pinned for {}; set LIB60870_SYS_MIRROR and {} to a \
+             mirror of the archive and its digest, or use LIB60870_SRC_DIR",
+            tarball.file_name, tarball.sha256_env
+        ),
+    }
+}
+
+fn verify_sha256(tarball: &Tarball, expected: &str, data: &[u8]) {
+    let actual = format!("{:x}", Sha256::digest(data));
+    if actual != expected {
+        panic!(
+            "SHA-256 mismatch for {}: expected {}, got {}. The archive may have been \
+             tampered with; refusing to build from it.",
+            tarball.file_name, expected, actual
+        );
+    }
+}
+
 fn download_and_extract_lib60870(out_dir: &Path, lib60870_dir: &Path) {
     println!("cargo:warning=Downloading lib60870 v{}", LIB60870_VERSION);
-    download_and_extract_tarball(LIB60870_URL, out_dir);
+    let tarball = Tarball {
+        file_name: format!("lib60870-{}.tar.gz", LIB60870_VERSION),
+        url: LIB60870_URL,
+        sha256: LIB60870_SHA256,
+        sha256_env: "LIB60870_SHA256",
+    };
+    download_and_extract_tarball(&tarball, out_dir);
 
     assert!(
         lib60870_dir.exists(),
@@ -137,7 +504,13 @@ fn download_and_extract_lib60870(out_dir: &Path, lib60870_dir: &Path) {
 
 fn download_and_extract_mbedtls(out_dir: &Path, mbedtls_target: &Path) {
     println!("cargo:warning=Downloading mbedtls v{}", MBEDTLS_VERSION);
-    download_and_extract_tarball(MBEDTLS_URL, out_dir);
+    let tarball = Tarball {
+        file_name: format!("mbedtls-{}.tar.gz", MBEDTLS_VERSION),
+        url: MBEDTLS_URL,
+        sha256: MBEDTLS_SHA256,
+        sha256_env: "MBEDTLS_SHA256",
+    };
+    download_and_extract_tarball(&tarball, out_dir);
 
     // mbedtls extracts as mbedtls-2.28.9, but lib60870 expects mbedtls-2.28
     let mbedtls_extracted = out_dir.join(format!("mbedtls-{}", MBEDTLS_VERSION));
@@ -156,6 +529,151 @@ fn download_and_extract_mbedtls(out_dir: &Path, mbedtls_target: &Path) {
         .expect("Failed to move mbedtls to dependencies directory");
 }
 
+/// Returns the local lib60870 source tree to build from (`LIB60870_SRC_DIR`), if any.
+fn local_lib60870_source() -> Option<PathBuf> {
+    env::var_os("LIB60870_SRC_DIR").map(PathBuf::from)
+}
+
+/// Returns the local mbedtls source tree to build from (`MBEDTLS_SRC_DIR`), if any.
+fn local_mbedtls_source() -> Option<PathBuf> {
+    env::var_os("MBEDTLS_SRC_DIR").map(PathBuf::from)
+}
+
+fn copy_local_lib60870(src_dir: &Path, lib60870_dir: &Path) {
+    // Accept both a checkout of the whole repository and its lib60870-C subdirectory
+    let lib60870_c_src = if src_dir.join("lib60870-C/CMakeLists.txt").exists() {
+        src_dir.join("lib60870-C")
+    } else if src_dir.join("CMakeLists.txt").exists() && src_dir.join("src/inc/api").exists() {
+        src_dir.to_path_buf()
+    } else {
+        panic!(
+            "No lib60870-C source tree found at {} (set LIB60870_SRC_DIR to a lib60870 v{} \
+             checkout)",
+            src_dir.display(),
+            LIB60870_VERSION
+        );
+    };
+
+    println!(
+        "cargo:warning=Using local lib60870 sources from {}",
+        lib60870_c_src.display()
+    );
+    println!("cargo:rerun-if-changed={}", lib60870_c_src.display());
+
+    // Copy into OUT_DIR so the source tree itself is never modified (mbedtls is
+    // placed inside it below, and cargo's registry copy must stay pristine)
+    if lib60870_dir.exists() {
+        std::fs::remove_dir_all(lib60870_dir).expect("Failed to remove stale lib60870 copy");
+    }
+    copy_dir_all(&lib60870_c_src, &lib60870_dir.join("lib60870-C"))
+        .expect("Failed to copy local lib60870 sources");
+}
+
+fn copy_local_mbedtls(src_dir: &Path, mbedtls_target: &Path) {
+    assert!(
+        src_dir.join("include/mbedtls").exists(),
+        "No mbedtls source tree found at {} (set MBEDTLS_SRC_DIR to an mbedtls v{} \
+         checkout)",
+        src_dir.display(),
+        MBEDTLS_VERSION
+    );
+
+    println!(
+        "cargo:warning=Using local mbedtls sources from {}",
+        src_dir.display()
+    );
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    if mbedtls_target.exists() {
+        std::fs::remove_dir_all(mbedtls_target).expect("Failed to remove stale mbedtls copy");
+    }
+    copy_dir_all(src_dir, mbedtls_target).expect("Failed to copy local mbedtls sources");
+}
+
+fn copy_dir_all(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        // Skip VCS metadata of local checkouts
+        if file_name == ".git" {
+            continue;
+        }
+        if entry.path().is_dir() {
+            copy_dir_all(&entry.path(), &dst.join(&file_name))?;
+        } else {
+            std::fs::copy(entry.path(), dst.join(&file_name))?;
+        }
+    }
+    Ok(())
+}
+
+/// The directories under `src/hal` whose sources the `rust-hal` feature
+/// replaces. The memory and TLS parts of the HAL are kept.
+const PLATFORM_HAL_DIRS: &[&str] = &["socket", "serial", "thread", "time"];
+
+/// Marks a `src/CMakeLists.txt` that has already been stripped.
+const PLATFORM_HAL_REMOVED: &str = "# platform HAL removed by lib60870-sys (rust-hal)";
+
+/// Drops the socket, serial port, thread and time sources of every platform
+/// from the library sources, so their symbols resolve to the Rust HAL.
+fn remove_platform_hal(lib60870_c_dir: &Path) {
+    let cmake_lists = lib60870_c_dir.join("src/CMakeLists.txt");
+    let original = std::fs::read_to_string(&cmake_lists)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", cmake_lists.display(), e));
+    if original.starts_with(PLATFORM_HAL_REMOVED) {
+        return;
+    }
+
+    let is_platform_hal = |token: &str| {
+        let source = token.trim_end_matches(')');
+        source.ends_with(".c")
+            && PLATFORM_HAL_DIRS
+                .iter()
+                .any(|dir| source.contains(&format!("hal/{}/", dir)))
+    };
+    let mut removed = 0;
+    let mut stripped = format!("{}\n", PLATFORM_HAL_REMOVED);
+    for line in original.lines() {
+        if !line.split_whitespace().any(is_platform_hal) {
+            stripped.push_str(line);
+            stripped.push('\n');
+            continue;
+        }
+        // Keep the rest of the line, e.g. `set (` or the `)` closing a list
+        let kept: Vec<&str> = line
+            .split_whitespace()
+            .map(|token| {
+                if is_platform_hal(token) {
+                    removed += 1;
+                    &token[token.trim_end_matches(')').len()..]
+                } else {
+                    token
+                }
+            })
+            .filter(|token| !token.is_empty())
+            .collect();
+        stripped.push_str(&kept.join(" "));
+        stripped.push('\n');
+    }
+    assert!(
+        removed > 0,
+        "No platform HAL sources found in {}",
+        cmake_lists.display()
+    );
+
+    println!(
+        "cargo:warning=Building lib60870 without its platform HAL ({} sources replaced by rust-hal)",
+        removed
+    );
+    std::fs::write(&cmake_lists, stripped)
+        .unwrap_or_else(|e| panic!("Failed to write {}: {}", cmake_lists.display(), e));
+}
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+}
+
 fn build_lib60870(lib60870_c_dir: &Path, tls_enabled: bool) -> PathBuf {
     let mut config = cmake::Config::new(lib60870_c_dir);
 
@@ -173,55 +691,337 @@ fn build_lib60870(lib60870_c_dir: &Path, tls_enabled: bool) -> PathBuf {
         config.define("CMAKE_MSVC_RUNTIME_LIBRARY", "MultiThreaded");
     }
 
+    // Cross builds: a toolchain file takes over compiler and sysroot selection.
+    // Without one, the cmake crate picks the compiler through cc-rs, which
+    // honors CC_<target>/TARGET_CC, and sets CMAKE_SYSTEM_NAME for the target.
+    if let Some(toolchain_file) = target_env_var("CMAKE_TOOLCHAIN_FILE") {
+        config.define("CMAKE_TOOLCHAIN_FILE", toolchain_file);
+    }
+    // Rebuild when the cross toolchain configuration changes
+    for var in ["CC", "CFLAGS", "AR"] {
+        target_env_var(var);
+    }
+
+    // Compile-time options from config/lib60870_config.h. The overrides are
+    // written into the header, which is what the C sources read, and the
+    // resulting values of all options are exported to dependent crates as
+    // DEP_LIB60870_SYS_CONFIG_* metadata
+    println!("cargo:config=source");
+    let overrides = lib60870_config(lib60870_c_dir);
+    for (name, value) in &overrides {
+        config.define(name, value);
+    }
+    for (name, value) in write_config_header(lib60870_c_dir, &overrides) {
+        println!("cargo:{}={}", name.to_lowercase(), value);
+    }
+
+    // Feature: TLS support
+    // Note: cmake will auto-detect mbedtls in dependencies/mbedtls-2.28
+    if tls_enabled {
+        println!("cargo:warning=Building lib60870 with TLS support");
+    }
+
+    config.build()
+}
+
+/// Compile-time options that have a dedicated entry in the README, tracked for
+/// rebuilds along with every option defined in config/lib60870_config.h. Any
+/// other `LIB60870_CONFIG_*` variable is forwarded as well.
+const KNOWN_CONFIG_OPTIONS: &[&str] = &[
+    "DEBUG_OUTPUT",
+    "USE_THREADS",
+    "USE_SEMAPHORES",
+    "ACTIVATE_TCP_KEEPALIVE",
+    "TCP_KEEPALIVE_IDLE",
+    "TCP_KEEPALIVE_INTERVAL",
+    "TCP_KEEPALIVE_CNT",
+    "CS104_MAX_CLIENT_CONNECTIONS",
+    "SLAVE_MESSAGE_QUEUE_SIZE",
+    "SLAVE_WITH_STATIC_MESSAGE_QUEUE",
+    "CS104_MESSAGE_QUEUE_HIGH_PRIO_SIZE",
+    "CS104_SLAVE_POOL",
+    "CS104_SUPPORT_SERVER_MODE_SINGLE_REDUNDANCY_GROUP",
+    "CS104_SUPPORT_SERVER_MODE_MULTIPLE_REDUNDANCY_GROUPS",
+    "CS104_SUPPORT_SERVER_MODE_CONNECTION_IS_REDUNDANCY_GROUP",
+];
+
+/// The options defined in config/lib60870_config.h of the source tree, such
+/// as the CS101/CS104 switches and the maximum ASDU size, without the
+/// `CONFIG_` prefix.
+fn header_config_options(lib60870_c_dir: &Path) -> Vec<String> {
+    let header = lib60870_c_dir.join("config").join("lib60870_config.h");
+    // The options of upstream, before any were added by write_config_header
+    let upstream = header.with_extension(format!("h.{}", UPSTREAM_CONFIG_SUFFIX));
+    let header = if upstream.exists() { upstream } else { header };
+    let Ok(contents) = std::fs::read_to_string(header) else {
+        return Vec::new();
+    };
+    config_defines(&contents)
+        .filter_map(|(_, define)| define)
+        .filter_map(|(name, _)| name.strip_prefix("CONFIG_").map(str::to_string))
+        .collect()
+}
+
+/// The lines of config/lib60870_config.h, each with the option it defines
+/// and its value, e.g. `("CONFIG_USE_THREADS", Some("1"))`. The include
+/// guard of the header, a define without value right after its `#ifndef`,
+/// is not an option.
+#[allow(clippy::type_complexity)]
+fn config_defines(
+    contents: &str,
+) -> impl Iterator<Item = (&str, Option<(String, Option<String>)>)> {
+    let mut previous = "";
+    contents.lines().map(move |line| {
+        let define = line.trim_start().strip_prefix("#define").and_then(|rest| {
+            let mut parts = rest.split_whitespace();
+            let name = parts.next().filter(|name| name.starts_with("CONFIG_"))?;
+            let value = parts.next().map(str::to_string);
+            let is_guard = value.is_none() && previous.split_whitespace().eq(["#ifndef", name]);
+            (!is_guard).then(|| (name.to_string(), value))
+        });
+        if !line.trim().is_empty() {
+            previous = line;
+        }
+        (line, define)
+    })
+}
+
+/// Suffix of the unmodified config/lib60870_config.h, kept next to the
+/// generated one so that every build starts from the upstream defaults.
+const UPSTREAM_CONFIG_SUFFIX: &str = "upstream";
+
+/// Writes `overrides` into config/lib60870_config.h and returns the value of
+/// every option the C sources see.
+///
+/// Options defined by the header get the new value in place; others are
+/// appended. Known options the header does not define are reported as `0`,
+/// which is how the preprocessor evaluates them.
+fn write_config_header(
+    lib60870_c_dir: &Path,
+    overrides: &BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+    let header = lib60870_c_dir.join("config").join("lib60870_config.h");
+    let upstream = header.with_extension(format!("h.{}", UPSTREAM_CONFIG_SUFFIX));
+    if !upstream.exists() {
+        std::fs::copy(&header, &upstream)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", header.display(), e));
+    }
+    let original = std::fs::read_to_string(&upstream)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", upstream.display(), e));
+
+    let mut effective = BTreeMap::new();
+    let mut generated = String::new();
+    for (line, define) in config_defines(&original) {
+        match define {
+            Some((name, value)) => {
+                let value = overrides.get(&name).cloned().or(value);
+                match &value {
+                    Some(value) if overrides.contains_key(&name) => {
+                        generated.push_str(&format!("#define {} {}\n", name, value));
+                    }
+                    _ => {
+                        generated.push_str(line);
+                        generated.push('\n');
+                    }
+                }
+                effective.insert(name, value.unwrap_or_else(|| "1".to_string()));
+            }
+            None => {
+                generated.push_str(line);
+                generated.push('\n');
+            }
+        }
+    }
+
+    let appended: Vec<(&String, &String)> = overrides
+        .iter()
+        .filter(|(name, _)| !effective.contains_key(*name))
+        .collect();
+    if !appended.is_empty() {
+        generated.push_str("\n/* Options set through LIB60870_CONFIG_* (lib60870-sys) */\n");
+        for (name, value) in appended {
+            generated.push_str(&format!("#undef {}\n#define {} {}\n", name, name, value));
+            effective.insert(name.clone(), value.clone());
+        }
+    }
+    for name in KNOWN_CONFIG_OPTIONS {
+        effective
+            .entry(format!("CONFIG_{}", name))
+            .or_insert_with(|| "0".to_string());
+    }
+
+    // Keep the modification time when nothing changed, so cmake does not
+    // rebuild the library
+    if std::fs::read_to_string(&header).ok().as_deref() != Some(generated.as_str()) {
+        std::fs::write(&header, generated)
+            .unwrap_or_else(|e| panic!("Failed to write {}: {}", header.display(), e));
+    }
+    effective
+}
+
+/// Collects the lib60870 compile-time options to pass to cmake.
+///
+/// Cargo features provide defaults; a `LIB60870_CONFIG_<NAME>` environment
+/// variable overrides them and is passed as `CONFIG_<NAME>`.
+fn lib60870_config(lib60870_c_dir: &Path) -> BTreeMap<String, String> {
+    let mut config = BTreeMap::new();
+    let mut set = |name: &str, value: &str| {
+        config.insert(format!("CONFIG_{}", name), value.to_string());
+    };
+
     // Feature: debug output
     if env::var("CARGO_FEATURE_DEBUG").is_ok() {
-        config.define("CONFIG_DEBUG_OUTPUT", "1");
+        set("DEBUG_OUTPUT", "1");
     }
 
     // Feature: no-threads
     if env::var("CARGO_FEATURE_NO_THREADS").is_ok() {
-        config.define("CONFIG_USE_THREADS", "0");
-        config.define("CONFIG_USE_SEMAPHORES", "0");
+        set("USE_THREADS", "0");
+        set("USE_SEMAPHORES", "0");
     }
 
     // Feature: tcp-keepalive
     if env::var("CARGO_FEATURE_TCP_KEEPALIVE").is_ok() {
-        config.define("CONFIG_ACTIVATE_TCP_KEEPALIVE", "1");
+        set("ACTIVATE_TCP_KEEPALIVE", "1");
     }
 
-    // Feature: TLS support
-    // Note: cmake will auto-detect mbedtls in dependencies/mbedtls-2.28
-    if tls_enabled {
-        println!("cargo:warning=Building lib60870 with TLS support");
+    // Feature: static-message-queue
+    if env::var("CARGO_FEATURE_STATIC_MESSAGE_QUEUE").is_ok() {
+        set("SLAVE_WITH_STATIC_MESSAGE_QUEUE", "1");
     }
 
-    config.build()
+    // Feature: cs104-slave-pool
+    if env::var("CARGO_FEATURE_CS104_SLAVE_POOL").is_ok() {
+        set("CS104_SLAVE_POOL", "1");
+    }
+
+    let header_options = header_config_options(lib60870_c_dir);
+    let tracked: BTreeSet<&str> = KNOWN_CONFIG_OPTIONS
+        .iter()
+        .copied()
+        .chain(header_options.iter().map(String::as_str))
+        .collect();
+    for name in tracked {
+        println!("cargo:rerun-if-env-changed=LIB60870_CONFIG_{}", name);
+    }
+
+    for (key, value) in env::vars() {
+        if let Some(name) = key.strip_prefix("LIB60870_CONFIG_") {
+            assert!(
+                !name.is_empty() && !value.is_empty(),
+                "Invalid lib60870 config override {}={:?}",
+                key,
+                value
+            );
+            println!("cargo:rerun-if-env-changed={}", key);
+            set(name, &value);
+        }
+    }
+
+    config
+}
+
+/// Reads a per-target environment variable the way cc-rs does: `VAR_<target>`,
+/// `VAR_<target_with_underscores>`, `TARGET_VAR`/`HOST_VAR`, then `VAR`.
+fn target_env_var(var: &str) -> Option<String> {
+    let target = env::var("TARGET").unwrap();
+    let host = env::var("HOST").unwrap();
+    let kind = if target == host { "HOST" } else { "TARGET" };
+
+    let candidates = [
+        format!("{}_{}", var, target),
+        format!("{}_{}", var, target.replace('-', "_")),
+        format!("{}_{}", kind, var),
+        var.to_string(),
+    ];
+    for name in &candidates {
+        println!("cargo:rerun-if-env-changed={}", name);
+    }
+    candidates.iter().find_map(|name| env::var(name).ok())
+}
+
+/// Maps a Rust target triple to the triple clang expects.
+fn clang_target(target: &str) -> String {
+    // Rust encodes RISC-V extensions in the architecture name, clang does not
+    if let Some(rest) = target.strip_prefix("riscv64gc-") {
+        return format!("riscv64-{}", rest);
+    }
+    if let Some(rest) = target.strip_prefix("riscv32imac-") {
+        return format!("riscv32-{}", rest);
+    }
+    target.to_string()
+}
+
+/// Clang arguments selecting the target triple and, when cross compiling, its
+/// sysroot.
+///
+/// The sysroot comes from `LIB60870_SYSROOT` (or its per-target variants), or
+/// else is queried from the cross compiler configured through `CC_<target>`.
+fn target_clang_args() -> Vec<String> {
+    let target = env::var("TARGET").unwrap();
+    let host = env::var("HOST").unwrap();
+
+    let mut args = vec![format!("--target={}", clang_target(&target))];
+
+    let sysroot = target_env_var("LIB60870_SYSROOT").or_else(|| {
+        if target == host {
+            return None;
+        }
+        let compiler = target_env_var("CC")?;
+        let output = std::process::Command::new(&compiler)
+            .arg("-print-sysroot")
+            .output()
+            .ok()?;
+        let sysroot = String::from_utf8(output.stdout).ok()?.trim().to_string();
+        (output.status.success() && !sysroot.is_empty() && sysroot != "/").then_some(sysroot)
+    });
+    if let Some(sysroot) = sysroot {
+        args.push(format!("--sysroot={}", sysroot));
+    }
+
+    args
 }
 
-fn generate_bindings(lib60870_c_dir: &Path, out_dir: &Path, tls_enabled: bool) {
-    let api_inc = lib60870_c_dir.join("src/inc/api");
-    let hal_inc = lib60870_c_dir.join("src/hal/inc");
-    let common_inc = lib60870_c_dir.join("src/common/inc");
-    let config_dir = lib60870_c_dir.join("config");
+/// Include directories of a lib60870-C source tree.
+fn source_include_dirs(lib60870_c_dir: &Path, tls_enabled: bool) -> Vec<PathBuf> {
+    let mut dirs = vec![
+        lib60870_c_dir.join("src/inc/api"),
+        lib60870_c_dir.join("src/hal/inc"),
+        lib60870_c_dir.join("src/common/inc"),
+        lib60870_c_dir.join("config"),
+    ];
+
+    // Add TLS-related include paths
+    if tls_enabled {
+        dirs.push(lib60870_c_dir.join("src/hal/tls/mbedtls"));
+        dirs.push(lib60870_c_dir.join("dependencies/mbedtls-2.28/include"));
+    }
+
+    dirs
+}
 
-    let wrapper_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("wrapper.h");
+fn generate_bindings(include_dirs: &[PathBuf], out_dir: &Path, tls_enabled: bool) {
+    let wrapper_path = manifest_dir().join("wrapper.h");
 
     let mut builder = bindgen::Builder::default()
         .header(wrapper_path.to_str().unwrap())
-        .clang_arg(format!("-I{}", api_inc.display()))
-        .clang_arg(format!("-I{}", hal_inc.display()))
-        .clang_arg(format!("-I{}", common_inc.display()))
-        .clang_arg(format!("-I{}", config_dir.display()));
+        // Parse the headers for the target, not the host
+        .clang_args(target_clang_args());
+
+    for dir in include_dirs {
+        builder = builder.clang_arg(format!("-I{}", dir.display()));
+    }
 
-    // Add TLS-related include paths and defines
     if tls_enabled {
-        let tls_mbedtls_inc = lib60870_c_dir.join("src/hal/tls/mbedtls");
-        let mbedtls_inc = lib60870_c_dir.join("dependencies/mbedtls-2.28/include");
+        builder = builder.clang_arg("-DCONFIG_CS104_SUPPORT_TLS=1");
+    }
 
+    // Only expose the lib60870 API, not the libc/platform symbols its headers pull in
+    for pattern in LIB60870_API_PATTERNS {
         builder = builder
-            .clang_arg(format!("-I{}", tls_mbedtls_inc.display()))
-            .clang_arg(format!("-I{}", mbedtls_inc.display()))
-            .clang_arg("-DCONFIG_CS104_SUPPORT_TLS=1");
+            .allowlist_function(pattern)
+            .allowlist_type(pattern)
+            .allowlist_var(pattern);
     }
 
     let bindings = builder
@@ -0,0 +1,324 @@
+//! Application service data units.
+//!
+//! [`Asdu`] owns a `CS101_ASDU` and destroys it on drop. ASDUs handed to
+//! callbacks by the library are only borrowed and show up as
+//! [`&AsduRef`](AsduRef), which must not outlive the callback.
+
+use std::fmt;
+use std::ptr;
+
+use foreign_types::{foreign_type, ForeignType, ForeignTypeRef};
+
+use crate::bindings::*;
+use crate::error::{Error, Result};
+use crate::information_object::InformationObject;
+use crate::parameters::AppLayerParameters;
+use crate::types::{CauseOfTransmission, TypeId};
+
+foreign_type! {
+    /// An owned ASDU.
+    pub unsafe type Asdu: Send + Sync {
+        type CType = sCS101_ASDU;
+        fn drop = CS101_ASDU_destroy;
+        fn clone = clone_asdu;
+    }
+
+    /// An owned information object decoded from an ASDU.
+    pub unsafe type Element: Send + Sync {
+        type CType = sInformationObject;
+        fn drop = InformationObject_destroy;
+    }
+}
+
+/// Copies an ASDU into a heap allocated static ASDU.
+///
+/// `CS101_ASDU_clone` copies the parameters pointer as is. ASDUs received by
+/// a connection point at parameters owned by that connection, so the clone is
+/// repointed to an interned copy that lives as long as the clone.
+unsafe fn clone_asdu(asdu: *mut sCS101_ASDU) -> *mut sCS101_ASDU {
+    let clone = CS101_ASDU_clone(asdu, ptr::null_mut());
+    assert!(!clone.is_null(), "CS101_ASDU_clone failed");
+
+    let params = asdu_parameters(asdu);
+    (*clone.cast::<sCS101_StaticASDU>()).parameters = params.interned() as *const _ as *mut _;
+    clone
+}
+
+/// Reads the parameters an ASDU was created or decoded with.
+///
+/// Every ASDU starts with the same header as `sCS101_StaticASDU`.
+unsafe fn asdu_parameters(asdu: *mut sCS101_ASDU) -> AppLayerParameters {
+    AppLayerParameters::from(&*(*asdu.cast::<sCS101_StaticASDU>()).parameters)
+}
+
+impl Asdu {
+    /// Creates an empty ASDU.
+    ///
+    /// The originator address is taken from `parameters`. The type id is set
+    /// by the first information object added to the ASDU.
+    pub fn new(
+        parameters: &AppLayerParameters,
+        cot: CauseOfTransmission,
+        common_address: u16,
+    ) -> Self {
+        unsafe {
+            let asdu = CS101_ASDU_create(
+                parameters.interned() as *const _ as *mut _,
+                false,
+                cot as CS101_CauseOfTransmission,
+                parameters.originator_address.into(),
+                common_address.into(),
+                false,
+                false,
+            );
+            assert!(!asdu.is_null(), "CS101_ASDU_create failed");
+            Asdu::from_ptr(asdu)
+        }
+    }
+}
+
+impl AsduRef {
+    /// The application layer parameters of the ASDU.
+    pub fn parameters(&self) -> AppLayerParameters {
+        unsafe { asdu_parameters(self.as_ptr()) }
+    }
+
+    /// The type identification, or `None` if it is not a standard one.
+    pub fn type_id(&self) -> Option<TypeId> {
+        TypeId::try_from(self.raw_type_id()).ok()
+    }
+
+    /// The type identification as transmitted.
+    pub fn raw_type_id(&self) -> u8 {
+        unsafe { CS101_ASDU_getTypeID(self.as_ptr()) as u8 }
+    }
+
+    /// Sets the type identification.
+    pub fn set_type_id(&mut self, type_id: TypeId) {
+        unsafe { CS101_ASDU_setTypeID(self.as_ptr(), type_id as IEC60870_5_TypeID) }
+    }
+
+    /// The cause of transmission, or `None` if it is not a standard one.
+    pub fn cot(&self) -> Option<CauseOfTransmission> {
+        CauseOfTransmission::try_from(self.raw_cot()).ok()
+    }
+
+    /// The cause of transmission as transmitted.
+    pub fn raw_cot(&self) -> u8 {
+        unsafe { CS101_ASDU_getCOT(self.as_ptr()) as u8 }
+    }
+
+    /// Sets the cause of transmission.
+    pub fn set_cot(&mut self, cot: CauseOfTransmission) {
+        unsafe { CS101_ASDU_setCOT(self.as_ptr(), cot as CS101_CauseOfTransmission) }
+    }
+
+    /// The originator address, or 0 if the COT field has no room for it.
+    pub fn originator_address(&self) -> u8 {
+        unsafe { CS101_ASDU_getOA(self.as_ptr()) as u8 }
+    }
+
+    /// The common address.
+    pub fn common_address(&self) -> u16 {
+        unsafe { CS101_ASDU_getCA(self.as_ptr()) as u16 }
+    }
+
+    /// Sets the common address.
+    pub fn set_common_address(&mut self, common_address: u16) {
+        unsafe { CS101_ASDU_setCA(self.as_ptr(), common_address.into()) }
+    }
+
+    /// Whether the test flag is set.
+    pub fn is_test(&self) -> bool {
+        unsafe { CS101_ASDU_isTest(self.as_ptr()) }
+    }
+
+    /// Sets the test flag.
+    pub fn set_test(&mut self, value: bool) {
+        unsafe { CS101_ASDU_setTest(self.as_ptr(), value) }
+    }
+
+    /// Whether the negative (P/N) flag is set.
+    pub fn is_negative(&self) -> bool {
+        unsafe { CS101_ASDU_isNegative(self.as_ptr()) }
+    }
+
+    /// Sets the negative (P/N) flag.
+    pub fn set_negative(&mut self, value: bool) {
+        unsafe { CS101_ASDU_setNegative(self.as_ptr(), value) }
+    }
+
+    /// Whether the information objects are encoded as a sequence with
+    /// consecutive addresses (SQ = 1).
+    pub fn is_sequence(&self) -> bool {
+        unsafe { CS101_ASDU_isSequence(self.as_ptr()) }
+    }
+
+    /// Sets the sequence (SQ) flag.
+    pub fn set_sequence(&mut self, value: bool) {
+        unsafe { CS101_ASDU_setSequence(self.as_ptr(), value) }
+    }
+
+    /// The number of information objects in the ASDU.
+    pub fn len(&self) -> usize {
+        unsafe { CS101_ASDU_getNumberOfElements(self.as_ptr()) as usize }
+    }
+
+    /// Whether the ASDU contains no information objects.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The encoded payload following the ASDU header.
+    pub fn payload(&self) -> &[u8] {
+        unsafe {
+            let payload = CS101_ASDU_getPayload(self.as_ptr());
+            let size = CS101_ASDU_getPayloadSize(self.as_ptr()) as usize;
+            if payload.is_null() || size == 0 {
+                &[]
+            } else {
+                std::slice::from_raw_parts(payload, size)
+            }
+        }
+    }
+
+    /// Decodes the information object at `index`.
+    ///
+    /// Returns `None` if the index is out of range or the element cannot be
+    /// decoded.
+    pub fn element(&self, index: usize) -> Option<Element> {
+        if index >= self.len() {
+            return None;
+        }
+        unsafe {
+            let io = CS101_ASDU_getElementEx(self.as_ptr(), ptr::null_mut(), index as i32);
+            (!io.is_null()).then(|| Element::from_ptr(io))
+        }
+    }
+
+    /// Iterates over the information objects of the ASDU.
+    ///
+    /// Iteration stops at the first element that cannot be decoded.
+    pub fn elements(&self) -> Elements<'_> {
+        Elements {
+            asdu: self,
+            index: 0,
+        }
+    }
+
+    /// Iterates over the typed information objects of the ASDU.
+    pub fn objects(&self) -> impl Iterator<Item = Result<InformationObject>> + '_ {
+        self.elements().map(|element| element.to_object())
+    }
+
+    /// Appends an information object.
+    ///
+    /// The first object determines the type id of the ASDU. Fails with
+    /// [`Error::AsduFull`] if the object does not fit, has a different type
+    /// or, in a sequence ASDU, a non-consecutive address.
+    pub fn add_object(&mut self, object: &InformationObject) -> Result<()> {
+        let io = object.to_raw()?;
+        let added = unsafe {
+            let added = CS101_ASDU_addInformationObject(self.as_ptr(), io);
+            InformationObject_destroy(io);
+            added
+        };
+        if added {
+            Ok(())
+        } else {
+            Err(Error::AsduFull)
+        }
+    }
+}
+
+impl fmt::Debug for AsduRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Asdu")
+            .field("type_id", &self.raw_type_id())
+            .field("cot", &self.raw_cot())
+            .field("originator_address", &self.originator_address())
+            .field("common_address", &self.common_address())
+            .field("is_test", &self.is_test())
+            .field("is_negative", &self.is_negative())
+            .field("is_sequence", &self.is_sequence())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl fmt::Debug for Asdu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<'a> IntoIterator for &'a AsduRef {
+    type Item = Element;
+    type IntoIter = Elements<'a>;
+
+    fn into_iter(self) -> Elements<'a> {
+        self.elements()
+    }
+}
+
+/// Iterator over the information objects of an ASDU.
+///
+/// Created by [`AsduRef::elements`].
+pub struct Elements<'a> {
+    asdu: &'a AsduRef,
+    index: usize,
+}
+
+impl Iterator for Elements<'_> {
+    type Item = Element;
+
+    fn next(&mut self) -> Option<Element> {
+        let element = self.asdu.element(self.index);
+        self.index = match element {
+            Some(_) => self.index + 1,
+            None => usize::MAX,
+        };
+        element
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.asdu.len().saturating_sub(self.index)))
+    }
+}
+
+impl ElementRef {
+    /// The information object address.
+    pub fn address(&self) -> u32 {
+        unsafe { InformationObject_getObjectAddress(self.as_ptr()) as u32 }
+    }
+
+    /// The type identification of the information object.
+    pub fn type_id(&self) -> Option<TypeId> {
+        TypeId::try_from(self.raw_type_id()).ok()
+    }
+
+    /// The type identification of the information object as a number.
+    pub fn raw_type_id(&self) -> u8 {
+        unsafe { InformationObject_getType(self.as_ptr()) as u8 }
+    }
+
+    /// Converts the element to a typed information object.
+    pub fn to_object(&self) -> Result<InformationObject> {
+        unsafe { InformationObject::from_raw(self.as_ptr()) }
+    }
+}
+
+impl fmt::Debug for ElementRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Element")
+            .field("type_id", &self.raw_type_id())
+            .field("address", &self.address())
+            .finish()
+    }
+}
+
+impl fmt::Debug for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
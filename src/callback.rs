@@ -0,0 +1,23 @@
+//! Plumbing for Rust closures called by lib60870.
+//!
+//! Handlers are boxed twice: the outer box gives the trait object a thin,
+//! stable address that is passed to C as the `parameter` of the callback, and
+//! the owner keeps it alive until lib60870 no longer calls it.
+
+use std::os::raw::c_void;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Runs a handler, returning `fallback` instead of unwinding into C.
+///
+/// The panic message is still printed by the panic hook.
+pub(crate) fn guard<R>(fallback: R, f: impl FnOnce() -> R) -> R {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(fallback)
+}
+
+/// The `parameter` pointer for a boxed handler, or null if there is none.
+pub(crate) fn parameter<T: ?Sized>(handler: &mut Option<Box<Box<T>>>) -> *mut c_void {
+    match handler {
+        Some(handler) => &mut **handler as *mut Box<T> as *mut c_void,
+        None => std::ptr::null_mut(),
+    }
+}
@@ -0,0 +1,624 @@
+//! CS101 master (controlling station).
+
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use foreign_types::ForeignTypeRef;
+
+use crate::asdu::{Asdu, AsduRef};
+use crate::bindings::*;
+use crate::callback::{self, guard};
+use crate::cs101::{LinkLayerMode, LinkLayerState, SerialPort};
+use crate::error::{Error, Result};
+use crate::file::{self, FileLink, FileReceiver};
+use crate::information_object::{FileDirectory, InformationObject};
+use crate::parameters::{AppLayerParameters, LinkLayerParameters};
+use crate::timestamp::Cp56Time2a;
+use crate::types::CauseOfTransmission;
+
+type AsduHandler = Box<dyn FnMut(u16, &AsduRef) + Send>;
+type LinkStateHandler = Box<dyn FnMut(u16, LinkLayerState) + Send>;
+type RawMessageHandler = Box<dyn FnMut(&[u8], bool) + Send>;
+
+/// How long a file transfer waits for each response of the slave by
+/// default, the default t1 of CS104.
+const FILE_RESPONSE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// How often a file transfer of a started master checks for responses
+/// and, in unbalanced mode, polls the slave.
+const FILE_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Link states reported by lib60870, shared between the callback and the
+/// master.
+struct LinkStates {
+    states: Mutex<BTreeMap<u16, LinkLayerState>>,
+    handler: Option<LinkStateHandler>,
+}
+
+/// Builder for a [`Cs101Master`].
+///
+/// Created by [`Cs101Master::builder`].
+pub struct Cs101MasterBuilder {
+    port: SerialPort,
+    mode: LinkLayerMode,
+    link_layer: LinkLayerParameters,
+    app_layer: AppLayerParameters,
+    queue_size: Option<usize>,
+    own_address: Option<u16>,
+    dir: Option<bool>,
+    idle_timeout: Option<Duration>,
+    file_response_timeout: Option<Duration>,
+    slaves: Vec<u16>,
+    on_asdu: Option<AsduHandler>,
+    on_link_state_changed: Option<LinkStateHandler>,
+    on_raw_message: Option<RawMessageHandler>,
+}
+
+impl Cs101MasterBuilder {
+    /// Sets the link layer parameters.
+    pub fn link_layer_parameters(mut self, parameters: LinkLayerParameters) -> Self {
+        self.link_layer = parameters;
+        self
+    }
+
+    /// Sets the application layer parameters.
+    pub fn app_layer_parameters(mut self, parameters: AppLayerParameters) -> Self {
+        self.app_layer = parameters;
+        self
+    }
+
+    /// Sets the number of ASDUs the send queue can hold (balanced mode only).
+    pub fn queue_size(mut self, size: usize) -> Self {
+        self.queue_size = Some(size);
+        self
+    }
+
+    /// Sets the own link address (balanced mode only).
+    pub fn own_address(mut self, address: u16) -> Self {
+        self.own_address = Some(address);
+        self
+    }
+
+    /// Sets the DIR bit of sent messages (balanced mode only). Defaults to
+    /// `true`, the controlling station.
+    pub fn dir(mut self, dir: bool) -> Self {
+        self.dir = Some(dir);
+        self
+    }
+
+    /// Sets the time without activity after which the link is considered
+    /// idle (balanced mode only).
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how long a file transfer waits for each response of the slave.
+    /// Defaults to 15 seconds.
+    pub fn file_response_timeout(mut self, timeout: Duration) -> Self {
+        self.file_response_timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a slave with the given link address (unbalanced mode).
+    pub fn slave(mut self, address: u16) -> Self {
+        self.slaves.push(address);
+        self
+    }
+
+    /// Sets the handler for received ASDUs, called with the link address of
+    /// the sending slave.
+    pub fn on_asdu(mut self, handler: impl FnMut(u16, &AsduRef) + Send + 'static) -> Self {
+        self.on_asdu = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets the handler for link state changes, called with the link
+    /// address of the slave.
+    pub fn on_link_state_changed(
+        mut self,
+        handler: impl FnMut(u16, LinkLayerState) + Send + 'static,
+    ) -> Self {
+        self.on_link_state_changed = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets the handler for every frame sent (`true`) or received (`false`),
+    /// mostly useful for logging.
+    pub fn on_raw_message(mut self, handler: impl FnMut(&[u8], bool) + Send + 'static) -> Self {
+        self.on_raw_message = Some(Box::new(handler));
+        self
+    }
+
+    /// Opens the serial port and creates the master.
+    pub fn build(mut self) -> Result<Cs101Master> {
+        self.port.open()?;
+
+        let mut link_layer = sLinkLayerParameters::from(self.link_layer);
+        let mut app_layer = sCS101_AppLayerParameters::from(self.app_layer);
+        // both are copied by lib60870
+        let raw = unsafe {
+            match self.queue_size {
+                Some(size) => CS101_Master_createEx(
+                    self.port.as_raw(),
+                    &mut link_layer,
+                    &mut app_layer,
+                    self.mode.to_raw(),
+                    size.min(c_int::MAX as usize) as c_int,
+                ),
+                None => CS101_Master_create(
+                    self.port.as_raw(),
+                    &mut link_layer,
+                    &mut app_layer,
+                    self.mode.to_raw(),
+                ),
+            }
+        };
+        assert!(!raw.is_null(), "CS101_Master_create failed");
+
+        // file transfers see the ASDUs after the handler
+        let files = Arc::new(FileReceiver::default());
+        let mut user_on_asdu = self.on_asdu;
+        let asdu_files = Arc::clone(&files);
+        let on_asdu: AsduHandler = Box::new(move |address, asdu: &AsduRef| {
+            if let Some(handler) = &mut user_on_asdu {
+                handler(address, asdu);
+            }
+            asdu_files.forward(asdu);
+        });
+
+        let mut master = Cs101Master {
+            raw,
+            mode: self.mode,
+            link_states: Box::new(LinkStates {
+                states: Mutex::new(BTreeMap::new()),
+                handler: self.on_link_state_changed,
+            }),
+            on_asdu: Some(Box::new(on_asdu)),
+            on_raw_message: self.on_raw_message.map(Box::new),
+            files,
+            file_response_timeout: self.file_response_timeout.unwrap_or(FILE_RESPONSE_TIMEOUT),
+            slave_address: Cell::new(None),
+            is_started: false,
+            _port: self.port,
+        };
+        unsafe {
+            if let Some(address) = self.own_address {
+                CS101_Master_setOwnAddress(raw, address.into());
+            }
+            if let Some(dir) = self.dir {
+                CS101_Master_setDIR(raw, dir);
+            }
+            if let Some(timeout) = self.idle_timeout {
+                let ms = timeout.as_millis().min(c_int::MAX as u128) as c_int;
+                CS101_Master_setIdleTimeout(raw, ms);
+            }
+            CS101_Master_setLinkLayerStateChanged(
+                raw,
+                Some(link_state_changed),
+                &mut *master.link_states as *mut LinkStates as *mut c_void,
+            );
+            if master.on_asdu.is_some() {
+                CS101_Master_setASDUReceivedHandler(
+                    raw,
+                    Some(asdu_received),
+                    callback::parameter(&mut master.on_asdu),
+                );
+            }
+            if master.on_raw_message.is_some() {
+                CS101_Master_setRawMessageHandler(
+                    raw,
+                    Some(raw_message),
+                    callback::parameter(&mut master.on_raw_message),
+                );
+            }
+        }
+        for address in self.slaves {
+            master.add_slave(address);
+        }
+        Ok(master)
+    }
+}
+
+impl fmt::Debug for Cs101MasterBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cs101MasterBuilder")
+            .field("port", &self.port)
+            .field("mode", &self.mode)
+            .field("link_layer", &self.link_layer)
+            .field("app_layer", &self.app_layer)
+            .field("slaves", &self.slaves)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A CS101 master on a serial line.
+///
+/// The master owns its serial port and handlers and releases them after the
+/// link layer has been stopped and destroyed. The protocol runs either on a
+/// background thread started with `start` or on the caller's thread by
+/// calling [`run_once`](Self::run_once) in a loop. `start` and `stop` are
+/// not available with the `no-threads` feature.
+///
+/// In unbalanced mode every slave has to be added and polled for data with
+/// [`poll`](Self::poll); commands go to the slave selected with
+/// [`use_slave_address`](Self::use_slave_address).
+///
+/// ```no_run
+/// use lib60870_sys::cs101::{Cs101Master, LinkLayerMode, Parity, SerialPort};
+/// use lib60870_sys::CauseOfTransmission;
+///
+/// let port = SerialPort::new("/dev/ttyUSB0", 9600, Parity::Even)?;
+/// let mut master = Cs101Master::builder(port, LinkLayerMode::Unbalanced)
+///     .slave(1)
+///     .on_asdu(|address, asdu| println!("{address}: {asdu:?}"))
+///     .build()?;
+///
+/// master.use_slave_address(1);
+/// master.send_interrogation(CauseOfTransmission::Activation, 1, 20);
+/// for _ in 0..1000 {
+///     master.poll(1);
+///     master.run_once();
+/// }
+/// # Ok::<(), lib60870_sys::Error>(())
+/// ```
+pub struct Cs101Master {
+    raw: CS101_Master,
+    mode: LinkLayerMode,
+    link_states: Box<LinkStates>,
+    on_asdu: Option<Box<AsduHandler>>,
+    on_raw_message: Option<Box<RawMessageHandler>>,
+    files: Arc<FileReceiver>,
+    file_response_timeout: Duration,
+    // the slave selected with use_slave_address, polled by file transfers
+    slave_address: Cell<Option<u16>>,
+    is_started: bool,
+    // dropped after the master that reads and writes it
+    _port: SerialPort,
+}
+
+// The master and its handlers are used by one thread at a time: the caller
+// of `run_once` or the background thread.
+unsafe impl Send for Cs101Master {}
+
+impl Cs101Master {
+    /// Starts building a master that takes ownership of `port`.
+    pub fn builder(port: SerialPort, mode: LinkLayerMode) -> Cs101MasterBuilder {
+        Cs101MasterBuilder {
+            port,
+            mode,
+            link_layer: LinkLayerParameters::default(),
+            app_layer: AppLayerParameters::default(),
+            queue_size: None,
+            own_address: None,
+            dir: None,
+            idle_timeout: None,
+            file_response_timeout: None,
+            slaves: Vec::new(),
+            on_asdu: None,
+            on_link_state_changed: None,
+            on_raw_message: None,
+        }
+    }
+
+    /// The link layer mode.
+    pub fn mode(&self) -> LinkLayerMode {
+        self.mode
+    }
+
+    /// Adds a slave with the given link address (unbalanced mode).
+    pub fn add_slave(&self, address: u16) {
+        unsafe { CS101_Master_addSlave(self.raw, address.into()) }
+    }
+
+    /// Requests class 2 data from a slave (unbalanced mode). Slaves only
+    /// send data when polled, so this has to be called regularly for each
+    /// of them.
+    pub fn poll(&self, address: u16) {
+        unsafe { CS101_Master_pollSingleSlave(self.raw, address.into()) }
+    }
+
+    /// Selects the slave the following commands are sent to. Required in
+    /// unbalanced mode, and in balanced mode for slaves that check the
+    /// link address.
+    pub fn use_slave_address(&self, address: u16) {
+        self.slave_address.set(Some(address));
+        unsafe { CS101_Master_useSlaveAddress(self.raw, address.into()) }
+    }
+
+    /// The last reported state of the link to a slave, or `None` if no
+    /// state has been reported yet.
+    pub fn link_state(&self, address: u16) -> Option<LinkLayerState> {
+        let states = self.link_states.states.lock();
+        let states = states.unwrap_or_else(|e| e.into_inner());
+        states.get(&address).copied()
+    }
+
+    /// Whether the slave can take another ASDU, i.e. its transmit buffer is
+    /// empty (unbalanced mode).
+    pub fn is_channel_ready(&self, address: u16) -> bool {
+        unsafe { CS101_Master_isChannelReady(self.raw, address.into()) }
+    }
+
+    /// Receives a message and runs the link layer state machines once.
+    ///
+    /// Has to be called frequently when the master is not started, and does
+    /// nothing while it is, as the background thread runs them then.
+    pub fn run_once(&mut self) {
+        if !self.is_started {
+            unsafe { CS101_Master_run(self.raw) }
+        }
+    }
+
+    /// Runs the master on a background thread until [`stop`](Self::stop) is
+    /// called or the master is dropped.
+    #[cfg(not(feature = "no-threads"))]
+    pub fn start(&mut self) {
+        if !self.is_started {
+            unsafe { CS101_Master_start(self.raw) };
+            self.is_started = true;
+        }
+    }
+
+    /// Stops the background thread, waiting for it to finish.
+    #[cfg(not(feature = "no-threads"))]
+    pub fn stop(&mut self) {
+        if self.is_started {
+            unsafe { CS101_Master_stop(self.raw) };
+            self.is_started = false;
+        }
+    }
+
+    /// The link layer parameters in use.
+    pub fn link_layer_parameters(&self) -> LinkLayerParameters {
+        unsafe { LinkLayerParameters::from(&*CS101_Master_getLinkLayerParameters(self.raw)) }
+    }
+
+    /// The application layer parameters in use.
+    pub fn app_layer_parameters(&self) -> AppLayerParameters {
+        unsafe { AppLayerParameters::from(&*CS101_Master_getAppLayerParameters(self.raw)) }
+    }
+
+    /// Sends the link layer test function.
+    pub fn send_link_layer_test(&self) {
+        unsafe { CS101_Master_sendLinkLayerTestFunction(self.raw) }
+    }
+
+    /// Queues an interrogation command (`C_IC_NA_1`) with the qualifier of
+    /// interrogation, e.g. 20 for a station interrogation.
+    pub fn send_interrogation(&self, cot: CauseOfTransmission, common_address: u16, qualifier: u8) {
+        unsafe {
+            CS101_Master_sendInterrogationCommand(
+                self.raw,
+                cot as CS101_CauseOfTransmission,
+                common_address.into(),
+                qualifier,
+            )
+        }
+    }
+
+    /// Queues a counter interrogation command (`C_CI_NA_1`) with the
+    /// qualifier of counter interrogation (QCC).
+    pub fn send_counter_interrogation(
+        &self,
+        cot: CauseOfTransmission,
+        common_address: u16,
+        qualifier: u8,
+    ) {
+        unsafe {
+            CS101_Master_sendCounterInterrogationCommand(
+                self.raw,
+                cot as CS101_CauseOfTransmission,
+                common_address.into(),
+                qualifier,
+            )
+        }
+    }
+
+    /// Queues a read command (`C_RD_NA_1`) for one information object.
+    pub fn send_read(&self, common_address: u16, ioa: u32) {
+        unsafe { CS101_Master_sendReadCommand(self.raw, common_address.into(), ioa as c_int) }
+    }
+
+    /// Queues a clock synchronization command (`C_CS_NA_1`).
+    pub fn send_clock_sync(&self, common_address: u16, time: Cp56Time2a) {
+        let mut time = time;
+        unsafe {
+            CS101_Master_sendClockSyncCommand(self.raw, common_address.into(), time.as_mut_ptr())
+        }
+    }
+
+    /// Queues a test command (`C_TS_NA_1`).
+    pub fn send_test_command(&self, common_address: u16) {
+        unsafe { CS101_Master_sendTestCommand(self.raw, common_address.into()) }
+    }
+
+    /// Queues a process command, e.g. a single command or a set point, in
+    /// an ASDU of its own.
+    ///
+    /// Use [`CauseOfTransmission::Activation`] to select or execute and
+    /// [`CauseOfTransmission::Deactivation`] to cancel the command.
+    pub fn send_command(
+        &self,
+        cot: CauseOfTransmission,
+        common_address: u16,
+        command: &InformationObject,
+    ) -> Result<()> {
+        let io = command.to_raw()?;
+        unsafe {
+            CS101_Master_sendProcessCommand(
+                self.raw,
+                cot as CS101_CauseOfTransmission,
+                common_address.into(),
+                io,
+            );
+            InformationObject_destroy(io);
+        }
+        Ok(())
+    }
+
+    /// Queues an ASDU.
+    pub fn send_asdu(&self, asdu: &AsduRef) {
+        unsafe { CS101_Master_sendASDU(self.raw, asdu.as_ptr()) }
+    }
+
+    /// The underlying `CS101_Master`, for functions not covered by the
+    /// wrapper. It must not be destroyed and its handlers must not be
+    /// replaced.
+    pub fn as_raw(&self) -> CS101_Master {
+        self.raw
+    }
+}
+
+/// File transfer (`F_*` types), see [`file`](mod@crate::file).
+///
+/// The calls block until the transfer is complete and go to the slave
+/// selected with [`use_slave_address`](Self::use_slave_address). Meanwhile
+/// they run the master if it is not started and, in unbalanced mode, poll
+/// the selected slave. Every response is awaited for at most the
+/// [`file_response_timeout`](Cs101MasterBuilder::file_response_timeout).
+impl Cs101Master {
+    fn file_link(&mut self, common_address: u16) -> Cs101FileLink<'_> {
+        let files = self.files.subscribe(common_address);
+        Cs101FileLink {
+            master: self,
+            files,
+        }
+    }
+
+    /// Calls the directory of the station with `common_address`.
+    ///
+    /// Returns an empty directory if the station answers the call
+    /// negatively.
+    pub fn directory(&mut self, common_address: u16) -> Result<Vec<FileDirectory>> {
+        file::directory(&mut self.file_link(common_address), common_address)
+    }
+
+    /// Downloads a file listed in the directory of the station with
+    /// `common_address`.
+    ///
+    /// Fails with [`Error::FileUnavailable`] if the station has no such
+    /// file and with [`Error::ChecksumMismatch`] if the file does not match
+    /// its checksum.
+    pub fn download(&mut self, common_address: u16, ioa: u32, name: u16) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.download_to(common_address, ioa, name, &mut data)?;
+        Ok(data)
+    }
+
+    /// Like [`download`](Self::download), but writes the file section by
+    /// section to `writer`, e.g. a `std::fs::File`, and returns its length.
+    /// Sections are only written once their checksum has been verified.
+    pub fn download_to(
+        &mut self,
+        common_address: u16,
+        ioa: u32,
+        name: u16,
+        mut writer: impl io::Write,
+    ) -> Result<u64> {
+        let mut link = self.file_link(common_address);
+        file::download_to(&mut link, common_address, ioa, name, &mut writer)
+    }
+}
+
+/// A file transfer of a [`Cs101Master`].
+struct Cs101FileLink<'a> {
+    master: &'a mut Cs101Master,
+    files: mpsc::Receiver<Asdu>,
+}
+
+impl FileLink for Cs101FileLink<'_> {
+    fn send(
+        &mut self,
+        cot: CauseOfTransmission,
+        common_address: u16,
+        object: &InformationObject,
+    ) -> Result<()> {
+        self.master.send_command(cot, common_address, object)
+    }
+
+    fn receive(&mut self) -> Result<Asdu> {
+        let deadline = Instant::now() + self.master.file_response_timeout;
+        loop {
+            if self.master.mode == LinkLayerMode::Unbalanced {
+                if let Some(address) = self.master.slave_address.get() {
+                    self.master.poll(address);
+                }
+            }
+            // a master that is not started receives on this thread
+            let wait = if self.master.is_started {
+                FILE_POLL_INTERVAL
+            } else {
+                self.master.run_once();
+                Duration::ZERO
+            };
+            match file::receive_within(&self.files, wait) {
+                Err(Error::Timeout) if Instant::now() < deadline => {}
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Drop for Cs101Master {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "no-threads"))]
+        self.stop();
+        unsafe { CS101_Master_destroy(self.raw) }
+    }
+}
+
+impl fmt::Debug for Cs101Master {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cs101Master")
+            .field("mode", &self.mode)
+            .field("link_layer", &self.link_layer_parameters())
+            .field("app_layer", &self.app_layer_parameters())
+            .field("is_started", &self.is_started)
+            .finish_non_exhaustive()
+    }
+}
+
+unsafe extern "C" fn asdu_received(
+    parameter: *mut c_void,
+    address: c_int,
+    asdu: CS101_ASDU,
+) -> bool {
+    let handler = &mut *parameter.cast::<AsduHandler>();
+    guard((), || handler(address as u16, AsduRef::from_ptr(asdu)));
+    true
+}
+
+unsafe extern "C" fn link_state_changed(
+    parameter: *mut c_void,
+    address: c_int,
+    state: crate::bindings::LinkLayerState,
+) {
+    let link_states = parameter.cast::<LinkStates>();
+    let (address, state) = (address as u16, LinkLayerState::from_raw(state));
+    {
+        let states = (*link_states).states.lock();
+        states
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(address, state);
+    }
+    if let Some(handler) = &mut *ptr::addr_of_mut!((*link_states).handler) {
+        guard((), || handler(address, state));
+    }
+}
+
+unsafe extern "C" fn raw_message(parameter: *mut c_void, msg: *mut u8, size: c_int, sent: bool) {
+    let handler = &mut *parameter.cast::<RawMessageHandler>();
+    let msg = if msg.is_null() || size <= 0 {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(msg, size as usize)
+    };
+    guard((), || handler(msg, sent));
+}
@@ -0,0 +1,59 @@
+//! IEC 60870-5-101 (serial) master and slave.
+//!
+//! `LinkLayerState` and `SerialPort` are not re-exported at the crate root
+//! because the bindings already define type aliases with those names.
+
+mod master;
+mod serial;
+mod slave;
+
+pub use master::{Cs101Master, Cs101MasterBuilder};
+pub use serial::{Parity, SerialPort, SerialPortError};
+pub use slave::{Cs101Slave, Cs101SlaveBuilder, Cs101SlaveHandler};
+
+pub use crate::cs104::MasterConnection;
+
+use crate::bindings as sys;
+
+/// Transmission procedure of the link layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkLayerMode {
+    /// Both stations may initiate transfers (point-to-point links).
+    Balanced,
+    /// Only the master initiates transfers and polls the slaves for data.
+    Unbalanced,
+}
+
+impl LinkLayerMode {
+    pub(crate) fn to_raw(self) -> sys::IEC60870_LinkLayerMode {
+        match self {
+            LinkLayerMode::Balanced => sys::IEC60870_LinkLayerMode_IEC60870_LINK_LAYER_BALANCED,
+            LinkLayerMode::Unbalanced => sys::IEC60870_LinkLayerMode_IEC60870_LINK_LAYER_UNBALANCED,
+        }
+    }
+}
+
+/// State of the link to a station.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkLayerState {
+    /// There is no communication.
+    Idle,
+    /// An error occurred and the link may not be usable.
+    Error,
+    /// The link is busy.
+    Busy,
+    /// The link is available for user data.
+    Available,
+}
+
+impl LinkLayerState {
+    #[allow(non_upper_case_globals)]
+    pub(crate) fn from_raw(state: sys::LinkLayerState) -> Self {
+        match state {
+            sys::LinkLayerState_LL_STATE_IDLE => LinkLayerState::Idle,
+            sys::LinkLayerState_LL_STATE_BUSY => LinkLayerState::Busy,
+            sys::LinkLayerState_LL_STATE_AVAILABLE => LinkLayerState::Available,
+            _ => LinkLayerState::Error,
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Serial ports used by the CS101 link layer.
+
+use std::ffi::CString;
+use std::fmt;
+use std::os::raw::{c_char, c_int};
+use std::time::Duration;
+
+use crate::bindings as sys;
+use crate::error::{Error, Result};
+
+/// Parity of a serial port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Parity {
+    /// No parity bit.
+    None,
+    /// Even parity, the default of IEC 60870-5-101.
+    #[default]
+    Even,
+    /// Odd parity.
+    Odd,
+}
+
+impl Parity {
+    fn to_raw(self) -> c_char {
+        let parity = match self {
+            Parity::None => b'N',
+            Parity::Even => b'E',
+            Parity::Odd => b'O',
+        };
+        parity as c_char
+    }
+}
+
+/// Reason a serial port could not be opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SerialPortError {
+    /// The interface name or a setting is invalid.
+    InvalidArgument,
+    /// The baud rate is not supported.
+    InvalidBaudRate,
+    /// The interface could not be opened.
+    OpenFailed,
+    /// Any other error.
+    Unknown,
+}
+
+impl SerialPortError {
+    #[allow(non_upper_case_globals)]
+    fn from_raw(error: sys::SerialPortError) -> Self {
+        match error {
+            sys::SerialPortError_SERIAL_PORT_ERROR_INVALID_ARGUMENT => {
+                SerialPortError::InvalidArgument
+            }
+            sys::SerialPortError_SERIAL_PORT_ERROR_INVALID_BAUDRATE => {
+                SerialPortError::InvalidBaudRate
+            }
+            sys::SerialPortError_SERIAL_PORT_ERROR_OPEN_FAILED => SerialPortError::OpenFailed,
+            _ => SerialPortError::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for SerialPortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SerialPortError::InvalidArgument => "invalid argument",
+            SerialPortError::InvalidBaudRate => "invalid baud rate",
+            SerialPortError::OpenFailed => "open failed",
+            SerialPortError::Unknown => "unknown error",
+        })
+    }
+}
+
+/// A serial interface, e.g. `/dev/ttyUSB0` or `COM4`.
+///
+/// The port is opened by the master or slave that takes ownership of it and
+/// closed when that is dropped.
+pub struct SerialPort {
+    raw: sys::SerialPort,
+    is_open: bool,
+}
+
+// The port is only used by the link layer that owns it.
+unsafe impl Send for SerialPort {}
+
+impl SerialPort {
+    /// Creates a port with 8 data bits and 1 stop bit.
+    pub fn new(interface: &str, baud_rate: u32, parity: Parity) -> Result<Self> {
+        Self::with_format(interface, baud_rate, 8, parity, 1)
+    }
+
+    /// Creates a port with the given character format.
+    pub fn with_format(
+        interface: &str,
+        baud_rate: u32,
+        data_bits: u8,
+        parity: Parity,
+        stop_bits: u8,
+    ) -> Result<Self> {
+        let interface = CString::new(interface).map_err(|_| Error::NulByte)?;
+        let baud_rate = c_int::try_from(baud_rate)
+            .map_err(|_| Error::SerialPort(SerialPortError::InvalidBaudRate))?;
+        let raw = unsafe {
+            sys::SerialPort_create(
+                interface.as_ptr(),
+                baud_rate,
+                data_bits,
+                parity.to_raw(),
+                stop_bits,
+            )
+        };
+        if raw.is_null() {
+            return Err(Error::SerialPort(SerialPortError::InvalidArgument));
+        }
+        Ok(Self {
+            raw,
+            is_open: false,
+        })
+    }
+
+    /// The baud rate of the port.
+    pub fn baud_rate(&self) -> u32 {
+        unsafe { sys::SerialPort_getBaudRate(self.raw) as u32 }
+    }
+
+    /// Sets the timeout for receiving a message.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        let ms = timeout.as_millis().min(c_int::MAX as u128) as c_int;
+        unsafe { sys::SerialPort_setTimeout(self.raw, ms) }
+    }
+
+    /// Opens the port unless it is open already.
+    pub(crate) fn open(&mut self) -> Result<()> {
+        if !self.is_open {
+            if !unsafe { sys::SerialPort_open(self.raw) } {
+                let error = unsafe { sys::SerialPort_getLastError(self.raw) };
+                return Err(Error::SerialPort(SerialPortError::from_raw(error)));
+            }
+            self.is_open = true;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn as_raw(&self) -> sys::SerialPort {
+        self.raw
+    }
+}
+
+impl Drop for SerialPort {
+    fn drop(&mut self) {
+        unsafe {
+            if self.is_open {
+                sys::SerialPort_close(self.raw);
+            }
+            sys::SerialPort_destroy(self.raw);
+        }
+    }
+}
+
+impl fmt::Debug for SerialPort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SerialPort")
+            .field("baud_rate", &self.baud_rate())
+            .field("is_open", &self.is_open)
+            .finish()
+    }
+}
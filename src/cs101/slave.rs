@@ -0,0 +1,556 @@
+//! CS101 slave (controlled station).
+
+use std::fmt;
+use std::os::raw::{c_int, c_void};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use foreign_types::ForeignTypeRef;
+
+use crate::asdu::AsduRef;
+use crate::bindings::*;
+use crate::callback::guard;
+use crate::cs101::{LinkLayerMode, LinkLayerState, SerialPort};
+use crate::cs104::MasterConnection;
+use crate::error::{Error, Result};
+use crate::parameters::{AppLayerParameters, LinkLayerParameters};
+use crate::plugin::{Plugin, SlavePlugin};
+use crate::timestamp::{Cp16Time2a, Cp56Time2a};
+
+/// Callbacks of a CS101 slave.
+///
+/// Every method has a default that does nothing. Methods returning `bool`
+/// report whether the ASDU has been handled; unhandled ASDUs are passed on
+/// to [`asdu`](Self::asdu), and if that does not handle them either the
+/// slave answers with an unknown type identification.
+///
+/// Callbacks run on the thread driving the slave. A panic is caught at the
+/// FFI boundary and treated as "not handled".
+#[allow(unused_variables)]
+pub trait Cs101SlaveHandler: Send + Sync + 'static {
+    /// The master reset the communication unit (link layer reset CU). The
+    /// user data queues are usually flushed in response.
+    fn reset_cu(&self) {}
+
+    /// Interrogation command (`C_IC_NA_1`) with the qualifier of
+    /// interrogation.
+    fn interrogation(&self, connection: &MasterConnection, asdu: &AsduRef, qualifier: u8) -> bool {
+        false
+    }
+
+    /// Counter interrogation command (`C_CI_NA_1`) with the qualifier of
+    /// counter interrogation.
+    fn counter_interrogation(
+        &self,
+        connection: &MasterConnection,
+        asdu: &AsduRef,
+        qualifier: u8,
+    ) -> bool {
+        false
+    }
+
+    /// Read command (`C_RD_NA_1`) for the object at `ioa`.
+    fn read(&self, connection: &MasterConnection, asdu: &AsduRef, ioa: u32) -> bool {
+        false
+    }
+
+    /// Clock synchronization command (`C_CS_NA_1`).
+    ///
+    /// The slave answers with a positive activation confirmation if this
+    /// returns `true` and a negative one otherwise. `time` may be changed to
+    /// the time to send back in the confirmation.
+    fn clock_sync(
+        &self,
+        connection: &MasterConnection,
+        asdu: &AsduRef,
+        time: &mut Cp56Time2a,
+    ) -> bool {
+        false
+    }
+
+    /// Reset process command (`C_RP_NA_1`) with the qualifier of reset
+    /// process (QRP).
+    fn reset_process(&self, connection: &MasterConnection, asdu: &AsduRef, qualifier: u8) -> bool {
+        false
+    }
+
+    /// Delay acquisition command (`C_CD_NA_1`).
+    fn delay_acquisition(
+        &self,
+        connection: &MasterConnection,
+        asdu: &AsduRef,
+        delay: Cp16Time2a,
+    ) -> bool {
+        false
+    }
+
+    /// Any ASDU not handled by the other callbacks.
+    fn asdu(&self, connection: &MasterConnection, asdu: &AsduRef) -> bool {
+        false
+    }
+
+    /// The state of the link to the master changed.
+    fn link_state_changed(&self, address: u16, state: LinkLayerState) {}
+
+    /// Every frame sent (`sent == true`) or received, mostly useful for
+    /// logging.
+    fn raw_message(&self, message: &[u8], sent: bool) {}
+}
+
+/// A slave whose callbacks all use the defaults.
+impl Cs101SlaveHandler for () {}
+
+/// Builder for a [`Cs101Slave`].
+///
+/// Created by [`Cs101Slave::builder`].
+#[derive(Debug)]
+pub struct Cs101SlaveBuilder {
+    port: SerialPort,
+    mode: LinkLayerMode,
+    link_layer: LinkLayerParameters,
+    app_layer: AppLayerParameters,
+    queue_sizes: Option<(usize, usize)>,
+    link_address: Option<u16>,
+    other_station_address: Option<u16>,
+    dir: Option<bool>,
+    idle_timeout: Option<Duration>,
+}
+
+impl Cs101SlaveBuilder {
+    /// Sets the link layer parameters.
+    pub fn link_layer_parameters(mut self, parameters: LinkLayerParameters) -> Self {
+        self.link_layer = parameters;
+        self
+    }
+
+    /// Sets the application layer parameters.
+    pub fn app_layer_parameters(mut self, parameters: AppLayerParameters) -> Self {
+        self.app_layer = parameters;
+        self
+    }
+
+    /// Sets the number of ASDUs the class 1 and class 2 queues can hold.
+    pub fn queue_sizes(mut self, class1: usize, class2: usize) -> Self {
+        self.queue_sizes = Some((class1, class2));
+        self
+    }
+
+    /// Sets the own link address.
+    pub fn link_address(mut self, address: u16) -> Self {
+        self.link_address = Some(address);
+        self
+    }
+
+    /// Sets the link address of the master (balanced mode only).
+    pub fn other_station_address(mut self, address: u16) -> Self {
+        self.other_station_address = Some(address);
+        self
+    }
+
+    /// Sets the DIR bit of sent messages (balanced mode only). Defaults to
+    /// `false`, the controlled station.
+    pub fn dir(mut self, dir: bool) -> Self {
+        self.dir = Some(dir);
+        self
+    }
+
+    /// Sets the time without activity after which the link is considered
+    /// idle (balanced mode only).
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Opens the serial port and creates the slave with `handler` for its
+    /// callbacks.
+    pub fn build<H: Cs101SlaveHandler>(mut self, handler: H) -> Result<Cs101Slave<H>> {
+        self.port.open()?;
+
+        let mut link_layer = sLinkLayerParameters::from(self.link_layer);
+        let mut app_layer = sCS101_AppLayerParameters::from(self.app_layer);
+        // both are copied by lib60870
+        let raw = unsafe {
+            match self.queue_sizes {
+                Some((class1, class2)) => CS101_Slave_createEx(
+                    self.port.as_raw(),
+                    &mut link_layer,
+                    &mut app_layer,
+                    self.mode.to_raw(),
+                    queue_size(class1),
+                    queue_size(class2),
+                ),
+                None => CS101_Slave_create(
+                    self.port.as_raw(),
+                    &mut link_layer,
+                    &mut app_layer,
+                    self.mode.to_raw(),
+                ),
+            }
+        };
+        assert!(!raw.is_null(), "CS101_Slave_create failed");
+
+        let slave = Cs101Slave {
+            raw,
+            mode: self.mode,
+            handler: Box::new(handler),
+            plugins: Vec::new(),
+            enqueue: Mutex::new(()),
+            is_started: false,
+            _port: self.port,
+        };
+        unsafe {
+            if let Some(address) = self.link_address {
+                CS101_Slave_setLinkLayerAddress(raw, address.into());
+            }
+            if let Some(address) = self.other_station_address {
+                CS101_Slave_setLinkLayerAddressOtherStation(raw, address.into());
+            }
+            if let Some(dir) = self.dir {
+                CS101_Slave_setDIR(raw, dir);
+            }
+            if let Some(timeout) = self.idle_timeout {
+                let ms = timeout.as_millis().min(c_int::MAX as u128) as c_int;
+                CS101_Slave_setIdleTimeout(raw, ms);
+            }
+            slave.set_handlers();
+        }
+        Ok(slave)
+    }
+}
+
+fn queue_size(size: usize) -> c_int {
+    size.min(c_int::MAX as usize) as c_int
+}
+
+/// A CS101 slave on a serial line.
+///
+/// Spontaneous and cyclic data is sent through two queues: class 1 for high
+/// priority events and class 2 for everything else. The enqueue methods
+/// fail with [`Error::QueueFull`] instead of overwriting queued data, so
+/// the producer can hold back until the master has polled.
+///
+/// The slave owns its serial port and handler and releases them after the
+/// link layer has been stopped and destroyed. The protocol runs either on a
+/// background thread started with `start` or on the caller's thread by
+/// calling [`run`](Self::run) in a loop. `start` and `stop` are not
+/// available with the `no-threads` feature.
+///
+/// ```no_run
+/// use lib60870_sys::cs101::{Cs101Slave, LinkLayerMode, Parity, SerialPort};
+///
+/// let port = SerialPort::new("/dev/ttyUSB0", 9600, Parity::Even)?;
+/// let mut slave = Cs101Slave::builder(port, LinkLayerMode::Unbalanced)
+///     .link_address(1)
+///     .build(())?;
+/// loop {
+///     slave.run();
+/// }
+/// # Ok::<(), lib60870_sys::Error>(())
+/// ```
+pub struct Cs101Slave<H: Cs101SlaveHandler> {
+    raw: CS101_Slave,
+    mode: LinkLayerMode,
+    handler: Box<H>,
+    // called by the slave until it is destroyed
+    plugins: Vec<Plugin>,
+    // held from the full check to the enqueue, so concurrent producers
+    // cannot both take the last free slot
+    enqueue: Mutex<()>,
+    is_started: bool,
+    // dropped after the slave that reads and writes it
+    _port: SerialPort,
+}
+
+// The user data queues are protected by a mutex in lib60870, and the
+// handler is Sync. Without threads it has no mutexes.
+unsafe impl<H: Cs101SlaveHandler> Send for Cs101Slave<H> {}
+#[cfg(not(feature = "no-threads"))]
+unsafe impl<H: Cs101SlaveHandler> Sync for Cs101Slave<H> {}
+
+impl Cs101Slave<()> {
+    /// Starts building a slave that takes ownership of `port`.
+    pub fn builder(port: SerialPort, mode: LinkLayerMode) -> Cs101SlaveBuilder {
+        Cs101SlaveBuilder {
+            port,
+            mode,
+            link_layer: LinkLayerParameters::default(),
+            app_layer: AppLayerParameters::default(),
+            queue_sizes: None,
+            link_address: None,
+            other_station_address: None,
+            dir: None,
+            idle_timeout: None,
+        }
+    }
+}
+
+impl<H: Cs101SlaveHandler> Cs101Slave<H> {
+    unsafe fn set_handlers(&self) {
+        let raw = self.raw;
+        let parameter = &*self.handler as *const H as *mut c_void;
+        CS101_Slave_setResetCUHandler(raw, Some(reset_cu::<H>), parameter);
+        CS101_Slave_setInterrogationHandler(raw, Some(interrogation::<H>), parameter);
+        CS101_Slave_setCounterInterrogationHandler(
+            raw,
+            Some(counter_interrogation::<H>),
+            parameter,
+        );
+        CS101_Slave_setReadHandler(raw, Some(read::<H>), parameter);
+        CS101_Slave_setClockSyncHandler(raw, Some(clock_sync::<H>), parameter);
+        CS101_Slave_setResetProcessHandler(raw, Some(reset_process::<H>), parameter);
+        CS101_Slave_setDelayAcquisitionHandler(raw, Some(delay_acquisition::<H>), parameter);
+        CS101_Slave_setASDUHandler(raw, Some(asdu::<H>), parameter);
+        CS101_Slave_setLinkLayerStateChanged(raw, Some(link_state_changed::<H>), parameter);
+        CS101_Slave_setRawMessageHandler(raw, Some(raw_message::<H>), parameter);
+    }
+
+    /// The handler passed to the builder.
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// The link layer mode.
+    pub fn mode(&self) -> LinkLayerMode {
+        self.mode
+    }
+
+    /// Whether the class 1 queue is full.
+    pub fn is_class1_queue_full(&self) -> bool {
+        unsafe { CS101_Slave_isClass1QueueFull(self.raw) }
+    }
+
+    /// Whether the class 2 queue is full.
+    pub fn is_class2_queue_full(&self) -> bool {
+        unsafe { CS101_Slave_isClass2QueueFull(self.raw) }
+    }
+
+    /// Copies an ASDU into the class 1 (high priority) queue.
+    ///
+    /// Fails with [`Error::QueueFull`] if the queue is full.
+    pub fn enqueue_class1(&self, asdu: &AsduRef) -> Result<()> {
+        let _enqueue = self.enqueue.lock().unwrap_or_else(|e| e.into_inner());
+        if self.is_class1_queue_full() {
+            return Err(Error::QueueFull);
+        }
+        unsafe { CS101_Slave_enqueueUserDataClass1(self.raw, asdu.as_ptr()) };
+        Ok(())
+    }
+
+    /// Copies an ASDU into the class 2 (low priority) queue.
+    ///
+    /// Fails with [`Error::QueueFull`] if the queue is full.
+    pub fn enqueue_class2(&self, asdu: &AsduRef) -> Result<()> {
+        let _enqueue = self.enqueue.lock().unwrap_or_else(|e| e.into_inner());
+        if self.is_class2_queue_full() {
+            return Err(Error::QueueFull);
+        }
+        unsafe { CS101_Slave_enqueueUserDataClass2(self.raw, asdu.as_ptr()) };
+        Ok(())
+    }
+
+    /// Discards all queued ASDUs of both classes.
+    pub fn flush_queues(&self) {
+        unsafe { CS101_Slave_flushQueues(self.raw) }
+    }
+
+    /// Adds a plugin, which sees received ASDUs before the handler. The
+    /// plugin is dropped after the slave has been destroyed.
+    ///
+    /// Fails with [`Error::AlreadyStarted`] once the slave is started.
+    pub fn add_plugin<P: SlavePlugin>(&mut self, plugin: P) -> Result<()> {
+        if self.is_started {
+            return Err(Error::AlreadyStarted);
+        }
+        let mut plugin = Plugin::new(plugin);
+        unsafe { CS101_Slave_addPlugin(self.raw, plugin.as_raw()) };
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    /// Receives a message and runs the link layer state machine once.
+    ///
+    /// Has to be called frequently when the slave is not started, and does
+    /// nothing while it is, as the background thread runs it then.
+    pub fn run(&mut self) {
+        if !self.is_started {
+            unsafe { CS101_Slave_run(self.raw) }
+        }
+    }
+
+    /// Runs the slave on a background thread until [`stop`](Self::stop) is
+    /// called or the slave is dropped.
+    #[cfg(not(feature = "no-threads"))]
+    pub fn start(&mut self) {
+        if !self.is_started {
+            unsafe { CS101_Slave_start(self.raw) };
+            self.is_started = true;
+        }
+    }
+
+    /// Stops the background thread, waiting for it to finish.
+    #[cfg(not(feature = "no-threads"))]
+    pub fn stop(&mut self) {
+        if self.is_started {
+            unsafe { CS101_Slave_stop(self.raw) };
+            self.is_started = false;
+        }
+    }
+
+    /// The link layer parameters in use.
+    pub fn link_layer_parameters(&self) -> LinkLayerParameters {
+        unsafe { LinkLayerParameters::from(&*CS101_Slave_getLinkLayerParameters(self.raw)) }
+    }
+
+    /// The application layer parameters in use.
+    pub fn app_layer_parameters(&self) -> AppLayerParameters {
+        unsafe { AppLayerParameters::from(&*CS101_Slave_getAppLayerParameters(self.raw)) }
+    }
+
+    /// The underlying `CS101_Slave`, for functions not covered by the
+    /// wrapper. It must not be destroyed and its handlers must not be
+    /// replaced.
+    pub fn as_raw(&self) -> CS101_Slave {
+        self.raw
+    }
+}
+
+impl<H: Cs101SlaveHandler> Drop for Cs101Slave<H> {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "no-threads"))]
+        self.stop();
+        unsafe { CS101_Slave_destroy(self.raw) }
+    }
+}
+
+impl<H: Cs101SlaveHandler> fmt::Debug for Cs101Slave<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cs101Slave")
+            .field("mode", &self.mode)
+            .field("link_layer", &self.link_layer_parameters())
+            .field("app_layer", &self.app_layer_parameters())
+            .field("is_started", &self.is_started)
+            .finish_non_exhaustive()
+    }
+}
+
+unsafe extern "C" fn reset_cu<H: Cs101SlaveHandler>(parameter: *mut c_void) {
+    let handler = &*parameter.cast::<H>();
+    guard((), || handler.reset_cu());
+}
+
+unsafe extern "C" fn interrogation<H: Cs101SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+    qoi: u8,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    guard(false, || {
+        handler.interrogation(&connection, AsduRef::from_ptr(asdu), qoi)
+    })
+}
+
+unsafe extern "C" fn counter_interrogation<H: Cs101SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+    qcc: QualifierOfCIC,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    guard(false, || {
+        handler.counter_interrogation(&connection, AsduRef::from_ptr(asdu), qcc)
+    })
+}
+
+unsafe extern "C" fn read<H: Cs101SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+    ioa: c_int,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    guard(false, || {
+        handler.read(&connection, AsduRef::from_ptr(asdu), ioa as u32)
+    })
+}
+
+unsafe extern "C" fn clock_sync<H: Cs101SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+    new_time: CP56Time2a,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    let mut time = Cp56Time2a::from_ptr(new_time);
+    let handled = guard(false, || {
+        handler.clock_sync(&connection, AsduRef::from_ptr(asdu), &mut time)
+    });
+    *new_time = time.0;
+    handled
+}
+
+unsafe extern "C" fn reset_process<H: Cs101SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+    qrp: u8,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    guard(false, || {
+        handler.reset_process(&connection, AsduRef::from_ptr(asdu), qrp)
+    })
+}
+
+unsafe extern "C" fn delay_acquisition<H: Cs101SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+    delay: CP16Time2a,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    let delay = Cp16Time2a::from_ptr(delay);
+    guard(false, || {
+        handler.delay_acquisition(&connection, AsduRef::from_ptr(asdu), delay)
+    })
+}
+
+unsafe extern "C" fn asdu<H: Cs101SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    guard(false, || handler.asdu(&connection, AsduRef::from_ptr(asdu)))
+}
+
+unsafe extern "C" fn link_state_changed<H: Cs101SlaveHandler>(
+    parameter: *mut c_void,
+    address: c_int,
+    state: crate::bindings::LinkLayerState,
+) {
+    let handler = &*parameter.cast::<H>();
+    let state = LinkLayerState::from_raw(state);
+    guard((), || handler.link_state_changed(address as u16, state));
+}
+
+unsafe extern "C" fn raw_message<H: Cs101SlaveHandler>(
+    parameter: *mut c_void,
+    msg: *mut u8,
+    size: c_int,
+    sent: bool,
+) {
+    let handler = &*parameter.cast::<H>();
+    let msg = if msg.is_null() || size <= 0 {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(msg, size as usize)
+    };
+    guard((), || handler.raw_message(msg, sent));
+}
@@ -0,0 +1,543 @@
+//! CS104 client for tokio applications.
+
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::{mpsc, oneshot, watch};
+
+use crate::asdu::{Asdu, AsduRef};
+use crate::cs104::{ConnectionEvent, Cs104Client, Cs104ClientBuilder};
+use crate::error::{Error, Result};
+use crate::file::{Directory, Download, Step};
+use crate::information_object::{FileDirectory, InformationObject, InformationObject as Io};
+use crate::timestamp::Cp56Time2a;
+use crate::types::{CauseOfTransmission, TypeId};
+
+/// Which response to a command a [`Waiter`] is waiting for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Confirmation,
+    Termination,
+}
+
+impl Stage {
+    /// The stage an ASDU with cause of transmission `cot` completes.
+    /// Responses with an unknown type, cause, common address or IOA reject
+    /// the command at the confirmation stage.
+    fn of(cot: CauseOfTransmission) -> Option<Self> {
+        match cot {
+            CauseOfTransmission::ActivationCon
+            | CauseOfTransmission::UnknownTypeId
+            | CauseOfTransmission::UnknownCot
+            | CauseOfTransmission::UnknownCa
+            | CauseOfTransmission::UnknownIoa => Some(Stage::Confirmation),
+            CauseOfTransmission::ActivationTermination => Some(Stage::Termination),
+            _ => None,
+        }
+    }
+}
+
+/// A command waiting for its ACT_CON or ACT_TERM.
+struct Waiter {
+    type_id: TypeId,
+    common_address: u16,
+    ioa: u32,
+    stage: Stage,
+    response: oneshot::Sender<Asdu>,
+}
+
+/// A file transfer receiving the `F_*` ASDUs of a station.
+struct FileSubscriber {
+    common_address: u16,
+    asdus: mpsc::UnboundedSender<Asdu>,
+}
+
+#[derive(Default)]
+struct Waiters {
+    commands: Mutex<Vec<Waiter>>,
+    files: Mutex<Vec<FileSubscriber>>,
+}
+
+impl Waiters {
+    fn register(
+        &self,
+        type_id: TypeId,
+        common_address: u16,
+        ioa: u32,
+        stage: Stage,
+    ) -> oneshot::Receiver<Asdu> {
+        let (response, rx) = oneshot::channel();
+        let mut waiters = self.commands.lock().unwrap_or_else(|e| e.into_inner());
+        // drop the waiters of cancelled commands
+        waiters.retain(|waiter| !waiter.response.is_closed());
+        waiters.push(Waiter {
+            type_id,
+            common_address,
+            ioa,
+            stage,
+            response,
+        });
+        rx
+    }
+
+    /// Forwards the file transfer ASDUs of `common_address` until the
+    /// receiver is dropped.
+    fn subscribe_files(&self, common_address: u16) -> mpsc::UnboundedReceiver<Asdu> {
+        let (asdus, rx) = mpsc::unbounded_channel();
+        let mut files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        files.retain(|file| !file.asdus.is_closed());
+        files.push(FileSubscriber {
+            common_address,
+            asdus,
+        });
+        rx
+    }
+
+    /// Hands a response to the first command waiting for it, if any.
+    fn resolve(&self, asdu: &AsduRef) {
+        let Some(type_id) = asdu.type_id() else {
+            return;
+        };
+        if type_id.is_file_transfer() {
+            let files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+            for file in files.iter() {
+                if file.common_address == asdu.common_address() {
+                    let _ = file.asdus.send(asdu.to_owned());
+                }
+            }
+            return;
+        }
+        let Some(stage) = asdu.cot().and_then(Stage::of) else {
+            return;
+        };
+        let ioa = asdu.element(0).map_or(0, |element| element.address());
+
+        let mut waiters = self.commands.lock().unwrap_or_else(|e| e.into_inner());
+        let position = waiters.iter().position(|waiter| {
+            waiter.stage == stage
+                && waiter.type_id == type_id
+                && waiter.common_address == asdu.common_address()
+                && waiter.ioa == ioa
+                && !waiter.response.is_closed()
+        });
+        if let Some(position) = position {
+            let _ = waiters.remove(position).response.send(asdu.to_owned());
+        }
+    }
+
+    /// Fails all pending commands and file transfers.
+    fn clear(&self) {
+        self.commands
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        self.files.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+/// Default limit for an ACT_TERM, in multiples of t1. Terminations follow
+/// all data of an interrogation, so they take longer than a confirmation.
+const TERMINATION_TIMEOUT_T1_FACTOR: u32 = 4;
+
+/// Returns the response if it confirms the command, or why it does not.
+fn confirmed(response: Asdu) -> Result<Asdu> {
+    match response.cot() {
+        Some(CauseOfTransmission::ActivationCon) if !response.is_negative() => Ok(response),
+        Some(cot) => Err(Error::Rejected(cot)),
+        None => unreachable!("responses are matched by their cause of transmission"),
+    }
+}
+
+pub(super) fn build(mut builder: Cs104ClientBuilder) -> Result<(AsyncCs104Client, AsduStream)> {
+    let waiters = Arc::new(Waiters::default());
+    let (events, events_rx) = watch::channel(ConnectionEvent::Closed);
+    let (asdus, asdus_rx) = mpsc::unbounded_channel();
+
+    let termination_timeout = builder.termination_timeout.take();
+    let mut user_on_asdu = builder.on_asdu.take();
+    let asdu_waiters = Arc::clone(&waiters);
+    let mut user_on_connection_event = builder.on_connection_event.take();
+    let event_waiters = Arc::clone(&waiters);
+    let client = builder
+        .on_asdu(move |asdu: &AsduRef| {
+            if let Some(handler) = &mut user_on_asdu {
+                handler(asdu);
+            }
+            asdu_waiters.resolve(asdu);
+            // the stream may have been dropped
+            let _ = asdus.send(asdu.to_owned());
+        })
+        .on_connection_event(move |event| {
+            if let Some(handler) = &mut user_on_connection_event {
+                handler(event);
+            }
+            if matches!(event, ConnectionEvent::Closed | ConnectionEvent::Failed) {
+                event_waiters.clear();
+            }
+            events.send_replace(event);
+        })
+        .build()?;
+
+    let termination_timeout = termination_timeout
+        .unwrap_or_else(|| client.apci_parameters().t1 * TERMINATION_TIMEOUT_T1_FACTOR);
+    let client = AsyncCs104Client {
+        client,
+        termination_timeout,
+        waiters,
+        events: events_rx,
+        file_transfer: tokio::sync::Mutex::new(()),
+    };
+    Ok((client, AsduStream { rx: asdus_rx }))
+}
+
+/// A CS104 client whose operations are futures.
+///
+/// Created by [`Cs104ClientBuilder::build_async`], together with the
+/// [`AsduStream`] of all received ASDUs. Command methods send with cause
+/// [`Activation`](CauseOfTransmission::Activation) and resolve when the
+/// server answers; responses are matched to commands by type
+/// identification, common address and IOA, and still appear on the stream.
+///
+/// Confirmations are awaited for at most t1 of the
+/// [APCI parameters](crate::ApciParameters), terminations for the limit set
+/// with [`termination_timeout`](Cs104ClientBuilder::termination_timeout),
+/// by default four times t1. The futures do not need a
+/// particular runtime apart from the tokio timer.
+///
+/// ```no_run
+/// # async fn example() -> lib60870_sys::Result<()> {
+/// use lib60870_sys::Cs104Client;
+///
+/// let (client, mut asdus) = Cs104Client::builder("127.0.0.1").build_async()?;
+/// client.connect().await?;
+/// client.start_dt().await?;
+/// client.interrogation(1, 20).await?;
+/// while let Some(asdu) = asdus.recv().await {
+///     println!("{asdu:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncCs104Client {
+    client: Cs104Client,
+    termination_timeout: Duration,
+    waiters: Arc<Waiters>,
+    events: watch::Receiver<ConnectionEvent>,
+    // file transfers run one at a time
+    file_transfer: tokio::sync::Mutex<()>,
+}
+
+impl AsyncCs104Client {
+    /// The underlying client, e.g. to send ASDUs without waiting for a
+    /// response.
+    pub fn client(&self) -> &Cs104Client {
+        &self.client
+    }
+
+    /// The connection state as the last [`ConnectionEvent`], starting with
+    /// [`Closed`](ConnectionEvent::Closed).
+    pub fn events(&self) -> watch::Receiver<ConnectionEvent> {
+        self.events.clone()
+    }
+
+    fn timeout(&self) -> Duration {
+        self.client.apci_parameters().t1
+    }
+
+    /// Waits for the next connection event satisfying `done`, which returns
+    /// the outcome.
+    async fn wait_for_event(
+        &self,
+        mut events: watch::Receiver<ConnectionEvent>,
+        mut done: impl FnMut(ConnectionEvent) -> Option<Result<()>>,
+    ) -> Result<()> {
+        loop {
+            if events.changed().await.is_err() {
+                return Err(Error::ConnectionClosed);
+            }
+            let event = *events.borrow_and_update();
+            if let Some(result) = done(event) {
+                return result;
+            }
+        }
+    }
+
+    /// Connects to the server. Fails with [`Error::ConnectFailed`] if the
+    /// connection could not be established within t0.
+    pub async fn connect(&self) -> Result<()> {
+        let mut events = self.events();
+        events.borrow_and_update();
+        self.client.connect_async();
+        self.wait_for_event(events, |event| match event {
+            ConnectionEvent::Opened => Some(Ok(())),
+            ConnectionEvent::Failed | ConnectionEvent::Closed => Some(Err(Error::ConnectFailed)),
+            _ => None,
+        })
+        .await
+    }
+
+    /// Closes the connection.
+    pub fn close(&self) {
+        self.client.close()
+    }
+
+    async fn data_transfer(&self, start: bool) -> Result<()> {
+        let mut events = self.events();
+        events.borrow_and_update();
+        if start {
+            self.client.start_dt();
+        } else {
+            self.client.stop_dt();
+        }
+        let confirmed = self.wait_for_event(events, |event| match event {
+            ConnectionEvent::StartDtConfirmed if start => Some(Ok(())),
+            ConnectionEvent::StopDtConfirmed if !start => Some(Ok(())),
+            ConnectionEvent::Closed | ConnectionEvent::Failed => Some(Err(Error::ConnectionClosed)),
+            _ => None,
+        });
+        tokio::time::timeout(self.timeout(), confirmed)
+            .await
+            .unwrap_or(Err(Error::Timeout))
+    }
+
+    /// Sends STARTDT and waits for the confirmation.
+    pub async fn start_dt(&self) -> Result<()> {
+        self.data_transfer(true).await
+    }
+
+    /// Sends STOPDT and waits for the confirmation.
+    pub async fn stop_dt(&self) -> Result<()> {
+        self.data_transfer(false).await
+    }
+
+    async fn confirmation(&self, response: oneshot::Receiver<Asdu>) -> Result<Asdu> {
+        match tokio::time::timeout(self.timeout(), response).await {
+            Ok(Ok(response)) => confirmed(response),
+            Ok(Err(_)) => Err(Error::ConnectionClosed),
+            Err(_) => Err(Error::Timeout),
+        }
+    }
+
+    async fn termination(&self, response: oneshot::Receiver<Asdu>) -> Result<()> {
+        match tokio::time::timeout(self.termination_timeout, response).await {
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(_)) => Err(Error::ConnectionClosed),
+            Err(_) => Err(Error::Timeout),
+        }
+    }
+
+    /// Sends an interrogation command (`C_IC_NA_1`) and waits until the
+    /// server has sent all data (ACT_TERM). The data arrives on the
+    /// [`AsduStream`].
+    pub async fn interrogation(&self, common_address: u16, qualifier: u8) -> Result<()> {
+        let con = self
+            .waiters
+            .register(TypeId::C_IC_NA_1, common_address, 0, Stage::Confirmation);
+        let term = self
+            .waiters
+            .register(TypeId::C_IC_NA_1, common_address, 0, Stage::Termination);
+        self.client.send_interrogation(
+            CauseOfTransmission::Activation,
+            common_address,
+            qualifier,
+        )?;
+        self.confirmation(con).await?;
+        self.termination(term).await
+    }
+
+    /// Sends a counter interrogation command (`C_CI_NA_1`) and waits until
+    /// the server has sent all counters (ACT_TERM).
+    pub async fn counter_interrogation(&self, common_address: u16, qualifier: u8) -> Result<()> {
+        let con = self
+            .waiters
+            .register(TypeId::C_CI_NA_1, common_address, 0, Stage::Confirmation);
+        let term = self
+            .waiters
+            .register(TypeId::C_CI_NA_1, common_address, 0, Stage::Termination);
+        self.client.send_counter_interrogation(
+            CauseOfTransmission::Activation,
+            common_address,
+            qualifier,
+        )?;
+        self.confirmation(con).await?;
+        self.termination(term).await
+    }
+
+    /// Sends a clock synchronization command (`C_CS_NA_1`) and waits for
+    /// the confirmation, which carries the time of the server.
+    pub async fn clock_sync(&self, common_address: u16, time: Cp56Time2a) -> Result<Asdu> {
+        let con = self
+            .waiters
+            .register(TypeId::C_CS_NA_1, common_address, 0, Stage::Confirmation);
+        self.client.send_clock_sync(common_address, time)?;
+        self.confirmation(con).await
+    }
+
+    /// Sends a process command (`C_SC_NA_1`, `C_SE_NB_1`, ...) and waits
+    /// for its confirmation (ACT_CON).
+    ///
+    /// Fails with [`Error::Rejected`] if the server answers negatively.
+    pub async fn command(&self, common_address: u16, command: &InformationObject) -> Result<Asdu> {
+        let con = self.waiters.register(
+            command.type_id(),
+            common_address,
+            command.ioa(),
+            Stage::Confirmation,
+        );
+        self.client
+            .send_command(CauseOfTransmission::Activation, common_address, command)?;
+        self.confirmation(con).await
+    }
+
+    /// Like [`command`](Self::command), but also waits for the termination
+    /// (ACT_TERM) that some commands, e.g. regulating step commands, end
+    /// with.
+    pub async fn command_with_termination(
+        &self,
+        common_address: u16,
+        command: &InformationObject,
+    ) -> Result<()> {
+        let type_id = command.type_id();
+        let ioa = command.ioa();
+        let con = self
+            .waiters
+            .register(type_id, common_address, ioa, Stage::Confirmation);
+        let term = self
+            .waiters
+            .register(type_id, common_address, ioa, Stage::Termination);
+        self.client
+            .send_command(CauseOfTransmission::Activation, common_address, command)?;
+        self.confirmation(con).await?;
+        self.termination(term).await
+    }
+}
+
+/// File transfer (`F_*` types), see [`file`](mod@crate::file).
+///
+/// Transfers run one at a time. Every response is awaited for at most t1.
+impl AsyncCs104Client {
+    /// Receives the next file transfer ASDU.
+    async fn next_file_asdu(&self, files: &mut mpsc::UnboundedReceiver<Asdu>) -> Result<Asdu> {
+        match tokio::time::timeout(self.timeout(), files.recv()).await {
+            Ok(Some(asdu)) => Ok(asdu),
+            Ok(None) => Err(Error::ConnectionClosed),
+            Err(_) => Err(Error::Timeout),
+        }
+    }
+
+    /// Calls the directory of the station with `common_address`.
+    ///
+    /// Returns an empty directory if the station answers the call
+    /// negatively.
+    pub async fn directory(&self, common_address: u16) -> Result<Vec<FileDirectory>> {
+        let _transfer = self.file_transfer.lock().await;
+        let mut files = self.waiters.subscribe_files(common_address);
+        let mut directory = Directory::default();
+        self.client.send_command(
+            CauseOfTransmission::Request,
+            common_address,
+            &Directory::call(),
+        )?;
+        loop {
+            let asdu = self.next_file_asdu(&mut files).await?;
+            if let Some(entries) = directory.receive(&asdu)? {
+                return Ok(entries);
+            }
+        }
+    }
+
+    /// Downloads a file listed in the directory of the station with
+    /// `common_address`.
+    ///
+    /// Fails with [`Error::FileUnavailable`] if the station has no such
+    /// file and with [`Error::ChecksumMismatch`] if the file does not match
+    /// its checksum.
+    pub async fn download(&self, common_address: u16, ioa: u32, name: u16) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.download_to(common_address, ioa, name, &mut data)
+            .await?;
+        Ok(data)
+    }
+
+    /// Like [`download`](Self::download), but writes the file section by
+    /// section to `writer`, e.g. a `tokio::fs::File`, and returns its
+    /// length. Sections are only written once their checksum has been
+    /// verified.
+    pub async fn download_to(
+        &self,
+        common_address: u16,
+        ioa: u32,
+        name: u16,
+        writer: &mut (impl AsyncWrite + Unpin),
+    ) -> Result<u64> {
+        let _transfer = self.file_transfer.lock().await;
+        let mut files = self.waiters.subscribe_files(common_address);
+        let send = |command: &Io| {
+            self.client
+                .send_command(CauseOfTransmission::FileTransfer, common_address, command)
+        };
+
+        let (mut download, select) = Download::new(ioa, name);
+        send(&select)?;
+        loop {
+            let asdu = self.next_file_asdu(&mut files).await?;
+            match download.receive(&asdu)? {
+                Step::Wait => {}
+                Step::Send(command) => send(&command)?,
+                Step::Section { data, ack } => {
+                    writer
+                        .write_all(&data)
+                        .await
+                        .map_err(|e| Error::Io(e.kind()))?;
+                    send(&ack)?;
+                }
+                Step::Done { ack, length } => {
+                    send(&ack)?;
+                    writer.flush().await.map_err(|e| Error::Io(e.kind()))?;
+                    return Ok(length);
+                }
+                Step::Abort { ack, error } => {
+                    send(&ack)?;
+                    return Err(error);
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for AsyncCs104Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncCs104Client")
+            .field("state", &*self.events.borrow())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The ASDUs received by an [`AsyncCs104Client`], including the responses
+/// its commands wait for.
+///
+/// The stream ends when the client is dropped. ASDUs are buffered without
+/// limit, so it should be polled continuously.
+#[derive(Debug)]
+pub struct AsduStream {
+    rx: mpsc::UnboundedReceiver<Asdu>,
+}
+
+impl AsduStream {
+    /// Receives the next ASDU.
+    pub async fn recv(&mut self) -> Option<Asdu> {
+        self.rx.recv().await
+    }
+}
+
+impl Stream for AsduStream {
+    type Item = Asdu;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Asdu>> {
+        self.rx.poll_recv(cx)
+    }
+}
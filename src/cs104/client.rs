@@ -0,0 +1,604 @@
+//! CS104 client (controlling station).
+
+use std::ffi::CString;
+use std::fmt;
+use std::io;
+use std::os::raw::{c_int, c_void};
+use std::sync::{Arc, Mutex};
+
+use foreign_types::ForeignTypeRef;
+
+use crate::asdu::{Asdu, AsduRef};
+use crate::bindings::*;
+use crate::callback::{self, guard};
+use crate::error::{sent, Error, Result};
+use crate::file::{self, FileLink, FileReceiver};
+use crate::information_object::{FileDirectory, InformationObject};
+use crate::parameters::{ApciParameters, AppLayerParameters};
+use crate::timestamp::Cp56Time2a;
+#[cfg(feature = "tls")]
+use crate::tls::{Role, TlsConfig};
+use crate::types::CauseOfTransmission;
+
+type AsduHandler = Box<dyn FnMut(&AsduRef) + Send>;
+type ConnectionHandler = Box<dyn FnMut(ConnectionEvent) + Send>;
+type RawMessageHandler = Box<dyn FnMut(&[u8], bool) + Send>;
+
+/// State change of a CS104 client connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionEvent {
+    /// The TCP connection has been established.
+    Opened,
+    /// The connection has been closed.
+    Closed,
+    /// The server confirmed STARTDT, data transfer is active.
+    StartDtConfirmed,
+    /// The server confirmed STOPDT, data transfer is stopped.
+    StopDtConfirmed,
+    /// The connection attempt failed.
+    Failed,
+}
+
+impl ConnectionEvent {
+    #[allow(non_upper_case_globals)]
+    fn from_raw(event: CS104_ConnectionEvent) -> Option<Self> {
+        match event {
+            CS104_ConnectionEvent_CS104_CONNECTION_OPENED => Some(ConnectionEvent::Opened),
+            CS104_ConnectionEvent_CS104_CONNECTION_CLOSED => Some(ConnectionEvent::Closed),
+            CS104_ConnectionEvent_CS104_CONNECTION_STARTDT_CON_RECEIVED => {
+                Some(ConnectionEvent::StartDtConfirmed)
+            }
+            CS104_ConnectionEvent_CS104_CONNECTION_STOPDT_CON_RECEIVED => {
+                Some(ConnectionEvent::StopDtConfirmed)
+            }
+            CS104_ConnectionEvent_CS104_CONNECTION_FAILED => Some(ConnectionEvent::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Builder for a [`Cs104Client`].
+///
+/// Created by [`Cs104Client::builder`].
+pub struct Cs104ClientBuilder {
+    hostname: String,
+    port: Option<u16>,
+    local_address: Option<(String, Option<u16>)>,
+    apci: ApciParameters,
+    app_layer: AppLayerParameters,
+    pub(super) on_asdu: Option<AsduHandler>,
+    pub(super) on_connection_event: Option<ConnectionHandler>,
+    on_raw_message: Option<RawMessageHandler>,
+    #[cfg(feature = "tls")]
+    tls: Option<TlsConfig>,
+    #[cfg(feature = "tokio")]
+    pub(super) termination_timeout: Option<std::time::Duration>,
+}
+
+impl Cs104ClientBuilder {
+    /// Sets the TCP port of the server. Defaults to 2404.
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Binds the client to a local address and, optionally, port. By default
+    /// the operating system chooses both.
+    pub fn local_address(mut self, address: &str, port: Option<u16>) -> Self {
+        self.local_address = Some((address.to_owned(), port));
+        self
+    }
+
+    /// Sets the APCI parameters (k, w and the timeouts t0 to t3).
+    pub fn apci_parameters(mut self, parameters: ApciParameters) -> Self {
+        self.apci = parameters;
+        self
+    }
+
+    /// Sets the application layer parameters.
+    pub fn app_layer_parameters(mut self, parameters: AppLayerParameters) -> Self {
+        self.app_layer = parameters;
+        self
+    }
+
+    /// Secures the connection with TLS. The port then defaults to 19998.
+    #[cfg(feature = "tls")]
+    pub fn tls(mut self, config: TlsConfig) -> Self {
+        self.tls = Some(config);
+        self
+    }
+
+    /// Sets the handler for received ASDUs.
+    ///
+    /// Handlers run on the receive thread of lib60870 and must not block for
+    /// long. The ASDU is only borrowed for the duration of the call.
+    pub fn on_asdu(mut self, handler: impl FnMut(&AsduRef) + Send + 'static) -> Self {
+        self.on_asdu = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets the handler for connection state changes.
+    pub fn on_connection_event(
+        mut self,
+        handler: impl FnMut(ConnectionEvent) + Send + 'static,
+    ) -> Self {
+        self.on_connection_event = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets the handler for every APDU sent (`true`) or received (`false`),
+    /// mostly useful for logging.
+    pub fn on_raw_message(mut self, handler: impl FnMut(&[u8], bool) + Send + 'static) -> Self {
+        self.on_raw_message = Some(Box::new(handler));
+        self
+    }
+
+    /// Creates the client. It is not connected yet.
+    pub fn build(self) -> Result<Cs104Client> {
+        let hostname = CString::new(self.hostname).map_err(|_| Error::NulByte)?;
+        let local_address = self
+            .local_address
+            .map(|(address, port)| Ok((CString::new(address)?, port)))
+            .transpose()
+            .map_err(|_: std::ffi::NulError| Error::NulByte)?;
+
+        let port = self.port.map_or(-1, c_int::from);
+        #[cfg(feature = "tls")]
+        if let Some(tls) = &self.tls {
+            tls.claim(Role::Client)?;
+        }
+        #[cfg(feature = "tls")]
+        let raw = match &self.tls {
+            Some(tls) => unsafe {
+                CS104_Connection_createSecure(hostname.as_ptr(), port, tls.as_raw())
+            },
+            None => unsafe { CS104_Connection_create(hostname.as_ptr(), port) },
+        };
+        #[cfg(not(feature = "tls"))]
+        let raw = unsafe { CS104_Connection_create(hostname.as_ptr(), port) };
+        assert!(!raw.is_null(), "CS104_Connection_create failed");
+
+        // file transfers see the ASDUs and events after the handlers
+        let files = Arc::new(FileReceiver::default());
+        let mut user_on_asdu = self.on_asdu;
+        let asdu_files = Arc::clone(&files);
+        let on_asdu: AsduHandler = Box::new(move |asdu: &AsduRef| {
+            if let Some(handler) = &mut user_on_asdu {
+                handler(asdu);
+            }
+            asdu_files.forward(asdu);
+        });
+        let mut user_on_connection_event = self.on_connection_event;
+        let event_files = Arc::clone(&files);
+        let on_connection_event: ConnectionHandler = Box::new(move |event| {
+            if let Some(handler) = &mut user_on_connection_event {
+                handler(event);
+            }
+            if matches!(event, ConnectionEvent::Closed | ConnectionEvent::Failed) {
+                event_files.clear();
+            }
+        });
+
+        let mut client = Cs104Client {
+            raw,
+            on_asdu: Some(Box::new(on_asdu)),
+            on_connection_event: Some(Box::new(on_connection_event)),
+            on_raw_message: self.on_raw_message.map(Box::new),
+            files,
+            file_transfer: Mutex::new(()),
+            #[cfg(feature = "tls")]
+            _tls: self.tls,
+        };
+        client.configure(&self.apci, &self.app_layer, local_address);
+        Ok(client)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Cs104ClientBuilder {
+    /// Sets how long the async client waits for the termination (ACT_TERM)
+    /// of a confirmed command, e.g. until all data of an interrogation has
+    /// arrived. Defaults to four times t1.
+    pub fn termination_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.termination_timeout = Some(timeout);
+        self
+    }
+
+    /// Creates an [`AsyncCs104Client`](super::AsyncCs104Client) and the
+    /// stream of ASDUs it receives. It is not connected yet.
+    ///
+    /// Handlers set with [`on_asdu`](Self::on_asdu) and
+    /// [`on_connection_event`](Self::on_connection_event) keep running on
+    /// the receive thread before the async client sees the ASDU or event.
+    pub fn build_async(self) -> Result<(super::AsyncCs104Client, super::AsduStream)> {
+        super::async_client::build(self)
+    }
+}
+
+impl fmt::Debug for Cs104ClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cs104ClientBuilder")
+            .field("hostname", &self.hostname)
+            .field("port", &self.port)
+            .field("local_address", &self.local_address)
+            .field("apci", &self.apci)
+            .field("app_layer", &self.app_layer)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A CS104 client connection to a server (controlled station).
+///
+/// The handlers given to the builder are owned by the client and dropped
+/// after the connection has been destroyed, so they are never called once
+/// the client is gone. A panicking handler does not unwind into lib60870;
+/// the panic is reported by the panic hook and the connection keeps running.
+///
+/// ```no_run
+/// use lib60870_sys::{CauseOfTransmission, Cs104Client};
+///
+/// let client = Cs104Client::builder("127.0.0.1")
+///     .on_asdu(|asdu| println!("{asdu:?}"))
+///     .build()?;
+/// client.connect()?;
+/// client.start_dt();
+/// client.send_interrogation(CauseOfTransmission::Activation, 1, 20)?;
+/// # Ok::<(), lib60870_sys::Error>(())
+/// ```
+pub struct Cs104Client {
+    raw: CS104_Connection,
+    on_asdu: Option<Box<AsduHandler>>,
+    on_connection_event: Option<Box<ConnectionHandler>>,
+    on_raw_message: Option<Box<RawMessageHandler>>,
+    files: Arc<FileReceiver>,
+    // file transfers run one at a time
+    file_transfer: Mutex<()>,
+    // used by the connection until it is destroyed
+    #[cfg(feature = "tls")]
+    _tls: Option<TlsConfig>,
+}
+
+// lib60870 serializes sending on an internal mutex, and the handlers are
+// only touched by the receive thread. Without threads it has no mutexes.
+unsafe impl Send for Cs104Client {}
+#[cfg(not(feature = "no-threads"))]
+unsafe impl Sync for Cs104Client {}
+
+impl Cs104Client {
+    /// Starts building a client for the server at `hostname`, an IP address
+    /// or host name.
+    pub fn builder(hostname: &str) -> Cs104ClientBuilder {
+        Cs104ClientBuilder {
+            hostname: hostname.to_owned(),
+            port: None,
+            local_address: None,
+            apci: ApciParameters::default(),
+            app_layer: AppLayerParameters::default(),
+            on_asdu: None,
+            on_connection_event: None,
+            on_raw_message: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+            #[cfg(feature = "tokio")]
+            termination_timeout: None,
+        }
+    }
+
+    fn configure(
+        &mut self,
+        apci: &ApciParameters,
+        app_layer: &AppLayerParameters,
+        local_address: Option<(CString, Option<u16>)>,
+    ) {
+        let mut apci = sCS104_APCIParameters::from(*apci);
+        let mut app_layer = sCS101_AppLayerParameters::from(*app_layer);
+        unsafe {
+            // both are copied by lib60870
+            CS104_Connection_setAPCIParameters(self.raw, &mut apci);
+            CS104_Connection_setAppLayerParameters(self.raw, &mut app_layer);
+            if let Some((address, port)) = &local_address {
+                CS104_Connection_setLocalAddress(
+                    self.raw,
+                    address.as_ptr(),
+                    port.map_or(-1, c_int::from),
+                );
+            }
+            if self.on_asdu.is_some() {
+                CS104_Connection_setASDUReceivedHandler(
+                    self.raw,
+                    Some(asdu_received),
+                    callback::parameter(&mut self.on_asdu),
+                );
+            }
+            if self.on_connection_event.is_some() {
+                CS104_Connection_setConnectionHandler(
+                    self.raw,
+                    Some(connection_event),
+                    callback::parameter(&mut self.on_connection_event),
+                );
+            }
+            if self.on_raw_message.is_some() {
+                CS104_Connection_setRawMessageHandler(
+                    self.raw,
+                    Some(raw_message),
+                    callback::parameter(&mut self.on_raw_message),
+                );
+            }
+        }
+    }
+
+    /// Connects to the server, blocking until the connection is established
+    /// or t0 has elapsed.
+    pub fn connect(&self) -> Result<()> {
+        if unsafe { CS104_Connection_connect(self.raw) } {
+            Ok(())
+        } else {
+            Err(Error::ConnectFailed)
+        }
+    }
+
+    /// Starts connecting to the server in the background. The outcome is
+    /// reported as [`ConnectionEvent::Opened`] or [`ConnectionEvent::Failed`].
+    pub fn connect_async(&self) {
+        unsafe { CS104_Connection_connectAsync(self.raw) }
+    }
+
+    /// Closes the connection. It can be opened again with
+    /// [`connect`](Self::connect).
+    pub fn close(&self) {
+        unsafe { CS104_Connection_close(self.raw) }
+    }
+
+    /// Sends STARTDT to activate data transfer.
+    pub fn start_dt(&self) {
+        unsafe { CS104_Connection_sendStartDT(self.raw) }
+    }
+
+    /// Sends STOPDT to stop data transfer.
+    pub fn stop_dt(&self) {
+        unsafe { CS104_Connection_sendStopDT(self.raw) }
+    }
+
+    /// The APCI parameters in use.
+    pub fn apci_parameters(&self) -> ApciParameters {
+        unsafe { ApciParameters::from(&*CS104_Connection_getAPCIParameters(self.raw)) }
+    }
+
+    /// The application layer parameters in use.
+    pub fn app_layer_parameters(&self) -> AppLayerParameters {
+        unsafe { AppLayerParameters::from(&*CS104_Connection_getAppLayerParameters(self.raw)) }
+    }
+
+    /// Whether k messages are unconfirmed, in which case the next send fails.
+    pub fn is_transmit_buffer_full(&self) -> bool {
+        unsafe { CS104_Connection_isTransmitBufferFull(self.raw) }
+    }
+
+    /// Sends an interrogation command (`C_IC_NA_1`) with the qualifier of
+    /// interrogation, e.g. 20 for a station interrogation.
+    pub fn send_interrogation(
+        &self,
+        cot: CauseOfTransmission,
+        common_address: u16,
+        qualifier: u8,
+    ) -> Result<()> {
+        sent(unsafe {
+            CS104_Connection_sendInterrogationCommand(
+                self.raw,
+                cot as CS101_CauseOfTransmission,
+                common_address.into(),
+                qualifier,
+            )
+        })
+    }
+
+    /// Sends a counter interrogation command (`C_CI_NA_1`) with the
+    /// qualifier of counter interrogation (QCC).
+    pub fn send_counter_interrogation(
+        &self,
+        cot: CauseOfTransmission,
+        common_address: u16,
+        qualifier: u8,
+    ) -> Result<()> {
+        sent(unsafe {
+            CS104_Connection_sendCounterInterrogationCommand(
+                self.raw,
+                cot as CS101_CauseOfTransmission,
+                common_address.into(),
+                qualifier,
+            )
+        })
+    }
+
+    /// Sends a read command (`C_RD_NA_1`) for one information object.
+    pub fn send_read(&self, common_address: u16, ioa: u32) -> Result<()> {
+        sent(unsafe {
+            CS104_Connection_sendReadCommand(self.raw, common_address.into(), ioa as c_int)
+        })
+    }
+
+    /// Sends a clock synchronization command (`C_CS_NA_1`).
+    pub fn send_clock_sync(&self, common_address: u16, time: Cp56Time2a) -> Result<()> {
+        let mut time = time;
+        sent(unsafe {
+            CS104_Connection_sendClockSyncCommand(
+                self.raw,
+                common_address.into(),
+                time.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// Sends a test command with time tag (`C_TS_TA_1`).
+    pub fn send_test_command(
+        &self,
+        common_address: u16,
+        counter: u16,
+        time: Cp56Time2a,
+    ) -> Result<()> {
+        let mut time = time;
+        sent(unsafe {
+            CS104_Connection_sendTestCommandWithTimestamp(
+                self.raw,
+                common_address.into(),
+                counter,
+                time.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// Sends a process command, e.g. a single command or a set point, in an
+    /// ASDU of its own.
+    ///
+    /// Use [`CauseOfTransmission::Activation`] to select or execute and
+    /// [`CauseOfTransmission::Deactivation`] to cancel the command.
+    pub fn send_command(
+        &self,
+        cot: CauseOfTransmission,
+        common_address: u16,
+        command: &InformationObject,
+    ) -> Result<()> {
+        let io = command.to_raw()?;
+        let ok = unsafe {
+            let ok = CS104_Connection_sendProcessCommandEx(
+                self.raw,
+                cot as CS101_CauseOfTransmission,
+                common_address.into(),
+                io,
+            );
+            InformationObject_destroy(io);
+            ok
+        };
+        sent(ok)
+    }
+
+    /// Sends an ASDU.
+    pub fn send_asdu(&self, asdu: &AsduRef) -> Result<()> {
+        sent(unsafe { CS104_Connection_sendASDU(self.raw, asdu.as_ptr()) })
+    }
+
+    /// The underlying `CS104_Connection`, for functions not covered by the
+    /// wrapper. It must not be destroyed and its handlers must not be
+    /// replaced.
+    pub fn as_raw(&self) -> CS104_Connection {
+        self.raw
+    }
+}
+
+/// File transfer (`F_*` types), see [`file`](mod@crate::file).
+///
+/// The calls block until the transfer is complete. Transfers run one at a
+/// time, and every response is awaited for at most t1.
+impl Cs104Client {
+    fn file_link(&self, common_address: u16) -> Cs104FileLink<'_> {
+        Cs104FileLink {
+            client: self,
+            files: self.files.subscribe(common_address),
+        }
+    }
+
+    /// Calls the directory of the station with `common_address`.
+    ///
+    /// Returns an empty directory if the station answers the call
+    /// negatively.
+    pub fn directory(&self, common_address: u16) -> Result<Vec<FileDirectory>> {
+        let _transfer = self.file_transfer.lock().unwrap_or_else(|e| e.into_inner());
+        file::directory(&mut self.file_link(common_address), common_address)
+    }
+
+    /// Downloads a file listed in the directory of the station with
+    /// `common_address`.
+    ///
+    /// Fails with [`Error::FileUnavailable`] if the station has no such
+    /// file and with [`Error::ChecksumMismatch`] if the file does not match
+    /// its checksum.
+    pub fn download(&self, common_address: u16, ioa: u32, name: u16) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.download_to(common_address, ioa, name, &mut data)?;
+        Ok(data)
+    }
+
+    /// Like [`download`](Self::download), but writes the file section by
+    /// section to `writer`, e.g. a `std::fs::File`, and returns its length.
+    /// Sections are only written once their checksum has been verified.
+    pub fn download_to(
+        &self,
+        common_address: u16,
+        ioa: u32,
+        name: u16,
+        mut writer: impl io::Write,
+    ) -> Result<u64> {
+        let _transfer = self.file_transfer.lock().unwrap_or_else(|e| e.into_inner());
+        let mut link = self.file_link(common_address);
+        file::download_to(&mut link, common_address, ioa, name, &mut writer)
+    }
+}
+
+/// A file transfer of a [`Cs104Client`].
+struct Cs104FileLink<'a> {
+    client: &'a Cs104Client,
+    files: std::sync::mpsc::Receiver<Asdu>,
+}
+
+impl FileLink for Cs104FileLink<'_> {
+    fn send(
+        &mut self,
+        cot: CauseOfTransmission,
+        common_address: u16,
+        object: &InformationObject,
+    ) -> Result<()> {
+        self.client.send_command(cot, common_address, object)
+    }
+
+    fn receive(&mut self) -> Result<Asdu> {
+        file::receive_within(&self.files, self.client.apci_parameters().t1)
+    }
+}
+
+impl Drop for Cs104Client {
+    fn drop(&mut self) {
+        // joins the receive thread, so the handlers are unused afterwards
+        unsafe { CS104_Connection_destroy(self.raw) }
+    }
+}
+
+impl fmt::Debug for Cs104Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cs104Client")
+            .field("apci", &self.apci_parameters())
+            .field("app_layer", &self.app_layer_parameters())
+            .finish_non_exhaustive()
+    }
+}
+
+unsafe extern "C" fn asdu_received(
+    parameter: *mut c_void,
+    _address: c_int,
+    asdu: CS101_ASDU,
+) -> bool {
+    let handler = &mut *parameter.cast::<AsduHandler>();
+    guard((), || handler(AsduRef::from_ptr(asdu)));
+    true
+}
+
+unsafe extern "C" fn connection_event(
+    parameter: *mut c_void,
+    _connection: CS104_Connection,
+    event: CS104_ConnectionEvent,
+) {
+    let handler = &mut *parameter.cast::<ConnectionHandler>();
+    if let Some(event) = ConnectionEvent::from_raw(event) {
+        guard((), || handler(event));
+    }
+}
+
+unsafe extern "C" fn raw_message(parameter: *mut c_void, msg: *mut u8, size: c_int, sent: bool) {
+    let handler = &mut *parameter.cast::<RawMessageHandler>();
+    let msg = if msg.is_null() || size <= 0 {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(msg, size as usize)
+    };
+    guard((), || handler(msg, sent));
+}
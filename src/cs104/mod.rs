@@ -0,0 +1,21 @@
+//! IEC 60870-5-104 (TCP/IP) client and server.
+
+#[cfg(feature = "tokio")]
+mod async_client;
+mod client;
+mod redundancy;
+#[cfg(feature = "tokio")]
+mod runner;
+mod server;
+
+#[cfg(feature = "tokio")]
+pub use async_client::{AsduStream, AsyncCs104Client};
+
+pub use client::{ConnectionEvent, Cs104Client, Cs104ClientBuilder};
+pub use redundancy::RedundancyGroup;
+#[cfg(feature = "tokio")]
+pub use runner::{ShutdownHandle, ThreadlessRunner};
+pub use server::{
+    Cs104Server, Cs104ServerBuilder, MasterConnection, PeerConnectionEvent, ServerMode,
+    SlaveHandler,
+};
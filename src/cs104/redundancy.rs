@@ -0,0 +1,139 @@
+//! Redundancy groups of a CS104 server.
+
+use std::ffi::{CStr, CString};
+use std::net::{IpAddr, SocketAddr};
+
+use crate::bindings::*;
+use crate::error::{Error, Result};
+
+/// A group of clients sharing one event queue, of which only one
+/// connection is active at a time.
+///
+/// Groups are added with
+/// [`Cs104ServerBuilder::redundancy_group`](super::Cs104ServerBuilder::redundancy_group),
+/// and need the server mode
+/// [`MultipleRedundancyGroups`](super::ServerMode::MultipleRedundancyGroups),
+/// the default once a group has been added.
+/// A client is assigned to the first group that allows its IP address;
+/// clients no group allows are assigned to the catch-all group, a group
+/// without allowed clients, or rejected if there is none. If there are
+/// several catch-all groups, the last one is used. Like lib60870, the
+/// addresses are compared together with their type: an IPv4-mapped IPv6
+/// address (`::ffff:a.b.c.d`) does not allow the IPv4 client, and an IPv4
+/// client of a dual-stack listener, which has such an address, is not
+/// allowed by its IPv4 address.
+///
+/// The groups are handed to lib60870 when the server is built and destroyed
+/// together with the server.
+///
+/// ```no_run
+/// use std::net::{IpAddr, Ipv4Addr};
+///
+/// use lib60870_sys::cs104::RedundancyGroup;
+/// use lib60870_sys::Cs104Server;
+///
+/// let scada = RedundancyGroup::new("scada").allowed_clients([
+///     IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+///     IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+/// ]);
+/// let server = Cs104Server::builder()
+///     .redundancy_group(scada)
+///     .redundancy_group(RedundancyGroup::catch_all("others"))
+///     .build(())?;
+/// # Ok::<(), lib60870_sys::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RedundancyGroup {
+    name: String,
+    allowed_clients: Vec<IpAddr>,
+}
+
+impl RedundancyGroup {
+    /// Creates a group without allowed clients, which is a catch-all group
+    /// until clients are added.
+    pub fn new(name: &str) -> Self {
+        RedundancyGroup {
+            name: name.to_owned(),
+            allowed_clients: Vec::new(),
+        }
+    }
+
+    /// Creates a group for all clients not allowed by another group.
+    pub fn catch_all(name: &str) -> Self {
+        Self::new(name)
+    }
+
+    /// Allows a client to join the group.
+    pub fn allowed_client(mut self, address: IpAddr) -> Self {
+        self.allowed_clients.push(address);
+        self
+    }
+
+    /// Allows several clients to join the group.
+    pub fn allowed_clients(mut self, addresses: impl IntoIterator<Item = IpAddr>) -> Self {
+        self.allowed_clients.extend(addresses);
+        self
+    }
+
+    /// Allows the clients at several socket addresses to join the group.
+    /// Only the IP addresses are compared; the ports are ignored.
+    pub fn allowed_socket_addresses(self, addresses: impl IntoIterator<Item = SocketAddr>) -> Self {
+        self.allowed_clients(addresses.into_iter().map(|address| address.ip()))
+    }
+
+    /// The name of the group.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The IP addresses of the allowed clients.
+    pub fn clients(&self) -> &[IpAddr] {
+        &self.allowed_clients
+    }
+
+    /// Whether the group takes all clients not allowed by another group.
+    pub fn is_catch_all(&self) -> bool {
+        self.allowed_clients.is_empty()
+    }
+
+    /// The name of the group for lib60870.
+    pub(super) fn c_name(&self) -> Result<CString> {
+        CString::new(self.name.as_str()).map_err(|_| Error::NulByte)
+    }
+
+    /// Creates the `CS104_RedundancyGroup` named `name`, owned by the
+    /// caller until it is added to a slave.
+    pub(super) fn to_raw(&self, name: &CStr) -> CS104_RedundancyGroup {
+        unsafe {
+            let raw = CS104_RedundancyGroup_create(name.as_ptr());
+            assert!(!raw.is_null(), "CS104_RedundancyGroup_create failed");
+            for client in &self.allowed_clients {
+                match client {
+                    IpAddr::V4(address) => CS104_RedundancyGroup_addAllowedClientEx(
+                        raw,
+                        address.octets().as_ptr(),
+                        eCS104_IPAddressType_IP_ADDRESS_TYPE_IPV4,
+                    ),
+                    IpAddr::V6(address) => CS104_RedundancyGroup_addAllowedClientEx(
+                        raw,
+                        address.octets().as_ptr(),
+                        eCS104_IPAddressType_IP_ADDRESS_TYPE_IPV6,
+                    ),
+                }
+            }
+            raw
+        }
+    }
+}
+
+/// The group lib60870 assigns a client at `peer` (address and port) to.
+pub(super) fn matching<'a>(
+    groups: &'a [RedundancyGroup],
+    peer: &str,
+) -> Option<&'a RedundancyGroup> {
+    let address = peer.parse::<SocketAddr>().ok().map(|peer| peer.ip());
+    let allowed = groups
+        .iter()
+        .find(|group| address.is_some_and(|address| group.allowed_clients.contains(&address)));
+    allowed.or_else(|| groups.iter().rev().find(|group| group.is_catch_all()))
+}
@@ -0,0 +1,154 @@
+//! Threadless CS104 server driven by a tokio task.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{mpsc, watch};
+use tokio::time::MissedTickBehavior;
+
+use crate::asdu::Asdu;
+use crate::cs104::{Cs104Server, SlaveHandler};
+
+/// Runs a [`Cs104Server`] without background threads by calling
+/// [`tick`](Cs104Server::tick) from a tokio task.
+///
+/// The server is only touched by the task running [`run`](Self::run), so
+/// this also works with the `no-threads` feature, where lib60870 has no
+/// locks. Spontaneous ASDUs are passed to that task through the channel
+/// returned by [`sender`](Self::sender) and enqueued between ticks.
+///
+/// ```no_run
+/// # async fn example() -> lib60870_sys::Result<()> {
+/// use std::time::Duration;
+///
+/// use lib60870_sys::cs104::ThreadlessRunner;
+/// use lib60870_sys::Cs104Server;
+///
+/// let server = Cs104Server::builder().port(2404).build(())?;
+/// let runner = ThreadlessRunner::new(server).tick_interval(Duration::from_millis(5));
+/// let events = runner.sender();
+/// let shutdown = runner.shutdown_handle();
+/// let task = tokio::spawn(runner.run());
+///
+/// // events.send(asdu).await ...
+/// shutdown.shutdown().await;
+/// let server = task.await.unwrap();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ThreadlessRunner<H: SlaveHandler> {
+    server: Cs104Server<H>,
+    interval: Duration,
+    sender: mpsc::Sender<Asdu>,
+    asdus: mpsc::Receiver<Asdu>,
+    shutdown: ShutdownHandle,
+    stopped: watch::Sender<bool>,
+}
+
+impl<H: SlaveHandler> ThreadlessRunner<H> {
+    /// Creates a runner for `server`, which must not have been started,
+    /// ticking every 10 ms and buffering up to 100 ASDUs.
+    pub fn new(server: Cs104Server<H>) -> Self {
+        Self::with_capacity(server, 100)
+    }
+
+    /// Creates a runner whose channel buffers up to `capacity` ASDUs before
+    /// senders have to wait.
+    pub fn with_capacity(server: Cs104Server<H>, capacity: usize) -> Self {
+        let (sender, asdus) = mpsc::channel(capacity.max(1));
+        let (requested, _) = watch::channel(false);
+        let (stopped, stopped_rx) = watch::channel(false);
+        ThreadlessRunner {
+            server,
+            interval: Duration::from_millis(10),
+            sender,
+            asdus,
+            shutdown: ShutdownHandle {
+                requested: Arc::new(requested),
+                stopped: stopped_rx,
+            },
+            stopped,
+        }
+    }
+
+    /// Sets how often [`tick`](Cs104Server::tick) is called. Shorter
+    /// intervals lower the latency at the cost of CPU time.
+    pub fn tick_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The server, e.g. to inspect its handler.
+    pub fn server(&self) -> &Cs104Server<H> {
+        &self.server
+    }
+
+    /// A sender for spontaneous ASDUs, which are put into the event queue
+    /// of the server.
+    pub fn sender(&self) -> mpsc::Sender<Asdu> {
+        self.sender.clone()
+    }
+
+    /// A handle to stop the runner.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    /// Starts the server and ticks it until shutdown is requested, then
+    /// enqueues the ASDUs still in the channel, ticks once more to send them
+    /// and stops the server. Returns the stopped server.
+    ///
+    /// Each tick runs on the calling task and may block it briefly while
+    /// lib60870 polls its sockets.
+    pub async fn run(self) -> Cs104Server<H> {
+        let ThreadlessRunner {
+            mut server,
+            interval,
+            sender,
+            mut asdus,
+            shutdown,
+            stopped,
+        } = self;
+        // the channel closes once all outside senders are gone
+        drop(sender);
+        let mut requested = shutdown.requested.subscribe();
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        server.start_threadless();
+        loop {
+            tokio::select! {
+                _ = requested.wait_for(|requested| *requested) => break,
+                Some(asdu) = asdus.recv() => server.enqueue_asdu(&asdu),
+                _ = ticker.tick() => server.tick(),
+            }
+        }
+
+        while let Ok(asdu) = asdus.try_recv() {
+            server.enqueue_asdu(&asdu);
+        }
+        server.tick();
+        server.stop_threadless();
+
+        stopped.send_replace(true);
+        server
+    }
+}
+
+/// Stops a [`ThreadlessRunner`].
+#[derive(Debug, Clone)]
+pub struct ShutdownHandle {
+    requested: Arc<watch::Sender<bool>>,
+    stopped: watch::Receiver<bool>,
+}
+
+impl ShutdownHandle {
+    /// Requests the runner to stop and waits until the server has been
+    /// stopped. Also returns if the runner has been dropped.
+    pub async fn shutdown(&self) {
+        self.requested.send_replace(true);
+        let mut stopped = self.stopped.clone();
+        let _ = stopped.wait_for(|stopped| *stopped).await;
+    }
+}
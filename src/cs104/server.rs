@@ -0,0 +1,732 @@
+//! CS104 server (controlled station).
+
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+
+use foreign_types::ForeignTypeRef;
+
+use crate::asdu::AsduRef;
+use crate::bindings::*;
+use crate::callback::guard;
+use crate::cs104::redundancy::{self, RedundancyGroup};
+use crate::error::{sent, Error, Result};
+use crate::parameters::{ApciParameters, AppLayerParameters};
+use crate::plugin::{Plugin, SlavePlugin};
+use crate::timestamp::Cp56Time2a;
+#[cfg(feature = "tls")]
+use crate::tls::{Role, TlsConfig};
+
+/// Connection state change reported by a CS104 server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PeerConnectionEvent {
+    /// A client has connected.
+    Opened,
+    /// The connection has been closed.
+    Closed,
+    /// The client sent STARTDT, data transfer is active.
+    Activated,
+    /// The client sent STOPDT, data transfer is stopped.
+    Deactivated,
+}
+
+impl PeerConnectionEvent {
+    #[allow(non_upper_case_globals)]
+    fn from_raw(event: CS104_PeerConnectionEvent) -> Option<Self> {
+        match event {
+            CS104_PeerConnectionEvent_CS104_CON_EVENT_CONNECTION_OPENED => {
+                Some(PeerConnectionEvent::Opened)
+            }
+            CS104_PeerConnectionEvent_CS104_CON_EVENT_CONNECTION_CLOSED => {
+                Some(PeerConnectionEvent::Closed)
+            }
+            CS104_PeerConnectionEvent_CS104_CON_EVENT_ACTIVATED => {
+                Some(PeerConnectionEvent::Activated)
+            }
+            CS104_PeerConnectionEvent_CS104_CON_EVENT_DEACTIVATED => {
+                Some(PeerConnectionEvent::Deactivated)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// How a CS104 server shares its event queues between connections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum ServerMode {
+    /// All clients share one queue; only one connection is active at a time.
+    #[default]
+    SingleRedundancyGroup,
+    /// Every connection has a queue of its own.
+    ConnectionIsRedundancyGroup,
+    /// Clients are assigned to the configured redundancy groups by address.
+    MultipleRedundancyGroups,
+}
+
+impl ServerMode {
+    fn to_raw(self) -> CS104_ServerMode {
+        match self {
+            ServerMode::SingleRedundancyGroup => {
+                CS104_ServerMode_CS104_MODE_SINGLE_REDUNDANCY_GROUP
+            }
+            ServerMode::ConnectionIsRedundancyGroup => {
+                CS104_ServerMode_CS104_MODE_CONNECTION_IS_REDUNDANCY_GROUP
+            }
+            ServerMode::MultipleRedundancyGroups => {
+                CS104_ServerMode_CS104_MODE_MULTIPLE_REDUNDANCY_GROUPS
+            }
+        }
+    }
+}
+
+/// Connection to a master, handed to [`SlaveHandler`] and
+/// [`Cs101SlaveHandler`](crate::cs101::Cs101SlaveHandler) callbacks.
+///
+/// It is only valid for the duration of the callback.
+pub struct MasterConnection {
+    raw: IMasterConnection,
+}
+
+impl MasterConnection {
+    /// Wraps a connection passed to a callback by lib60870.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be valid for the lifetime of the returned value.
+    pub(crate) unsafe fn from_raw(raw: IMasterConnection) -> Self {
+        Self { raw }
+    }
+
+    /// Whether data transfer is active, i.e. the client sent STARTDT.
+    pub fn is_ready(&self) -> bool {
+        unsafe { IMasterConnection_isReady(self.raw) }
+    }
+
+    /// Sends an ASDU to the client.
+    pub fn send_asdu(&self, asdu: &AsduRef) -> Result<()> {
+        sent(unsafe { IMasterConnection_sendASDU(self.raw, asdu.as_ptr()) })
+    }
+
+    /// Sends an activation confirmation for a received command. The ASDU
+    /// is modified in place and sent back, so a received ASDU is passed as
+    /// a copy (`to_owned()`).
+    pub fn send_act_con(&self, asdu: &mut AsduRef, negative: bool) -> Result<()> {
+        sent(unsafe { IMasterConnection_sendACT_CON(self.raw, asdu.as_ptr(), negative) })
+    }
+
+    /// Sends an activation termination for a received command. The ASDU is
+    /// modified in place and sent back.
+    pub fn send_act_term(&self, asdu: &mut AsduRef) -> Result<()> {
+        sent(unsafe { IMasterConnection_sendACT_TERM(self.raw, asdu.as_ptr()) })
+    }
+
+    /// Closes the connection.
+    pub fn close(&self) {
+        unsafe { IMasterConnection_close(self.raw) }
+    }
+
+    /// The address and port of the client, e.g. `192.168.1.1:34521`. Empty
+    /// for CS101 connections.
+    pub fn peer_address(&self) -> String {
+        let mut buf = [0 as c_char; 60];
+        unsafe {
+            IMasterConnection_getPeerAddress(self.raw, buf.as_mut_ptr(), buf.len() as c_int);
+            CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned()
+        }
+    }
+
+    /// The application layer parameters of the connection.
+    pub fn app_layer_parameters(&self) -> AppLayerParameters {
+        unsafe {
+            AppLayerParameters::from(&*IMasterConnection_getApplicationLayerParameters(self.raw))
+        }
+    }
+
+    /// The underlying `IMasterConnection`.
+    pub fn as_raw(&self) -> IMasterConnection {
+        self.raw
+    }
+}
+
+impl fmt::Debug for MasterConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MasterConnection")
+            .field("peer_address", &self.peer_address())
+            .field("is_ready", &self.is_ready())
+            .finish()
+    }
+}
+
+/// Callbacks of a CS104 server.
+///
+/// Every method has a default that does nothing. Methods returning `bool`
+/// report whether the ASDU has been handled; unhandled ASDUs are passed on
+/// to [`asdu`](Self::asdu), and if that does not handle them either the
+/// server answers with an unknown type identification.
+///
+/// Callbacks run on the threads of lib60870, concurrently for different
+/// connections, and must not block for long. A panic is caught at the FFI
+/// boundary and treated as "not handled".
+#[allow(unused_variables)]
+pub trait SlaveHandler: Send + Sync + 'static {
+    /// A client at `peer` (address and port) tries to connect. Return
+    /// `false` to reject it.
+    fn connection_request(&self, peer: &str) -> bool {
+        true
+    }
+
+    /// A connection has been opened, closed, activated or deactivated.
+    fn connection_event(&self, connection: &MasterConnection, event: PeerConnectionEvent) {}
+
+    /// The connection of `group` that is active changed: `connection` has
+    /// been activated (`active == true`) or deactivated. Only called for
+    /// servers with [redundancy groups](RedundancyGroup), after
+    /// [`connection_event`](Self::connection_event).
+    fn redundancy_group_changed(
+        &self,
+        connection: &MasterConnection,
+        group: &RedundancyGroup,
+        active: bool,
+    ) {
+    }
+
+    /// Interrogation command (`C_IC_NA_1`) with the qualifier of
+    /// interrogation.
+    fn interrogation(&self, connection: &MasterConnection, asdu: &AsduRef, qualifier: u8) -> bool {
+        false
+    }
+
+    /// Counter interrogation command (`C_CI_NA_1`) with the qualifier of
+    /// counter interrogation.
+    fn counter_interrogation(
+        &self,
+        connection: &MasterConnection,
+        asdu: &AsduRef,
+        qualifier: u8,
+    ) -> bool {
+        false
+    }
+
+    /// Read command (`C_RD_NA_1`) for the object at `ioa`.
+    fn read(&self, connection: &MasterConnection, asdu: &AsduRef, ioa: u32) -> bool {
+        false
+    }
+
+    /// Clock synchronization command (`C_CS_NA_1`).
+    ///
+    /// The server answers with a positive activation confirmation if this
+    /// returns `true` and a negative one otherwise, so unlike the other
+    /// commands it is never passed on to [`asdu`](Self::asdu). `time` may be
+    /// changed to the time to send back in the confirmation.
+    fn clock_sync(
+        &self,
+        connection: &MasterConnection,
+        asdu: &AsduRef,
+        time: &mut Cp56Time2a,
+    ) -> bool {
+        false
+    }
+
+    /// Any ASDU not handled by the other callbacks.
+    fn asdu(&self, connection: &MasterConnection, asdu: &AsduRef) -> bool {
+        false
+    }
+
+    /// Every APDU sent (`sent == true`) or received, mostly useful for
+    /// logging.
+    fn raw_message(&self, connection: &MasterConnection, message: &[u8], sent: bool) {}
+}
+
+/// Builder for a [`Cs104Server`].
+///
+/// Created by [`Cs104Server::builder`].
+#[derive(Debug, Clone)]
+pub struct Cs104ServerBuilder {
+    low_priority_queue_size: usize,
+    high_priority_queue_size: usize,
+    local_address: Option<String>,
+    port: Option<u16>,
+    mode: Option<ServerMode>,
+    max_open_connections: Option<usize>,
+    apci: ApciParameters,
+    app_layer: AppLayerParameters,
+    redundancy_groups: Vec<RedundancyGroup>,
+    #[cfg(feature = "tls")]
+    tls: Option<TlsConfig>,
+}
+
+impl Cs104ServerBuilder {
+    /// Sets the number of ASDUs the event (low priority) and the response
+    /// (high priority) queues can hold. Defaults to 100 each.
+    pub fn queue_sizes(mut self, low_priority: usize, high_priority: usize) -> Self {
+        self.low_priority_queue_size = low_priority;
+        self.high_priority_queue_size = high_priority;
+        self
+    }
+
+    /// Sets the local address to listen on. Defaults to all interfaces.
+    pub fn local_address(mut self, address: &str) -> Self {
+        self.local_address = Some(address.to_owned());
+        self
+    }
+
+    /// Sets the TCP port to listen on. Defaults to 2404.
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets how event queues are shared between connections. Defaults to
+    /// [`SingleRedundancyGroup`](ServerMode::SingleRedundancyGroup), or
+    /// [`MultipleRedundancyGroups`](ServerMode::MultipleRedundancyGroups)
+    /// once a redundancy group has been added.
+    pub fn server_mode(mut self, mode: ServerMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Adds a redundancy group, which needs the server mode
+    /// [`MultipleRedundancyGroups`](ServerMode::MultipleRedundancyGroups).
+    /// Building fails with [`Error::InvalidServerMode`] if another mode has
+    /// been set.
+    pub fn redundancy_group(mut self, group: RedundancyGroup) -> Self {
+        self.redundancy_groups.push(group);
+        self
+    }
+
+    /// Limits the number of concurrently open connections.
+    pub fn max_open_connections(mut self, max: usize) -> Self {
+        self.max_open_connections = Some(max);
+        self
+    }
+
+    /// Sets the APCI parameters (k, w and the timeouts t0 to t3).
+    pub fn apci_parameters(mut self, parameters: ApciParameters) -> Self {
+        self.apci = parameters;
+        self
+    }
+
+    /// Sets the application layer parameters.
+    pub fn app_layer_parameters(mut self, parameters: AppLayerParameters) -> Self {
+        self.app_layer = parameters;
+        self
+    }
+
+    /// Requires clients to connect with TLS. The port then defaults to
+    /// 19998.
+    #[cfg(feature = "tls")]
+    pub fn tls(mut self, config: TlsConfig) -> Self {
+        self.tls = Some(config);
+        self
+    }
+
+    /// Creates the server with `handler` for its callbacks. It does not
+    /// listen until `start` or
+    /// [`start_threadless`](Cs104Server::start_threadless) is called.
+    pub fn build<H: SlaveHandler>(self, handler: H) -> Result<Cs104Server<H>> {
+        let mode = match self.mode {
+            Some(mode)
+                if mode != ServerMode::MultipleRedundancyGroups
+                    && !self.redundancy_groups.is_empty() =>
+            {
+                return Err(Error::InvalidServerMode);
+            }
+            Some(mode) => mode,
+            None if self.redundancy_groups.is_empty() => ServerMode::default(),
+            None => ServerMode::MultipleRedundancyGroups,
+        };
+        let local_address = self
+            .local_address
+            .map(CString::new)
+            .transpose()
+            .map_err(|_| Error::NulByte)?;
+        let group_names = self
+            .redundancy_groups
+            .iter()
+            .map(RedundancyGroup::c_name)
+            .collect::<Result<Vec<_>>>()?;
+
+        let low_priority = queue_size(self.low_priority_queue_size);
+        let high_priority = queue_size(self.high_priority_queue_size);
+        #[cfg(feature = "tls")]
+        if let Some(tls) = &self.tls {
+            tls.claim(Role::Server)?;
+        }
+        #[cfg(feature = "tls")]
+        let raw = match &self.tls {
+            Some(tls) => unsafe {
+                CS104_Slave_createSecure(low_priority, high_priority, tls.as_raw())
+            },
+            None => unsafe { CS104_Slave_create(low_priority, high_priority) },
+        };
+        #[cfg(not(feature = "tls"))]
+        let raw = unsafe { CS104_Slave_create(low_priority, high_priority) };
+        assert!(!raw.is_null(), "CS104_Slave_create failed");
+
+        let handler = Box::new(handler);
+        let connection_events = Box::new(ConnectionEvents {
+            handler: &*handler,
+            redundancy_groups: self.redundancy_groups,
+        });
+        let server = Cs104Server {
+            raw,
+            handler,
+            connection_events,
+            plugins: Vec::new(),
+            #[cfg(feature = "tls")]
+            _tls: self.tls,
+        };
+        unsafe {
+            *CS104_Slave_getConnectionParameters(raw) = self.apci.into();
+            *CS104_Slave_getAppLayerParameters(raw) = self.app_layer.into();
+            if let Some(address) = &local_address {
+                CS104_Slave_setLocalAddress(raw, address.as_ptr());
+            }
+            if let Some(port) = self.port {
+                CS104_Slave_setLocalPort(raw, port.into());
+            }
+            CS104_Slave_setServerMode(raw, mode.to_raw());
+            // the slave destroys the groups
+            for (group, name) in server.redundancy_groups().iter().zip(&group_names) {
+                CS104_Slave_addRedundancyGroup(raw, group.to_raw(name));
+            }
+            if let Some(max) = self.max_open_connections {
+                CS104_Slave_setMaxOpenConnections(raw, queue_size(max));
+            }
+            server.set_handlers();
+        }
+        Ok(server)
+    }
+}
+
+fn queue_size(size: usize) -> c_int {
+    size.min(c_int::MAX as usize) as c_int
+}
+
+/// A CS104 server.
+///
+/// The handler is owned by the server and dropped after the server has
+/// been stopped and destroyed.
+///
+/// `start` and `stop` run the server on background threads and are not
+/// available with the `no-threads` feature. Without threads lib60870 also
+/// has no locks, so the server must then only be used from one thread at a
+/// time; `ThreadlessRunner` (`tokio` feature) takes care of that.
+///
+/// ```no_run
+/// use lib60870_sys::cs104::{MasterConnection, SlaveHandler};
+/// use lib60870_sys::{AsduRef, Cs104Server};
+///
+/// struct Rtu;
+///
+/// impl SlaveHandler for Rtu {
+///     fn interrogation(&self, connection: &MasterConnection, asdu: &AsduRef, _qoi: u8) -> bool {
+///         let mut response = asdu.to_owned();
+///         let _ = connection.send_act_con(&mut response, false);
+///         let _ = connection.send_act_term(&mut response);
+///         true
+///     }
+/// }
+///
+/// let server = Cs104Server::builder().port(2404).build(Rtu)?;
+/// server.start();
+/// # Ok::<(), lib60870_sys::Error>(())
+/// ```
+pub struct Cs104Server<H: SlaveHandler> {
+    raw: CS104_Slave,
+    handler: Box<H>,
+    connection_events: Box<ConnectionEvents<H>>,
+    // called by the slave until it is destroyed
+    plugins: Vec<Plugin>,
+    // used by the slave until it is destroyed
+    #[cfg(feature = "tls")]
+    _tls: Option<TlsConfig>,
+}
+
+// lib60870 guards its queues and connection list with mutexes, and the
+// handler is Sync. Without threads it has no mutexes.
+unsafe impl<H: SlaveHandler> Send for Cs104Server<H> {}
+#[cfg(not(feature = "no-threads"))]
+unsafe impl<H: SlaveHandler> Sync for Cs104Server<H> {}
+
+impl Cs104Server<()> {
+    /// Starts building a server.
+    pub fn builder() -> Cs104ServerBuilder {
+        Cs104ServerBuilder {
+            low_priority_queue_size: 100,
+            high_priority_queue_size: 100,
+            local_address: None,
+            port: None,
+            mode: None,
+            max_open_connections: None,
+            apci: ApciParameters::default(),
+            app_layer: AppLayerParameters::default(),
+            redundancy_groups: Vec::new(),
+            #[cfg(feature = "tls")]
+            tls: None,
+        }
+    }
+}
+
+/// A server whose callbacks all use the defaults.
+impl SlaveHandler for () {}
+
+impl<H: SlaveHandler> Cs104Server<H> {
+    unsafe fn set_handlers(&self) {
+        let raw = self.raw;
+        let parameter = &*self.handler as *const H as *mut c_void;
+        CS104_Slave_setConnectionRequestHandler(raw, Some(connection_request::<H>), parameter);
+        CS104_Slave_setConnectionEventHandler(
+            raw,
+            Some(connection_event::<H>),
+            &*self.connection_events as *const ConnectionEvents<H> as *mut c_void,
+        );
+        CS104_Slave_setInterrogationHandler(raw, Some(interrogation::<H>), parameter);
+        CS104_Slave_setCounterInterrogationHandler(
+            raw,
+            Some(counter_interrogation::<H>),
+            parameter,
+        );
+        CS104_Slave_setReadHandler(raw, Some(read::<H>), parameter);
+        CS104_Slave_setClockSyncHandler(raw, Some(clock_sync::<H>), parameter);
+        CS104_Slave_setASDUHandler(raw, Some(asdu::<H>), parameter);
+        CS104_Slave_setRawMessageHandler(raw, Some(raw_message::<H>), parameter);
+    }
+
+    /// The handler passed to the builder.
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Adds a plugin, which sees received ASDUs before the handler. The
+    /// plugin is dropped after the server has been destroyed.
+    ///
+    /// Fails with [`Error::AlreadyStarted`] once the server is running.
+    pub fn add_plugin<P: SlavePlugin>(&mut self, plugin: P) -> Result<()> {
+        if self.is_running() {
+            return Err(Error::AlreadyStarted);
+        }
+        let mut plugin = Plugin::new(plugin);
+        unsafe { CS104_Slave_addPlugin(self.raw, plugin.as_raw()) };
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    /// The redundancy groups passed to the builder.
+    pub fn redundancy_groups(&self) -> &[RedundancyGroup] {
+        &self.connection_events.redundancy_groups
+    }
+
+    /// Starts listening and serving clients on background threads.
+    #[cfg(not(feature = "no-threads"))]
+    pub fn start(&self) {
+        unsafe { CS104_Slave_start(self.raw) }
+    }
+
+    /// Closes all connections and stops listening, waiting for the
+    /// background threads to finish.
+    #[cfg(not(feature = "no-threads"))]
+    pub fn stop(&self) {
+        unsafe { CS104_Slave_stop(self.raw) }
+    }
+
+    /// Starts listening without background threads. Connections are only
+    /// served while [`tick`](Self::tick) is called.
+    pub fn start_threadless(&mut self) {
+        unsafe { CS104_Slave_startThreadless(self.raw) }
+    }
+
+    /// Accepts connections, handles received messages, sends queued events
+    /// and runs the protocol timers once. Has to be called periodically
+    /// after [`start_threadless`](Self::start_threadless).
+    pub fn tick(&mut self) {
+        unsafe { CS104_Slave_tick(self.raw) }
+    }
+
+    /// Closes all connections and stops listening after
+    /// [`start_threadless`](Self::start_threadless).
+    pub fn stop_threadless(&mut self) {
+        unsafe { CS104_Slave_stopThreadless(self.raw) }
+    }
+
+    /// Whether the server is listening.
+    pub fn is_running(&self) -> bool {
+        unsafe { CS104_Slave_isRunning(self.raw) }
+    }
+
+    /// The number of open connections.
+    pub fn open_connections(&self) -> usize {
+        unsafe { CS104_Slave_getOpenConnections(self.raw).max(0) as usize }
+    }
+
+    /// Limits the number of concurrently open connections.
+    pub fn set_max_open_connections(&self, max: usize) {
+        unsafe { CS104_Slave_setMaxOpenConnections(self.raw, queue_size(max)) }
+    }
+
+    /// Copies an ASDU into the event queue to be sent to the active
+    /// connections. If the queue is full the oldest event is dropped.
+    pub fn enqueue_asdu(&self, asdu: &AsduRef) {
+        unsafe { CS104_Slave_enqueueASDU(self.raw, asdu.as_ptr()) }
+    }
+
+    /// The APCI parameters of the server.
+    pub fn apci_parameters(&self) -> ApciParameters {
+        unsafe { ApciParameters::from(&*CS104_Slave_getConnectionParameters(self.raw)) }
+    }
+
+    /// The application layer parameters of the server.
+    pub fn app_layer_parameters(&self) -> AppLayerParameters {
+        unsafe { AppLayerParameters::from(&*CS104_Slave_getAppLayerParameters(self.raw)) }
+    }
+
+    /// The underlying `CS104_Slave`, for functions not covered by the
+    /// wrapper. It must not be destroyed and its handlers must not be
+    /// replaced.
+    pub fn as_raw(&self) -> CS104_Slave {
+        self.raw
+    }
+}
+
+impl<H: SlaveHandler> Drop for Cs104Server<H> {
+    fn drop(&mut self) {
+        // stops the server first, so the handler is unused afterwards
+        unsafe { CS104_Slave_destroy(self.raw) }
+    }
+}
+
+impl<H: SlaveHandler> fmt::Debug for Cs104Server<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cs104Server")
+            .field("is_running", &self.is_running())
+            .field("open_connections", &self.open_connections())
+            .finish_non_exhaustive()
+    }
+}
+
+unsafe extern "C" fn connection_request<H: SlaveHandler>(
+    parameter: *mut c_void,
+    ip_address: *const c_char,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let peer = CStr::from_ptr(ip_address).to_string_lossy();
+    guard(false, || handler.connection_request(&peer))
+}
+
+/// What the connection event handler needs to report the redundancy group
+/// of a connection.
+struct ConnectionEvents<H> {
+    // points into the box of the handler, owned by the same server
+    handler: *const H,
+    redundancy_groups: Vec<RedundancyGroup>,
+}
+
+unsafe extern "C" fn connection_event<H: SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    event: CS104_PeerConnectionEvent,
+) {
+    let events = &*parameter.cast::<ConnectionEvents<H>>();
+    let handler = &*events.handler;
+    let connection = MasterConnection::from_raw(connection);
+    let Some(event) = PeerConnectionEvent::from_raw(event) else {
+        return;
+    };
+    guard((), || handler.connection_event(&connection, event));
+
+    let active = match event {
+        PeerConnectionEvent::Activated => true,
+        PeerConnectionEvent::Deactivated => false,
+        _ => return,
+    };
+    guard((), || {
+        let peer = connection.peer_address();
+        if let Some(group) = redundancy::matching(&events.redundancy_groups, &peer) {
+            handler.redundancy_group_changed(&connection, group, active);
+        }
+    });
+}
+
+unsafe extern "C" fn interrogation<H: SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+    qoi: u8,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    guard(false, || {
+        handler.interrogation(&connection, AsduRef::from_ptr(asdu), qoi)
+    })
+}
+
+unsafe extern "C" fn counter_interrogation<H: SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+    qcc: QualifierOfCIC,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    guard(false, || {
+        handler.counter_interrogation(&connection, AsduRef::from_ptr(asdu), qcc)
+    })
+}
+
+unsafe extern "C" fn read<H: SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+    ioa: c_int,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    guard(false, || {
+        handler.read(&connection, AsduRef::from_ptr(asdu), ioa as u32)
+    })
+}
+
+unsafe extern "C" fn clock_sync<H: SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+    new_time: CP56Time2a,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    let mut time = Cp56Time2a::from_ptr(new_time);
+    let handled = guard(false, || {
+        handler.clock_sync(&connection, AsduRef::from_ptr(asdu), &mut time)
+    });
+    *new_time = time.0;
+    handled
+}
+
+unsafe extern "C" fn asdu<H: SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+) -> bool {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    guard(false, || handler.asdu(&connection, AsduRef::from_ptr(asdu)))
+}
+
+unsafe extern "C" fn raw_message<H: SlaveHandler>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    msg: *mut u8,
+    size: c_int,
+    sent: bool,
+) {
+    let handler = &*parameter.cast::<H>();
+    let connection = MasterConnection::from_raw(connection);
+    let msg = if msg.is_null() || size <= 0 {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(msg, size as usize)
+    };
+    guard((), || handler.raw_message(&connection, msg, sent));
+}
@@ -0,0 +1,109 @@
+//! Error type of the safe wrappers.
+
+use std::fmt;
+use std::io;
+
+use crate::cs101::SerialPortError;
+#[cfg(feature = "tls")]
+use crate::tls::TlsError;
+use crate::types::{CauseOfTransmission, TypeId};
+
+/// Errors returned by the safe wrappers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The information object does not fit into the ASDU, or its type or
+    /// address does not match the objects already in it.
+    AsduFull,
+    /// lib60870 has no encoder or decoder for the type.
+    UnsupportedType(TypeId),
+    /// The type identification is not defined by the standard.
+    UnknownTypeId(u8),
+    /// The time tag is marked invalid or its fields are out of range.
+    InvalidTimestamp,
+    /// The time cannot be represented by the time tag.
+    TimestampOutOfRange,
+    /// A string passed to lib60870 contains a NUL byte.
+    NulByte,
+    /// The connection could not be established.
+    ConnectFailed,
+    /// The message was not sent because the connection is not active or the
+    /// transmit buffer is full.
+    NotSent,
+    /// The serial port could not be opened.
+    SerialPort(SerialPortError),
+    /// The queue is full and the ASDU was not enqueued.
+    QueueFull,
+    /// The call is only allowed before the server or slave is started.
+    AlreadyStarted,
+    /// Redundancy groups were added to a server whose mode was set to
+    /// something other than `MultipleRedundancyGroups`.
+    InvalidServerMode,
+    /// The server rejected the command, with a negative confirmation or a
+    /// response naming the unknown part (`UnknownTypeId`, `UnknownCa`, ...).
+    Rejected(CauseOfTransmission),
+    /// No response arrived in time.
+    Timeout,
+    /// The connection was closed before the response arrived.
+    ConnectionClosed,
+    /// The station has no such file or refused to send it.
+    FileUnavailable,
+    /// A received section or file does not match its checksum (CHS).
+    ChecksumMismatch,
+    /// Writing a received file failed.
+    Io(io::ErrorKind),
+    /// A TLS certificate, key or CRL could not be loaded.
+    #[cfg(feature = "tls")]
+    Tls(TlsError),
+    /// The TLS configuration is already used by a server and cannot be used
+    /// by a client, or the other way round.
+    #[cfg(feature = "tls")]
+    TlsRoleMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::AsduFull => f.write_str("information object does not fit into the ASDU"),
+            Error::UnsupportedType(type_id) => write!(f, "unsupported type {type_id}"),
+            Error::UnknownTypeId(type_id) => write!(f, "unknown type id {type_id}"),
+            Error::InvalidTimestamp => f.write_str("invalid time tag"),
+            Error::TimestampOutOfRange => f.write_str("time out of range of the time tag"),
+            Error::NulByte => f.write_str("string contains a NUL byte"),
+            Error::ConnectFailed => f.write_str("failed to connect"),
+            Error::NotSent => f.write_str("message not sent"),
+            Error::SerialPort(error) => write!(f, "serial port: {error}"),
+            Error::QueueFull => f.write_str("queue is full"),
+            Error::AlreadyStarted => f.write_str("already started"),
+            Error::InvalidServerMode => {
+                f.write_str("redundancy groups need the multiple redundancy groups mode")
+            }
+            Error::Rejected(cot) => write!(f, "command rejected ({cot})"),
+            Error::Timeout => f.write_str("timed out waiting for a response"),
+            Error::ConnectionClosed => f.write_str("connection closed"),
+            Error::FileUnavailable => f.write_str("file not available"),
+            Error::ChecksumMismatch => f.write_str("checksum mismatch"),
+            Error::Io(kind) => write!(f, "I/O error: {kind}"),
+            #[cfg(feature = "tls")]
+            Error::Tls(error) => write!(f, "TLS: {error}"),
+            #[cfg(feature = "tls")]
+            Error::TlsRoleMismatch => {
+                f.write_str("TLS configuration is already used for the other side")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Result type of the safe wrappers.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Maps the outcome of a lib60870 send function to [`Error::NotSent`].
+pub(crate) fn sent(ok: bool) -> Result<()> {
+    if ok {
+        Ok(())
+    } else {
+        Err(Error::NotSent)
+    }
+}
@@ -0,0 +1,778 @@
+//! File transfer (`F_*` types) in monitor direction.
+//!
+//! [`FileServer`] serves the files of a [`FileProvider`] as a
+//! [`SlavePlugin`] of a [`Cs104Server`](crate::Cs104Server) or
+//! [`Cs101Slave`](crate::cs101::Cs101Slave). Masters list and download them
+//! with `directory`, `download` and `download_to` of
+//! [`Cs104Client`](crate::Cs104Client),
+//! [`Cs101Master`](crate::cs101::Cs101Master) and, with the `tokio` feature,
+//! `AsyncCs104Client`.
+//!
+//! A file is split into up to 255 sections, each sent as segments
+//! (`F_SG_NA_1`) followed by the checksum of the section (`F_LS_NA_1`). The
+//! master acknowledges every section and finally the file, whose checksum is
+//! the sum of the section checksums.
+
+use std::fmt;
+use std::io;
+use std::sync::{mpsc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::asdu::{Asdu, AsduRef};
+use crate::bindings::*;
+use crate::cs104::MasterConnection;
+use crate::error::{Error, Result};
+use crate::information_object::{
+    FileAck, FileCallOrSelect, FileDirectory, FileLastSegmentOrSection, FileReady, FileSegment,
+    InformationObject as Io, SectionReady,
+};
+use crate::plugin::{PluginResult, SlavePlugin};
+use crate::types::{CauseOfTransmission, TypeId};
+
+/// Default length of a section in bytes.
+const SECTION_SIZE: usize = 64 * 1024;
+
+/// Longest file the length of file (LOF), a three-octet field, can
+/// describe.
+const MAX_FILE_LENGTH: usize = 0xFF_FFFF;
+
+/// A transfer is dropped once the tasks of its connection have not run for
+/// this long, as lib60870 no longer runs them for a closed connection. Open
+/// connections are served far more often.
+const STALE_TRANSFER: Duration = Duration::from_secs(30);
+
+/// Segments sent per call of the plugin, so a large section does not
+/// overrun the queue of the connection.
+const SEGMENTS_PER_TASK: usize = 16;
+
+/// Checksum (CHS) of a section or file: the sum of all octets modulo 256.
+pub fn checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0, |sum, byte| sum.wrapping_add(*byte))
+}
+
+/// Files offered by a [`FileServer`].
+///
+/// Files are identified by the IOA and the name of file (NOF) they are
+/// listed with in the directory. Methods are called on the threads of
+/// lib60870, concurrently for different connections.
+pub trait FileProvider: Send + Sync + 'static {
+    /// The directory of the station with `common_address`. The last file
+    /// (LFD) flag of the status is set by the server.
+    fn directory(&self, common_address: u16) -> Vec<FileDirectory>;
+
+    /// The contents of a file, or `None` if there is no such file. Called
+    /// when the master selects the file; the contents are kept until the
+    /// transfer is finished. Files longer than 0xFF_FFFF octets are refused
+    /// like missing ones.
+    fn read(&self, common_address: u16, ioa: u32, name: u16) -> Option<Vec<u8>>;
+}
+
+/// A file being sent to one connection.
+struct Transfer {
+    /// Address of the `IMasterConnection`, only used to tell connections
+    /// apart.
+    connection: usize,
+    /// Peer address of the connection, as lib60870 may reuse the memory of
+    /// a closed connection for a new one.
+    peer: String,
+    common_address: u16,
+    ioa: u32,
+    name: u16,
+    data: Vec<u8>,
+    section_size: usize,
+    /// The current section, starting at 1.
+    section: u8,
+    /// The next segment of the current section while it is being sent.
+    offset: Option<usize>,
+    /// When the connection was last served.
+    seen: Instant,
+}
+
+impl Transfer {
+    fn sections(&self) -> usize {
+        ((self.data.len() + self.section_size - 1) / self.section_size).max(1)
+    }
+
+    fn section_data(&self) -> &[u8] {
+        let start = (usize::from(self.section) - 1) * self.section_size;
+        let end = (start + self.section_size).min(self.data.len());
+        &self.data[start.min(end)..end]
+    }
+
+    fn is_for(&self, connection: &MasterConnection) -> bool {
+        self.connection == connection.as_raw() as usize && self.peer == connection.peer_address()
+    }
+
+    fn is(&self, call_ioa: u32, call_name: u16) -> bool {
+        self.ioa == call_ioa && self.name == call_name
+    }
+
+    fn section_ready(&self) -> Io {
+        Io::F_SR_NA_1(SectionReady {
+            ioa: self.ioa,
+            name: self.name,
+            section: self.section,
+            length: self.section_data().len() as u32,
+            not_ready: false,
+        })
+    }
+
+    /// Sends the next segments of the current section, and its checksum
+    /// once all have been sent.
+    fn send_segments(&mut self, connection: &MasterConnection) {
+        let Some(mut offset) = self.offset else {
+            return;
+        };
+        let max_size = unsafe {
+            FileSegment_GetMaxDataSize(IMasterConnection_getApplicationLayerParameters(
+                connection.as_raw(),
+            ))
+        }
+        .clamp(1, u8::MAX.into()) as usize;
+
+        let section = self.section_data();
+        for _ in 0..SEGMENTS_PER_TASK {
+            if offset >= section.len() {
+                break;
+            }
+            let end = (offset + max_size).min(section.len());
+            let segment = Io::F_SG_NA_1(FileSegment {
+                ioa: self.ioa,
+                name: self.name,
+                section: self.section,
+                data: section[offset..end].to_vec(),
+            });
+            if send(connection, self.common_address, &segment).is_err() {
+                // retried by the next task
+                self.offset = Some(offset);
+                return;
+            }
+            offset = end;
+        }
+        if offset < section.len() {
+            self.offset = Some(offset);
+            return;
+        }
+
+        let last = Io::F_LS_NA_1(FileLastSegmentOrSection {
+            ioa: self.ioa,
+            name: self.name,
+            section: self.section,
+            qualifier: CS101_LSQ_SECTION_TRANSFER_WITHOUT_DEACT as u8,
+            checksum: checksum(section),
+        });
+        self.offset = match send(connection, self.common_address, &last) {
+            Ok(()) => None,
+            Err(_) => Some(offset),
+        };
+    }
+}
+
+/// Sends a single information object with cause
+/// [`FileTransfer`](CauseOfTransmission::FileTransfer).
+fn send(connection: &MasterConnection, common_address: u16, object: &Io) -> Result<()> {
+    let mut asdu = Asdu::new(
+        &connection.app_layer_parameters(),
+        CauseOfTransmission::FileTransfer,
+        common_address,
+    );
+    asdu.add_object(object)?;
+    connection.send_asdu(&asdu)
+}
+
+/// Serves the files of a [`FileProvider`] to masters.
+///
+/// Added to a server with `add_plugin`, where it handles the file transfer
+/// commands (`F_SC_NA_1`, `F_AF_NA_1`) before the handler of the server. A
+/// master calling the directory gets every entry of
+/// [`FileProvider::directory`]; an empty directory is answered with a
+/// negative confirmation of the call. The transfer of a connection is
+/// dropped some time after the connection has been closed.
+///
+/// ```no_run
+/// use lib60870_sys::file::{FileProvider, FileServer};
+/// use lib60870_sys::information_object::FileDirectory;
+/// use lib60870_sys::Cs104Server;
+///
+/// struct Records;
+///
+/// impl FileProvider for Records {
+///     fn directory(&self, _common_address: u16) -> Vec<FileDirectory> {
+///         Vec::new()
+///     }
+///
+///     fn read(&self, _common_address: u16, _ioa: u32, _name: u16) -> Option<Vec<u8>> {
+///         None
+///     }
+/// }
+///
+/// let mut server = Cs104Server::builder().build(())?;
+/// server.add_plugin(FileServer::new(Records))?;
+/// # Ok::<(), lib60870_sys::Error>(())
+/// ```
+pub struct FileServer<P: FileProvider> {
+    provider: P,
+    section_size: usize,
+    transfers: Vec<Transfer>,
+}
+
+impl<P: FileProvider> FileServer<P> {
+    /// Creates a server for the files of `provider`.
+    pub fn new(provider: P) -> Self {
+        FileServer {
+            provider,
+            section_size: SECTION_SIZE,
+            transfers: Vec::new(),
+        }
+    }
+
+    /// Sets the length of the sections files are split into. Defaults to
+    /// 64 KiB; larger files use longer sections to stay within 255 of
+    /// them.
+    pub fn section_size(mut self, size: usize) -> Self {
+        self.section_size = size.max(1);
+        self
+    }
+
+    /// The provider passed to [`new`](Self::new).
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    fn call(
+        &mut self,
+        connection: &MasterConnection,
+        asdu: &AsduRef,
+        call: FileCallOrSelect,
+    ) -> bool {
+        let common_address = asdu.common_address();
+        let transfers = &mut self.transfers;
+        let current = transfers
+            .iter()
+            .position(|transfer| transfer.is_for(connection));
+
+        match u32::from(call.qualifier) {
+            CS101_SCQ_DEFAULT if asdu.cot() == Some(CauseOfTransmission::Request) => {
+                self.send_directory(connection, asdu);
+            }
+            CS101_SCQ_SELECT_FILE => {
+                if let Some(current) = current {
+                    transfers.remove(current);
+                }
+                let data = self
+                    .provider
+                    .read(common_address, call.ioa, call.name)
+                    .filter(|data| data.len() <= MAX_FILE_LENGTH);
+                let ready = FileReady {
+                    ioa: call.ioa,
+                    name: call.name,
+                    length: data.as_ref().map_or(0, |data| data.len() as u32),
+                    positive: data.is_some(),
+                };
+                if let Some(data) = data {
+                    let section_size = self.section_size.max((data.len() + 254) / 255);
+                    transfers.push(Transfer {
+                        connection: connection.as_raw() as usize,
+                        peer: connection.peer_address(),
+                        common_address,
+                        ioa: call.ioa,
+                        name: call.name,
+                        data,
+                        section_size,
+                        section: 1,
+                        offset: None,
+                        seen: Instant::now(),
+                    });
+                }
+                let _ = send(connection, common_address, &Io::F_FR_NA_1(ready));
+            }
+            CS101_SCQ_REQUEST_FILE => match current {
+                Some(current) if transfers[current].is(call.ioa, call.name) => {
+                    let transfer = &mut transfers[current];
+                    transfer.section = 1;
+                    let _ = send(connection, common_address, &transfer.section_ready());
+                }
+                _ => {
+                    let ready = Io::F_FR_NA_1(FileReady {
+                        ioa: call.ioa,
+                        name: call.name,
+                        length: 0,
+                        positive: false,
+                    });
+                    let _ = send(connection, common_address, &ready);
+                }
+            },
+            CS101_SCQ_REQUEST_SECTION => {
+                if let Some(current) = current {
+                    let transfer = &mut transfers[current];
+                    if transfer.is(call.ioa, call.name)
+                        && call.section >= 1
+                        && usize::from(call.section) <= transfer.sections()
+                    {
+                        transfer.section = call.section;
+                        transfer.offset = Some(0);
+                        transfer.send_segments(connection);
+                    }
+                }
+            }
+            CS101_SCQ_DEACTIVATE_FILE => {
+                if let Some(current) = current {
+                    transfers.remove(current);
+                }
+            }
+            CS101_SCQ_DEACTIVATE_SECTION => {
+                if let Some(current) = current {
+                    transfers[current].offset = None;
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn ack(&mut self, connection: &MasterConnection, ioa: u32, name: u16, qualifier: u32) -> bool {
+        let transfers = &mut self.transfers;
+        let Some(current) = transfers
+            .iter()
+            .position(|transfer| transfer.is_for(connection))
+            .filter(|current| transfers[*current].is(ioa, name))
+        else {
+            return false;
+        };
+
+        let transfer = &mut transfers[current];
+        let common_address = transfer.common_address;
+        match qualifier & 0x0f {
+            CS101_AFQ_POS_ACK_SECTION if usize::from(transfer.section) < transfer.sections() => {
+                transfer.section += 1;
+                let _ = send(connection, common_address, &transfer.section_ready());
+            }
+            CS101_AFQ_POS_ACK_SECTION => {
+                let last = Io::F_LS_NA_1(FileLastSegmentOrSection {
+                    ioa,
+                    name,
+                    section: transfer.section,
+                    qualifier: CS101_LSQ_FILE_TRANSFER_WITHOUT_DEACT as u8,
+                    checksum: checksum(&transfer.data),
+                });
+                let _ = send(connection, common_address, &last);
+            }
+            CS101_AFQ_NEG_ACK_SECTION => {
+                transfer.offset = None;
+                let _ = send(connection, common_address, &transfer.section_ready());
+            }
+            CS101_AFQ_POS_ACK_FILE | CS101_AFQ_NEG_ACK_FILE => {
+                transfers.remove(current);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn send_directory(&self, connection: &MasterConnection, call: &AsduRef) {
+        let common_address = call.common_address();
+        let mut entries = self.provider.directory(common_address);
+        if entries.is_empty() {
+            let mut response = call.to_owned();
+            response.set_negative(true);
+            let _ = connection.send_asdu(&response);
+            return;
+        }
+
+        let count = entries.len();
+        let new_asdu = || {
+            Asdu::new(
+                &connection.app_layer_parameters(),
+                CauseOfTransmission::Request,
+                common_address,
+            )
+        };
+        let mut asdu = new_asdu();
+        for (index, entry) in entries.iter_mut().enumerate() {
+            entry.status &= !0x20;
+            if index + 1 == count {
+                entry.status |= 0x20;
+            }
+            let entry = Io::F_DR_TA_1(*entry);
+            if asdu.add_object(&entry).is_err() {
+                let _ = connection.send_asdu(&asdu);
+                asdu = new_asdu();
+                let _ = asdu.add_object(&entry);
+            }
+        }
+        let _ = connection.send_asdu(&asdu);
+    }
+}
+
+impl<P: FileProvider> SlavePlugin for FileServer<P> {
+    fn handle_asdu(&mut self, connection: &MasterConnection, asdu: &AsduRef) -> PluginResult {
+        let handled = match asdu.element(0).map(|element| element.to_object()) {
+            Some(Ok(Io::F_SC_NA_1(call))) => self.call(connection, asdu, call),
+            Some(Ok(Io::F_AF_NA_1(ack))) => {
+                self.ack(connection, ack.ioa, ack.name, ack.qualifier.into())
+            }
+            _ => false,
+        };
+        if handled {
+            PluginResult::Handled
+        } else {
+            PluginResult::NotHandled
+        }
+    }
+
+    fn run_task(&mut self, connection: &MasterConnection) {
+        let now = Instant::now();
+        if let Some(transfer) = self
+            .transfers
+            .iter_mut()
+            .find(|transfer| transfer.is_for(connection))
+        {
+            transfer.seen = now;
+            transfer.send_segments(connection);
+        }
+        // the connections of these have been closed
+        self.transfers
+            .retain(|transfer| now.duration_since(transfer.seen) < STALE_TRANSFER);
+    }
+}
+
+impl<P: FileProvider + fmt::Debug> fmt::Debug for FileServer<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileServer")
+            .field("provider", &self.provider)
+            .field("section_size", &self.section_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How often a section with a wrong checksum is requested again.
+const SECTION_RETRIES: usize = 3;
+
+/// The object of a file transfer response. Responses naming an unknown
+/// type, cause, common address or IOA fail with [`Error::Rejected`].
+fn response_object(asdu: &AsduRef) -> Result<Option<Io>> {
+    match asdu.cot() {
+        Some(
+            cot @ (CauseOfTransmission::UnknownTypeId
+            | CauseOfTransmission::UnknownCot
+            | CauseOfTransmission::UnknownCa
+            | CauseOfTransmission::UnknownIoa),
+        ) => Err(Error::Rejected(cot)),
+        _ => Ok(asdu.element(0).and_then(|element| element.to_object().ok())),
+    }
+}
+
+/// Master side of a directory call, fed with the responses of the station.
+#[derive(Debug, Default)]
+pub(crate) struct Directory {
+    entries: Vec<FileDirectory>,
+}
+
+impl Directory {
+    /// The call of the directory, sent with cause
+    /// [`Request`](CauseOfTransmission::Request).
+    pub(crate) fn call() -> Io {
+        Io::F_SC_NA_1(FileCallOrSelect {
+            qualifier: CS101_SCQ_DEFAULT as u8,
+            ..FileCallOrSelect::default()
+        })
+    }
+
+    /// Takes the next response. Returns the directory once it is complete;
+    /// a negative confirmation of the call completes it as well.
+    pub(crate) fn receive(&mut self, asdu: &AsduRef) -> Result<Option<Vec<FileDirectory>>> {
+        response_object(asdu)?;
+        match asdu.type_id() {
+            Some(TypeId::F_SC_NA_1) if asdu.is_negative() => {
+                return Ok(Some(std::mem::take(&mut self.entries)))
+            }
+            Some(TypeId::F_DR_TA_1) => {}
+            _ => return Ok(None),
+        }
+        for object in asdu.objects() {
+            if let Io::F_DR_TA_1(entry) = object? {
+                self.entries.push(entry);
+                if entry.is_last() {
+                    return Ok(Some(std::mem::take(&mut self.entries)));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// What a master does after a response of a [`Download`].
+#[derive(Debug)]
+pub(crate) enum Step {
+    /// Nothing, the response belongs to something else.
+    Wait,
+    /// Sends the command.
+    Send(Io),
+    /// Writes a verified section, then sends its acknowledgement.
+    Section { data: Vec<u8>, ack: Io },
+    /// Sends the acknowledgement of the file, which is complete.
+    Done { ack: Io, length: u64 },
+    /// Sends the negative acknowledgement of the file and fails.
+    Abort { ack: Io, error: Error },
+}
+
+#[derive(Debug)]
+enum DownloadState {
+    /// Waiting for the file ready (`F_FR_NA_1`) of the select.
+    Selecting,
+    /// Waiting for the next section ready (`F_SR_NA_1`) or the last
+    /// section (`F_LS_NA_1`) of the file.
+    Requested { length: u32 },
+    /// Collecting the segments of a section.
+    Receiving {
+        length: u32,
+        section: u8,
+        data: Vec<u8>,
+    },
+}
+
+/// Master side of a file download: select, call, receive the sections and
+/// acknowledge them. Fed with the responses of the station, it tells the
+/// master what to send next; the commands are sent with cause
+/// [`FileTransfer`](CauseOfTransmission::FileTransfer).
+#[derive(Debug)]
+pub(crate) struct Download {
+    ioa: u32,
+    name: u16,
+    state: DownloadState,
+    written: u64,
+    checksum: u8,
+    retries: usize,
+}
+
+impl Download {
+    /// Starts the download of a file; the select is sent first.
+    pub(crate) fn new(ioa: u32, name: u16) -> (Self, Io) {
+        let download = Download {
+            ioa,
+            name,
+            state: DownloadState::Selecting,
+            written: 0,
+            checksum: 0,
+            retries: 0,
+        };
+        let select = download.call(0, CS101_SCQ_SELECT_FILE);
+        (download, select)
+    }
+
+    fn call(&self, section: u8, qualifier: u32) -> Io {
+        Io::F_SC_NA_1(FileCallOrSelect {
+            ioa: self.ioa,
+            name: self.name,
+            section,
+            qualifier: qualifier as u8,
+        })
+    }
+
+    fn ack(&self, section: u8, qualifier: u32) -> Io {
+        Io::F_AF_NA_1(FileAck {
+            ioa: self.ioa,
+            name: self.name,
+            section,
+            qualifier: qualifier as u8,
+        })
+    }
+
+    /// Takes the next response. Fails with [`Error::FileUnavailable`] if
+    /// the station has no such file.
+    pub(crate) fn receive(&mut self, asdu: &AsduRef) -> Result<Step> {
+        let Some(object) = response_object(asdu)? else {
+            return Ok(Step::Wait);
+        };
+        let (ioa, name) = (self.ioa, self.name);
+        match (&mut self.state, object) {
+            (DownloadState::Selecting, Io::F_FR_NA_1(ready))
+                if ready.ioa == ioa && ready.name == name =>
+            {
+                if !ready.positive {
+                    return Err(Error::FileUnavailable);
+                }
+                self.state = DownloadState::Requested {
+                    length: ready.length,
+                };
+                Ok(Step::Send(self.call(0, CS101_SCQ_REQUEST_FILE)))
+            }
+            (DownloadState::Requested { length }, Io::F_SR_NA_1(ready))
+                if ready.ioa == ioa && ready.name == name =>
+            {
+                if ready.not_ready {
+                    return Err(Error::FileUnavailable);
+                }
+                self.state = DownloadState::Receiving {
+                    length: *length,
+                    section: ready.section,
+                    data: Vec::new(),
+                };
+                Ok(Step::Send(
+                    self.call(ready.section, CS101_SCQ_REQUEST_SECTION),
+                ))
+            }
+            (DownloadState::Requested { length }, Io::F_LS_NA_1(last))
+                if last.ioa == ioa
+                    && last.name == name
+                    && matches!(
+                        u32::from(last.qualifier),
+                        CS101_LSQ_FILE_TRANSFER_WITHOUT_DEACT | CS101_LSQ_FILE_TRANSFER_WITH_DEACT
+                    ) =>
+            {
+                if last.checksum == self.checksum && self.written == u64::from(*length) {
+                    Ok(Step::Done {
+                        ack: self.ack(last.section, CS101_AFQ_POS_ACK_FILE),
+                        length: self.written,
+                    })
+                } else {
+                    Ok(Step::Abort {
+                        ack: self.ack(last.section, CS101_AFQ_NEG_ACK_FILE),
+                        error: Error::ChecksumMismatch,
+                    })
+                }
+            }
+            (DownloadState::Receiving { section, data, .. }, Io::F_SG_NA_1(segment))
+                if segment.ioa == ioa && segment.name == name && segment.section == *section =>
+            {
+                data.extend_from_slice(&segment.data);
+                Ok(Step::Wait)
+            }
+            (
+                DownloadState::Receiving {
+                    length,
+                    section,
+                    data,
+                },
+                Io::F_LS_NA_1(last),
+            ) if last.ioa == ioa && last.name == name && last.section == *section => {
+                let (length, section, data) = (*length, *section, std::mem::take(data));
+                self.state = DownloadState::Requested { length };
+                if last.checksum == checksum(&data) {
+                    self.written += data.len() as u64;
+                    self.checksum = self.checksum.wrapping_add(last.checksum);
+                    self.retries = 0;
+                    Ok(Step::Section {
+                        data,
+                        ack: self.ack(section, CS101_AFQ_POS_ACK_SECTION),
+                    })
+                } else if self.retries < SECTION_RETRIES {
+                    self.retries += 1;
+                    Ok(Step::Send(self.ack(section, CS101_AFQ_NEG_ACK_SECTION)))
+                } else {
+                    Ok(Step::Abort {
+                        ack: self.ack(section, CS101_AFQ_NEG_ACK_FILE),
+                        error: Error::ChecksumMismatch,
+                    })
+                }
+            }
+            _ => Ok(Step::Wait),
+        }
+    }
+}
+
+/// A blocking master taking part in a file transfer.
+pub(crate) trait FileLink {
+    /// Sends a file transfer command to the station with `common_address`.
+    fn send(&mut self, cot: CauseOfTransmission, common_address: u16, object: &Io) -> Result<()>;
+
+    /// Waits for the next file transfer ASDU of the station.
+    fn receive(&mut self) -> Result<Asdu>;
+}
+
+/// Calls the directory of the station with `common_address`.
+pub(crate) fn directory(
+    link: &mut impl FileLink,
+    common_address: u16,
+) -> Result<Vec<FileDirectory>> {
+    let mut directory = Directory::default();
+    link.send(
+        CauseOfTransmission::Request,
+        common_address,
+        &Directory::call(),
+    )?;
+    loop {
+        let asdu = link.receive()?;
+        if let Some(entries) = directory.receive(&asdu)? {
+            return Ok(entries);
+        }
+    }
+}
+
+/// Downloads a file, writing it section by section to `writer`, and
+/// returns its length.
+pub(crate) fn download_to(
+    link: &mut impl FileLink,
+    common_address: u16,
+    ioa: u32,
+    name: u16,
+    writer: &mut impl io::Write,
+) -> Result<u64> {
+    let cot = CauseOfTransmission::FileTransfer;
+    let (mut download, select) = Download::new(ioa, name);
+    link.send(cot, common_address, &select)?;
+    loop {
+        let asdu = link.receive()?;
+        match download.receive(&asdu)? {
+            Step::Wait => {}
+            Step::Send(command) => link.send(cot, common_address, &command)?,
+            Step::Section { data, ack } => {
+                writer.write_all(&data).map_err(|e| Error::Io(e.kind()))?;
+                link.send(cot, common_address, &ack)?;
+            }
+            Step::Done { ack, length } => {
+                link.send(cot, common_address, &ack)?;
+                writer.flush().map_err(|e| Error::Io(e.kind()))?;
+                return Ok(length);
+            }
+            Step::Abort { ack, error } => {
+                link.send(cot, common_address, &ack)?;
+                return Err(error);
+            }
+        }
+    }
+}
+
+/// Hands the file transfer ASDUs a blocking master receives to the
+/// transfer waiting for them.
+#[derive(Debug, Default)]
+pub(crate) struct FileReceiver {
+    subscribers: Mutex<Vec<(u16, mpsc::Sender<Asdu>)>>,
+}
+
+impl FileReceiver {
+    /// Receives the file transfer ASDUs of `common_address` until the
+    /// receiver is dropped.
+    pub(crate) fn subscribe(&self, common_address: u16) -> mpsc::Receiver<Asdu> {
+        let (tx, rx) = mpsc::channel();
+        let mut subscribers = self.subscribers.lock().unwrap_or_else(|e| e.into_inner());
+        subscribers.push((common_address, tx));
+        rx
+    }
+
+    pub(crate) fn forward(&self, asdu: &AsduRef) {
+        if !asdu.type_id().is_some_and(TypeId::is_file_transfer) {
+            return;
+        }
+        let mut subscribers = self.subscribers.lock().unwrap_or_else(|e| e.into_inner());
+        subscribers.retain(|(common_address, tx)| {
+            *common_address != asdu.common_address() || tx.send(asdu.to_owned()).is_ok()
+        });
+    }
+
+    /// Disconnects all transfers, e.g. when the connection is closed.
+    pub(crate) fn clear(&self) {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+/// Waits for the next ASDU of a [`FileReceiver`] subscription.
+pub(crate) fn receive_within(files: &mpsc::Receiver<Asdu>, timeout: Duration) -> Result<Asdu> {
+    files.recv_timeout(timeout).map_err(|e| match e {
+        mpsc::RecvTimeoutError::Timeout => Error::Timeout,
+        mpsc::RecvTimeoutError::Disconnected => Error::ConnectionClosed,
+    })
+}
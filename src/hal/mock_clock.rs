@@ -0,0 +1,127 @@
+//! A virtual clock in place of the system clocks.
+
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::time::{real_monotonic_time_ns, real_system_time_ns};
+
+/// The virtual time while a [`MockClock`] is installed, in nanoseconds.
+#[derive(Clone, Copy)]
+struct Frozen {
+    system: u64,
+    monotonic: u64,
+}
+
+struct Clock {
+    frozen: Option<Frozen>,
+    /// Added to the real monotonic time, so it does not run backwards once
+    /// a mock clock has been dropped.
+    monotonic_offset: u64,
+}
+
+fn clock() -> MutexGuard<'static, Clock> {
+    static CLOCK: Mutex<Clock> = Mutex::new(Clock {
+        frozen: None,
+        monotonic_offset: 0,
+    });
+    CLOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The virtual system time, if a mock clock is installed.
+pub(super) fn system_time_ns() -> Option<u64> {
+    clock().frozen.map(|frozen| frozen.system)
+}
+
+/// The virtual monotonic time, or the real one continuing from it.
+pub(super) fn monotonic_time_ns() -> u64 {
+    let clock = clock();
+    match clock.frozen {
+        Some(frozen) => frozen.monotonic,
+        None => real_monotonic_time_ns() + clock.monotonic_offset,
+    }
+}
+
+/// A virtual clock for lib60870, to test timeouts without waiting for them.
+///
+/// While a `MockClock` exists, the time functions of the HAL
+/// (`Hal_getTimeInMs`, `Hal_getMonotonicTimeInMs`, ...) stand still at the
+/// time the clock was installed and only move by [`advance`](Self::advance).
+/// They drive every timer of lib60870: the t0 to t3 timeouts of CS104
+/// connections, the link layer timeouts of CS101 and the idle timeout of a
+/// CS101 master. Threads still sleep and wait for sockets in real time, so an
+/// expired timeout is noticed on the next turn of the connection loop, within
+/// a few hundred milliseconds.
+///
+/// The clock is global. Installing a second `MockClock` waits until the first
+/// one has been dropped, which serializes the tests using it; other tests in
+/// the same test binary see the virtual time too while it is installed. When
+/// the clock is dropped, the system time is real again and the monotonic time
+/// goes on from the virtual one, so it never runs backwards.
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use lib60870_sys::cs104::ConnectionEvent;
+/// use lib60870_sys::hal::MockClock;
+/// use lib60870_sys::Cs104Client;
+///
+/// let clock = MockClock::install();
+/// let (events, closed) = std::sync::mpsc::channel();
+/// let client = Cs104Client::builder("127.0.0.1")
+///     .on_connection_event(move |event| {
+///         let _ = events.send(event);
+///     })
+///     .build()?;
+/// client.connect()?;
+/// client.start_dt();
+///
+/// // no STARTDT confirmation within t1 (15 s) closes the connection
+/// clock.advance(Duration::from_secs(16));
+/// while closed.recv()? != ConnectionEvent::Closed {}
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct MockClock {
+    _installed: MutexGuard<'static, ()>,
+}
+
+impl MockClock {
+    /// Stops the clock of lib60870 at the current time, after the clock
+    /// installed before has been dropped.
+    pub fn install() -> Self {
+        static INSTALLED: Mutex<()> = Mutex::new(());
+        let installed = INSTALLED.lock().unwrap_or_else(|e| e.into_inner());
+        let mut clock = clock();
+        clock.frozen = Some(Frozen {
+            system: real_system_time_ns(),
+            monotonic: real_monotonic_time_ns() + clock.monotonic_offset,
+        });
+        MockClock {
+            _installed: installed,
+        }
+    }
+
+    /// Moves the clock forward.
+    pub fn advance(&self, duration: Duration) {
+        let duration = duration.as_nanos().min(u64::MAX.into()) as u64;
+        if let Some(frozen) = &mut clock().frozen {
+            frozen.system = frozen.system.saturating_add(duration);
+            frozen.monotonic = frozen.monotonic.saturating_add(duration);
+        }
+    }
+
+    /// The system time lib60870 sees.
+    pub fn now(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_nanos(system_time_ns().unwrap_or_else(real_system_time_ns))
+    }
+}
+
+impl Drop for MockClock {
+    fn drop(&mut self) {
+        let mut clock = clock();
+        if let Some(frozen) = clock.frozen.take() {
+            let behind = frozen.monotonic.saturating_sub(real_monotonic_time_ns());
+            clock.monotonic_offset = clock.monotonic_offset.max(behind);
+        }
+    }
+}
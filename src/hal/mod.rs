@@ -0,0 +1,25 @@
+//! Rust implementation of the lib60870 HAL (`rust-hal` feature).
+//!
+//! lib60870 is built without the socket, serial port, thread and time
+//! sources of its platform HAL, and the functions declared in `hal_socket.h`,
+//! `hal_serial.h`, `hal_thread.h` and `hal_time.h` are exported from here
+//! under their C names instead. The opaque handles lib60870 passes around
+//! (`Socket`, `Thread`, ...) are boxed Rust values.
+//!
+//! Sockets sit on mio and socket2, serial ports on serialport, threads and
+//! time on std. The `UdpSocket_*` functions are not provided, lib60870 does
+//! not use them.
+//!
+//! With the `mock-clock` feature, a `MockClock` replaces the time of the
+//! HAL with a virtual one for tests.
+#![allow(non_snake_case)]
+
+#[cfg(feature = "mock-clock")]
+mod mock_clock;
+mod serial;
+mod socket;
+mod thread;
+mod time;
+
+#[cfg(feature = "mock-clock")]
+pub use mock_clock::MockClock;
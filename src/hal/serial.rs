@@ -0,0 +1,197 @@
+//! `hal_serial.h` on serialport.
+
+use std::ffi::CStr;
+use std::io::{Read, Write};
+use std::os::raw::{c_char, c_int};
+use std::time::Duration;
+
+use serialport::{ClearBuffer, DataBits, Parity, StopBits};
+
+use crate::bindings::{
+    SerialPort, SerialPortError, SerialPortError_SERIAL_PORT_ERROR_INVALID_ARGUMENT,
+    SerialPortError_SERIAL_PORT_ERROR_INVALID_BAUDRATE, SerialPortError_SERIAL_PORT_ERROR_NONE,
+    SerialPortError_SERIAL_PORT_ERROR_OPEN_FAILED, SerialPortError_SERIAL_PORT_ERROR_UNKNOWN,
+};
+
+/// The read timeout until `SerialPort_setTimeout` is called.
+const DEFAULT_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// A `SerialPort` handle, opened by `SerialPort_open`.
+struct HalSerialPort {
+    interface_name: String,
+    baud_rate: c_int,
+    data_bits: u8,
+    parity: c_char,
+    stop_bits: u8,
+    timeout: Duration,
+    port: Option<Box<dyn serialport::SerialPort>>,
+    last_error: SerialPortError,
+}
+
+impl HalSerialPort {
+    fn open(&self) -> Result<Box<dyn serialport::SerialPort>, SerialPortError> {
+        let baud_rate = u32::try_from(self.baud_rate)
+            .map_err(|_| SerialPortError_SERIAL_PORT_ERROR_INVALID_BAUDRATE)?;
+        let data_bits = match self.data_bits {
+            5 => DataBits::Five,
+            6 => DataBits::Six,
+            7 => DataBits::Seven,
+            8 => DataBits::Eight,
+            _ => return Err(SerialPortError_SERIAL_PORT_ERROR_INVALID_ARGUMENT),
+        };
+        let parity = match self.parity as u8 {
+            b'N' => Parity::None,
+            b'E' => Parity::Even,
+            b'O' => Parity::Odd,
+            _ => return Err(SerialPortError_SERIAL_PORT_ERROR_INVALID_ARGUMENT),
+        };
+        let stop_bits = match self.stop_bits {
+            1 => StopBits::One,
+            2 => StopBits::Two,
+            _ => return Err(SerialPortError_SERIAL_PORT_ERROR_INVALID_ARGUMENT),
+        };
+        serialport::new(&self.interface_name, baud_rate)
+            .data_bits(data_bits)
+            .parity(parity)
+            .stop_bits(stop_bits)
+            .timeout(self.timeout)
+            .open()
+            .map_err(|_| SerialPortError_SERIAL_PORT_ERROR_OPEN_FAILED)
+    }
+
+    /// Records the outcome of an operation on the open port.
+    fn result<T, E>(&mut self, result: Option<Result<T, E>>) -> Option<T> {
+        match result {
+            Some(Ok(value)) => {
+                self.last_error = SerialPortError_SERIAL_PORT_ERROR_NONE;
+                Some(value)
+            }
+            Some(Err(_)) => {
+                self.last_error = SerialPortError_SERIAL_PORT_ERROR_UNKNOWN;
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+unsafe fn serial_port<'a>(serial_port: SerialPort) -> &'a mut HalSerialPort {
+    &mut *serial_port.cast::<HalSerialPort>()
+}
+
+#[no_mangle]
+unsafe extern "C" fn SerialPort_create(
+    interface_name: *const c_char,
+    baud_rate: c_int,
+    data_bits: u8,
+    parity: c_char,
+    stop_bits: u8,
+) -> SerialPort {
+    if interface_name.is_null() {
+        return std::ptr::null_mut();
+    }
+    Box::into_raw(Box::new(HalSerialPort {
+        interface_name: CStr::from_ptr(interface_name)
+            .to_string_lossy()
+            .into_owned(),
+        baud_rate,
+        data_bits,
+        parity,
+        stop_bits,
+        timeout: DEFAULT_TIMEOUT,
+        port: None,
+        last_error: SerialPortError_SERIAL_PORT_ERROR_NONE,
+    }))
+    .cast()
+}
+
+#[no_mangle]
+unsafe extern "C" fn SerialPort_destroy(this: SerialPort) {
+    if !this.is_null() {
+        drop(Box::from_raw(this.cast::<HalSerialPort>()));
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn SerialPort_open(this: SerialPort) -> bool {
+    let this = serial_port(this);
+    match this.open() {
+        Ok(port) => {
+            this.port = Some(port);
+            this.last_error = SerialPortError_SERIAL_PORT_ERROR_NONE;
+            true
+        }
+        Err(error) => {
+            this.last_error = error;
+            false
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn SerialPort_close(this: SerialPort) {
+    serial_port(this).port = None;
+}
+
+#[no_mangle]
+unsafe extern "C" fn SerialPort_getBaudRate(this: SerialPort) -> c_int {
+    serial_port(this).baud_rate
+}
+
+#[no_mangle]
+unsafe extern "C" fn SerialPort_setTimeout(this: SerialPort, timeout: c_int) {
+    let this = serial_port(this);
+    this.timeout = Duration::from_millis(timeout.max(0) as u64);
+    let timeout = this.timeout;
+    let result = this.port.as_mut().map(|port| port.set_timeout(timeout));
+    this.result(result);
+}
+
+#[no_mangle]
+unsafe extern "C" fn SerialPort_discardInBuffer(this: SerialPort) {
+    let this = serial_port(this);
+    let result = this
+        .port
+        .as_ref()
+        .map(|port| port.clear(ClearBuffer::Input));
+    this.result(result);
+}
+
+/// Returns -1 if no byte arrives within the timeout.
+#[no_mangle]
+unsafe extern "C" fn SerialPort_readByte(this: SerialPort) -> c_int {
+    let this = serial_port(this);
+    let Some(port) = this.port.as_mut() else {
+        return -1;
+    };
+    let mut byte = [0];
+    match port.read(&mut byte) {
+        Ok(1) => c_int::from(byte[0]),
+        _ => -1,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn SerialPort_write(
+    this: SerialPort,
+    buffer: *mut u8,
+    start_pos: c_int,
+    number_of_bytes: c_int,
+) -> c_int {
+    let this = serial_port(this);
+    if start_pos < 0 || number_of_bytes < 0 {
+        return -1;
+    }
+    let data = std::slice::from_raw_parts(buffer.add(start_pos as usize), number_of_bytes as usize);
+    let result = this.port.as_mut().map(|port| -> std::io::Result<usize> {
+        port.write_all(data)?;
+        port.flush()?;
+        Ok(data.len())
+    });
+    this.result(result).map_or(-1, |written| written as c_int)
+}
+
+#[no_mangle]
+unsafe extern "C" fn SerialPort_getLastError(this: SerialPort) -> SerialPortError {
+    serial_port(this).last_error
+}
@@ -0,0 +1,557 @@
+//! `hal_socket.h`: TCP sockets on socket2, waited on with mio.
+
+use std::ffi::CStr;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::os::raw::{c_char, c_int, c_uint, c_void};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use mio::event::Source;
+use mio::net::{TcpListener, TcpStream};
+use mio::{Events, Interest, Poll, Registry, Token};
+use socket2::{Domain, SockRef, TcpKeepalive, Type};
+
+use crate::bindings::{
+    HandleSet, ServerSocket, Socket, SocketState, SocketState_SOCKET_STATE_CONNECTED,
+    SocketState_SOCKET_STATE_CONNECTING, SocketState_SOCKET_STATE_FAILED,
+};
+
+extern "C" {
+    // lib_memory.h, lib60870 frees the address strings with GLOBAL_FREEMEM
+    fn Memory_malloc(size: usize) -> *mut c_void;
+}
+
+/// The connect timeout until `Socket_setConnectTimeout` is called.
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The listen backlog until `ServerSocket_setBacklog` is called.
+const DEFAULT_BACKLOG: c_int = 10;
+
+/// The buffer size `Socket_getPeerAddressStatic` may assume.
+const PEER_ADDRESS_SIZE: usize = 60;
+
+/// Ids of the handlesets a socket can be registered with.
+static NEXT_HANDLESET_ID: AtomicUsize = AtomicUsize::new(1);
+
+enum State {
+    /// Created by `TcpSocket_create`, with the local address of
+    /// `Socket_bind`.
+    Unconnected(Option<SocketAddr>),
+    /// Created by `TcpServerSocket_create`, not listening yet.
+    Bound(socket2::Socket),
+    /// A server socket, with a connection taken from the queue while
+    /// waiting.
+    Listening {
+        listener: TcpListener,
+        pending: Option<TcpStream>,
+    },
+    Connecting {
+        stream: TcpStream,
+        deadline: Instant,
+    },
+    Connected(TcpStream),
+    Failed,
+}
+
+/// A `Socket` or `ServerSocket` handle; lib60870 adds both to handlesets.
+struct HalSocket {
+    state: State,
+    connect_timeout: Duration,
+    backlog: c_int,
+    keepalive: Option<TcpKeepalive>,
+    /// The handleset the socket is registered with and its registry.
+    registration: Option<(usize, Registry)>,
+}
+
+impl HalSocket {
+    fn new(state: State) -> Self {
+        HalSocket {
+            state,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            backlog: DEFAULT_BACKLOG,
+            keepalive: None,
+            registration: None,
+        }
+    }
+
+    fn into_raw(self) -> Socket {
+        Box::into_raw(Box::new(self)).cast()
+    }
+
+    fn stream(&mut self) -> Option<&mut TcpStream> {
+        match &mut self.state {
+            State::Connected(stream) => Some(stream),
+            _ => None,
+        }
+    }
+
+    fn source(&mut self) -> Option<&mut dyn Source> {
+        match &mut self.state {
+            State::Listening { listener, .. } => Some(listener),
+            State::Connecting { stream, .. } | State::Connected(stream) => Some(stream),
+            _ => None,
+        }
+    }
+
+    /// Replaces the socket, which drops the registration of the old one.
+    fn set_state(&mut self, state: State) {
+        self.deregister();
+        self.state = state;
+    }
+
+    /// Registers the socket with handleset `id` if it is not yet.
+    fn register(&mut self, id: usize, registry: &Registry) {
+        if matches!(self.registration, Some((current, _)) if current == id) {
+            return;
+        }
+        self.deregister();
+        let token = Token(self as *mut HalSocket as usize);
+        let Some(source) = self.source() else {
+            return;
+        };
+        if registry.register(source, token, Interest::READABLE).is_ok() {
+            self.registration = registry.try_clone().ok().map(|registry| (id, registry));
+        }
+    }
+
+    fn deregister(&mut self) {
+        if let Some((_, registry)) = self.registration.take() {
+            if let Some(source) = self.source() {
+                let _ = registry.deregister(source);
+            }
+        }
+    }
+
+    /// Whether a read or accept would not block, including errors and the
+    /// end of a connection as `select` reports them.
+    fn is_ready(&mut self) -> bool {
+        match &mut self.state {
+            State::Listening { listener, pending } => {
+                if pending.is_none() {
+                    *pending = listener.accept().ok().map(|(stream, _)| stream);
+                }
+                pending.is_some()
+            }
+            State::Connected(stream) => match stream.peek(&mut [0]) {
+                Ok(_) => true,
+                Err(e) => !would_block(&e),
+            },
+            _ => false,
+        }
+    }
+
+    /// Connects to `remote`, waiting for the connect timeout if `wait`.
+    fn connect(&mut self, remote: Option<SocketAddr>, wait: bool) -> bool {
+        let State::Unconnected(local) = self.state else {
+            return false;
+        };
+        let state = match remote.map(|remote| self.open(remote, local, wait)) {
+            Some(Ok(state)) => state,
+            _ => State::Failed,
+        };
+        let connected = !matches!(state, State::Failed);
+        self.set_state(state);
+        connected
+    }
+
+    fn open(&self, remote: SocketAddr, local: Option<SocketAddr>, wait: bool) -> io::Result<State> {
+        let socket = socket2::Socket::new(Domain::for_address(remote), Type::STREAM, None)?;
+        if let Some(local) = local {
+            socket.bind(&local.into())?;
+        }
+        if let Some(keepalive) = &self.keepalive {
+            socket.set_tcp_keepalive(keepalive)?;
+        }
+        socket.set_nodelay(true)?;
+        if wait {
+            let timeout = self.connect_timeout.max(Duration::from_millis(1));
+            socket.connect_timeout(&remote.into(), timeout)?;
+            socket.set_nonblocking(true)?;
+            return Ok(State::Connected(TcpStream::from_std(socket.into())));
+        }
+        socket.set_nonblocking(true)?;
+        match socket.connect(&remote.into()) {
+            Ok(()) => Ok(State::Connected(TcpStream::from_std(socket.into()))),
+            Err(e) if in_progress(&e) => Ok(State::Connecting {
+                stream: TcpStream::from_std(socket.into()),
+                deadline: Instant::now() + self.connect_timeout,
+            }),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Drop for HalSocket {
+    fn drop(&mut self) {
+        self.deregister();
+    }
+}
+
+fn would_block(e: &io::Error) -> bool {
+    matches!(
+        e.kind(),
+        io::ErrorKind::WouldBlock | io::ErrorKind::Interrupted
+    )
+}
+
+/// Whether a non-blocking connect has started.
+fn in_progress(e: &io::Error) -> bool {
+    #[cfg(unix)]
+    if e.raw_os_error() == Some(libc::EINPROGRESS) {
+        return true;
+    }
+    e.kind() == io::ErrorKind::WouldBlock
+}
+
+/// Resolves an IP address or host name and a port from lib60870; `NULL` is
+/// any address and a port below 1 any port.
+unsafe fn resolve(address: *const c_char, port: c_int) -> Option<SocketAddr> {
+    let port = u16::try_from(port.max(0)).ok()?;
+    if address.is_null() {
+        return Some(SocketAddr::from(([0, 0, 0, 0], port)));
+    }
+    let host = CStr::from_ptr(address).to_str().ok()?;
+    (host, port).to_socket_addrs().ok()?.next()
+}
+
+/// Copies `text` into a string lib60870 frees.
+unsafe fn c_string(text: &str) -> *mut c_char {
+    let raw = Memory_malloc(text.len() + 1).cast::<c_char>();
+    if !raw.is_null() {
+        ptr::copy_nonoverlapping(text.as_ptr().cast(), raw, text.len());
+        *raw.add(text.len()) = 0;
+    }
+    raw
+}
+
+unsafe fn socket<'a, T>(socket: *mut T) -> &'a mut HalSocket {
+    &mut *socket.cast::<HalSocket>()
+}
+
+#[no_mangle]
+unsafe extern "C" fn TcpServerSocket_create(address: *const c_char, port: c_int) -> ServerSocket {
+    let Some(address) = resolve(address, port) else {
+        return ptr::null_mut();
+    };
+    let bind = || -> io::Result<socket2::Socket> {
+        let socket = socket2::Socket::new(Domain::for_address(address), Type::STREAM, None)?;
+        // Windows lets a second socket steal the address instead
+        #[cfg(not(windows))]
+        socket.set_reuse_address(true)?;
+        socket.bind(&address.into())?;
+        Ok(socket)
+    };
+    match bind() {
+        Ok(socket) => HalSocket::new(State::Bound(socket)).into_raw().cast(),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn ServerSocket_setBacklog(server: ServerSocket, backlog: c_int) {
+    socket(server).backlog = backlog;
+}
+
+#[no_mangle]
+unsafe extern "C" fn ServerSocket_listen(server: ServerSocket) {
+    let server = socket(server);
+    if !matches!(server.state, State::Bound(_)) {
+        return;
+    }
+    let State::Bound(socket) = std::mem::replace(&mut server.state, State::Failed) else {
+        unreachable!();
+    };
+    let listen = || -> io::Result<TcpListener> {
+        socket.listen(server.backlog)?;
+        socket.set_nonblocking(true)?;
+        Ok(TcpListener::from_std(socket.into()))
+    };
+    if let Ok(listener) = listen() {
+        server.set_state(State::Listening {
+            listener,
+            pending: None,
+        });
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn ServerSocket_accept(server: ServerSocket) -> Socket {
+    let State::Listening { listener, pending } = &mut socket(server).state else {
+        return ptr::null_mut();
+    };
+    let stream = match pending.take() {
+        Some(stream) => stream,
+        None => match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(_) => return ptr::null_mut(),
+        },
+    };
+    let _ = stream.set_nodelay(true);
+    HalSocket::new(State::Connected(stream)).into_raw()
+}
+
+#[no_mangle]
+unsafe extern "C" fn ServerSocket_destroy(server: ServerSocket) {
+    if !server.is_null() {
+        drop(Box::from_raw(server.cast::<HalSocket>()));
+    }
+}
+
+#[no_mangle]
+extern "C" fn TcpSocket_create() -> Socket {
+    HalSocket::new(State::Unconnected(None)).into_raw()
+}
+
+#[no_mangle]
+unsafe extern "C" fn Socket_setConnectTimeout(this: Socket, timeout_ms: u32) {
+    socket(this).connect_timeout = Duration::from_millis(timeout_ms.into());
+}
+
+/// Keep-alive applies to the connection made later if the socket is not
+/// connected yet.
+#[no_mangle]
+unsafe extern "C" fn Socket_activateTcpKeepAlive(
+    this: Socket,
+    idle_time: c_int,
+    interval: c_int,
+    count: c_int,
+) {
+    let seconds = |value: c_int| Duration::from_secs(value.max(1) as u64);
+    let keepalive = TcpKeepalive::new().with_time(seconds(idle_time));
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        windows
+    ))]
+    let keepalive = keepalive.with_interval(seconds(interval));
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+    let keepalive = keepalive.with_retries(count.max(1) as u32);
+    let _ = (interval, count);
+
+    let this = socket(this);
+    if let Some(stream) = this.stream() {
+        let _ = SockRef::from(&*stream).set_tcp_keepalive(&keepalive);
+    }
+    this.keepalive = Some(keepalive);
+}
+
+#[no_mangle]
+unsafe extern "C" fn Socket_bind(this: Socket, address: *const c_char, port: c_int) -> bool {
+    let this = socket(this);
+    match (&this.state, resolve(address, port)) {
+        (State::Unconnected(_), Some(local)) => {
+            this.state = State::Unconnected(Some(local));
+            true
+        }
+        _ => false,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn Socket_connect(this: Socket, address: *const c_char, port: c_int) -> bool {
+    socket(this).connect(resolve(address, port), true)
+}
+
+#[no_mangle]
+unsafe extern "C" fn Socket_connectAsync(
+    this: Socket,
+    address: *const c_char,
+    port: c_int,
+) -> bool {
+    socket(this).connect(resolve(address, port), false)
+}
+
+#[no_mangle]
+unsafe extern "C" fn Socket_checkAsyncConnectState(this: Socket) -> SocketState {
+    let this = socket(this);
+    let State::Connecting { stream, deadline } = &this.state else {
+        return match this.state {
+            State::Connected(_) => SocketState_SOCKET_STATE_CONNECTED,
+            _ => SocketState_SOCKET_STATE_FAILED,
+        };
+    };
+    let failed = !matches!(stream.take_error(), Ok(None));
+    let connected = !failed && stream.peer_addr().is_ok();
+    if !failed && !connected && Instant::now() < *deadline {
+        return SocketState_SOCKET_STATE_CONNECTING;
+    }
+    if connected {
+        // the stream stays registered, only its state changes
+        let State::Connecting { stream, .. } = std::mem::replace(&mut this.state, State::Failed)
+        else {
+            unreachable!();
+        };
+        this.state = State::Connected(stream);
+        SocketState_SOCKET_STATE_CONNECTED
+    } else {
+        this.set_state(State::Failed);
+        SocketState_SOCKET_STATE_FAILED
+    }
+}
+
+/// Returns 0 if no data is available and -1 once the connection is closed.
+#[no_mangle]
+unsafe extern "C" fn Socket_read(this: Socket, buf: *mut u8, size: c_int) -> c_int {
+    let Some(stream) = socket(this).stream() else {
+        return -1;
+    };
+    if size <= 0 {
+        return 0;
+    }
+    let buf = std::slice::from_raw_parts_mut(buf, size as usize);
+    match stream.read(buf) {
+        Ok(0) => -1,
+        Ok(read) => read as c_int,
+        Err(e) if would_block(&e) => 0,
+        Err(_) => -1,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn Socket_write(this: Socket, buf: *mut u8, size: c_int) -> c_int {
+    let Some(stream) = socket(this).stream() else {
+        return -1;
+    };
+    if size <= 0 {
+        return 0;
+    }
+    let buf = std::slice::from_raw_parts(buf, size as usize);
+    match stream.write(buf) {
+        Ok(written) => written as c_int,
+        Err(e) if would_block(&e) => 0,
+        Err(_) => -1,
+    }
+}
+
+unsafe fn local_address(this: Socket) -> Option<SocketAddr> {
+    match &socket(this).state {
+        State::Listening { listener, .. } => listener.local_addr().ok(),
+        State::Connecting { stream, .. } | State::Connected(stream) => stream.local_addr().ok(),
+        _ => None,
+    }
+}
+
+unsafe fn peer_address(this: Socket) -> Option<SocketAddr> {
+    socket(this).stream()?.peer_addr().ok()
+}
+
+#[no_mangle]
+unsafe extern "C" fn Socket_getLocalAddress(this: Socket) -> *mut c_char {
+    local_address(this).map_or(ptr::null_mut(), |address| c_string(&address.to_string()))
+}
+
+#[no_mangle]
+unsafe extern "C" fn Socket_getPeerAddress(this: Socket) -> *mut c_char {
+    peer_address(this).map_or(ptr::null_mut(), |address| c_string(&address.to_string()))
+}
+
+/// Writes an empty string if the socket is not connected.
+#[no_mangle]
+unsafe extern "C" fn Socket_getPeerAddressStatic(
+    this: Socket,
+    peer_address_string: *mut c_char,
+) -> *mut c_char {
+    let address = peer_address(this).map_or_else(String::new, |address| address.to_string());
+    let len = address.len().min(PEER_ADDRESS_SIZE - 1);
+    ptr::copy_nonoverlapping(address.as_ptr().cast(), peer_address_string, len);
+    *peer_address_string.add(len) = 0;
+    peer_address_string
+}
+
+#[no_mangle]
+unsafe extern "C" fn Socket_destroy(this: Socket) {
+    if !this.is_null() {
+        drop(Box::from_raw(this.cast::<HalSocket>()));
+    }
+}
+
+/// A `HandleSet`, which waits like `select` although mio reports edges:
+/// sockets are checked for pending data before every wait, and a wait only
+/// has to catch data arriving after that.
+struct HalHandleset {
+    id: usize,
+    poll: Poll,
+    events: Events,
+    sockets: Vec<*mut HalSocket>,
+}
+
+#[no_mangle]
+extern "C" fn Handleset_new() -> HandleSet {
+    let Ok(poll) = Poll::new() else {
+        return ptr::null_mut();
+    };
+    Box::into_raw(Box::new(HalHandleset {
+        id: NEXT_HANDLESET_ID.fetch_add(1, Ordering::Relaxed),
+        poll,
+        events: Events::with_capacity(16),
+        sockets: Vec::new(),
+    }))
+    .cast()
+}
+
+unsafe fn handleset<'a>(handleset: HandleSet) -> &'a mut HalHandleset {
+    &mut *handleset.cast::<HalHandleset>()
+}
+
+/// Sockets stay registered so they can be added again cheaply; a socket
+/// is only touched while it is in the set.
+#[no_mangle]
+unsafe extern "C" fn Handleset_reset(this: HandleSet) {
+    handleset(this).sockets.clear();
+}
+
+#[no_mangle]
+unsafe extern "C" fn Handleset_addSocket(this: HandleSet, sock: Socket) {
+    if sock.is_null() {
+        return;
+    }
+    let this = handleset(this);
+    socket(sock).register(this.id, this.poll.registry());
+    let sock = sock.cast::<HalSocket>();
+    if !this.sockets.contains(&sock) {
+        this.sockets.push(sock);
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn Handleset_removeSocket(this: HandleSet, sock: Socket) {
+    let sock = sock.cast::<HalSocket>();
+    handleset(this).sockets.retain(|&socket| socket != sock);
+}
+
+#[no_mangle]
+unsafe extern "C" fn Handleset_waitReady(this: HandleSet, timeout_ms: c_uint) -> c_int {
+    let this = handleset(this);
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms.into());
+    loop {
+        let ready = this
+            .sockets
+            .iter()
+            .filter(|&&sock| (*sock).is_ready())
+            .count();
+        if ready > 0 {
+            return ready as c_int;
+        }
+        let Some(left) = deadline.checked_duration_since(Instant::now()) else {
+            return 0;
+        };
+        if left.is_zero() {
+            return 0;
+        }
+        match this.poll.poll(&mut this.events, Some(left)) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(_) => return -1,
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn Handleset_destroy(this: HandleSet) {
+    if !this.is_null() {
+        drop(Box::from_raw(this.cast::<HalHandleset>()));
+    }
+}
@@ -0,0 +1,121 @@
+//! `hal_thread.h` on std threads.
+
+use std::os::raw::{c_int, c_void};
+use std::sync::{Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::bindings::{Semaphore, Thread, ThreadExecutionFunction};
+
+/// The entry point of a thread with its parameter.
+#[derive(Clone, Copy)]
+struct Entry {
+    function: ThreadExecutionFunction,
+    parameter: *mut c_void,
+}
+
+// The parameter belongs to the thread function, which lib60870 runs on
+// another thread by design
+unsafe impl Send for Entry {}
+
+impl Entry {
+    fn run(self) {
+        if let Some(function) = self.function {
+            unsafe { function(self.parameter) };
+        }
+    }
+}
+
+/// A `Thread` handle.
+struct HalThread {
+    entry: Entry,
+    autodestroy: bool,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[no_mangle]
+extern "C" fn Thread_create(
+    function: ThreadExecutionFunction,
+    parameter: *mut c_void,
+    autodestroy: bool,
+) -> Thread {
+    Box::into_raw(Box::new(HalThread {
+        entry: Entry {
+            function,
+            parameter,
+        },
+        autodestroy,
+        handle: None,
+    }))
+    .cast()
+}
+
+/// lib60870 does not touch an autodestroy thread after starting it, so its
+/// handle is freed right away; other threads are joined in `Thread_destroy`.
+#[no_mangle]
+unsafe extern "C" fn Thread_start(thread: Thread) {
+    let thread = thread.cast::<HalThread>();
+    let entry = (*thread).entry;
+    let spawned = std::thread::Builder::new().spawn(move || entry.run());
+    if (*thread).autodestroy {
+        drop(Box::from_raw(thread));
+    } else {
+        (*thread).handle = spawned.ok();
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn Thread_destroy(thread: Thread) {
+    if thread.is_null() {
+        return;
+    }
+    let thread = Box::from_raw(thread.cast::<HalThread>());
+    if let Some(handle) = thread.handle {
+        let _ = handle.join();
+    }
+}
+
+#[no_mangle]
+extern "C" fn Thread_sleep(millis: c_int) {
+    std::thread::sleep(Duration::from_millis(millis.max(0) as u64));
+}
+
+/// A counting `Semaphore`.
+struct HalSemaphore {
+    count: Mutex<c_int>,
+    posted: Condvar,
+}
+
+#[no_mangle]
+extern "C" fn Semaphore_create(initial_value: c_int) -> Semaphore {
+    Box::into_raw(Box::new(HalSemaphore {
+        count: Mutex::new(initial_value),
+        posted: Condvar::new(),
+    }))
+    .cast()
+}
+
+#[no_mangle]
+unsafe extern "C" fn Semaphore_wait(semaphore: Semaphore) {
+    let semaphore = &*semaphore.cast::<HalSemaphore>();
+    let count = semaphore.count.lock().unwrap_or_else(|e| e.into_inner());
+    let mut count = semaphore
+        .posted
+        .wait_while(count, |count| *count <= 0)
+        .unwrap_or_else(|e| e.into_inner());
+    *count -= 1;
+}
+
+#[no_mangle]
+unsafe extern "C" fn Semaphore_post(semaphore: Semaphore) {
+    let semaphore = &*semaphore.cast::<HalSemaphore>();
+    *semaphore.count.lock().unwrap_or_else(|e| e.into_inner()) += 1;
+    semaphore.posted.notify_one();
+}
+
+#[no_mangle]
+unsafe extern "C" fn Semaphore_destroy(semaphore: Semaphore) {
+    if !semaphore.is_null() {
+        drop(Box::from_raw(semaphore.cast::<HalSemaphore>()));
+    }
+}
@@ -0,0 +1,62 @@
+//! `hal_time.h` on the std clocks.
+
+use std::sync::OnceLock;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use crate::bindings::{msSinceEpoch, nsSinceEpoch};
+
+/// Nanoseconds since the UNIX epoch of the system clock.
+pub(super) fn real_system_time_ns() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_nanos() as u64)
+}
+
+/// Nanoseconds of a monotonic clock, which starts at the system time of its
+/// first use so it never reads zero.
+pub(super) fn real_monotonic_time_ns() -> u64 {
+    static START: OnceLock<(Instant, u64)> = OnceLock::new();
+    let (start, offset) = START.get_or_init(|| (Instant::now(), real_system_time_ns()));
+    offset + start.elapsed().as_nanos() as u64
+}
+
+fn system_time_ns() -> u64 {
+    #[cfg(feature = "mock-clock")]
+    if let Some(time) = super::mock_clock::system_time_ns() {
+        return time;
+    }
+    real_system_time_ns()
+}
+
+fn monotonic_time_ns() -> u64 {
+    #[cfg(feature = "mock-clock")]
+    return super::mock_clock::monotonic_time_ns();
+    #[cfg(not(feature = "mock-clock"))]
+    real_monotonic_time_ns()
+}
+
+#[no_mangle]
+extern "C" fn Hal_getTimeInMs() -> msSinceEpoch {
+    system_time_ns() / 1_000_000
+}
+
+#[no_mangle]
+extern "C" fn Hal_getTimeInNs() -> nsSinceEpoch {
+    system_time_ns()
+}
+
+/// Setting the system clock is left to the application.
+#[no_mangle]
+extern "C" fn Hal_setTimeInNs(_time: nsSinceEpoch) -> bool {
+    false
+}
+
+#[no_mangle]
+extern "C" fn Hal_getMonotonicTimeInMs() -> msSinceEpoch {
+    monotonic_time_ns() / 1_000_000
+}
+
+#[no_mangle]
+extern "C" fn Hal_getMonotonicTimeInNs() -> nsSinceEpoch {
+    monotonic_time_ns()
+}
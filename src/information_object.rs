@@ -0,0 +1,1962 @@
+//! Typed information objects.
+//!
+//! [`InformationObject`] has one variant per type identification supported
+//! by lib60870. Each variant holds a plain struct that can be decoded from
+//! an ASDU with [`AsduRef::objects`](crate::asdu::AsduRef::objects) and
+//! added to one with [`AsduRef::add_object`](crate::asdu::AsduRef::add_object).
+//!
+//! Structs that exist with and without a time tag take the time type as a
+//! parameter, e.g. `SinglePoint` for `M_SP_NA_1`, `SinglePoint<Cp24Time2a>`
+//! for `M_SP_TA_1` and `SinglePoint<Cp56Time2a>` for `M_SP_TB_1`.
+
+use std::ptr;
+
+use crate::bindings as sys;
+use crate::error::{Error, Result};
+use crate::quality::{OutputCircuitInfo, Quality, StartEvents};
+use crate::timestamp::{Cp16Time2a, Cp24Time2a, Cp56Time2a};
+use crate::types::TypeId;
+
+/// State of a double point (DPI) or double command (DCS).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DoublePointState {
+    /// Intermediate state, or not permitted in a command.
+    Intermediate,
+    /// Off.
+    Off,
+    /// On.
+    On,
+    /// Indeterminate state, or not permitted in a command.
+    Indeterminate,
+}
+
+impl DoublePointState {
+    fn from_raw(value: u32) -> Self {
+        match value & 0x03 {
+            0 => Self::Intermediate,
+            1 => Self::Off,
+            2 => Self::On,
+            _ => Self::Indeterminate,
+        }
+    }
+
+    fn to_raw(self) -> u32 {
+        match self {
+            Self::Intermediate => sys::DoublePointValue_IEC60870_DOUBLE_POINT_INTERMEDIATE,
+            Self::Off => sys::DoublePointValue_IEC60870_DOUBLE_POINT_OFF,
+            Self::On => sys::DoublePointValue_IEC60870_DOUBLE_POINT_ON,
+            Self::Indeterminate => sys::DoublePointValue_IEC60870_DOUBLE_POINT_INDETERMINATE,
+        }
+    }
+}
+
+/// Regulating step command state (RCS).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StepCommandState {
+    /// Not permitted (0).
+    Invalid0,
+    /// Next step lower.
+    Lower,
+    /// Next step higher.
+    Higher,
+    /// Not permitted (3).
+    Invalid3,
+}
+
+impl StepCommandState {
+    fn from_raw(value: u32) -> Self {
+        match value & 0x03 {
+            0 => Self::Invalid0,
+            1 => Self::Lower,
+            2 => Self::Higher,
+            _ => Self::Invalid3,
+        }
+    }
+
+    fn to_raw(self) -> u32 {
+        match self {
+            Self::Invalid0 => sys::StepCommandValue_IEC60870_STEP_INVALID_0,
+            Self::Lower => sys::StepCommandValue_IEC60870_STEP_LOWER,
+            Self::Higher => sys::StepCommandValue_IEC60870_STEP_HIGHER,
+            Self::Invalid3 => sys::StepCommandValue_IEC60870_STEP_INVALID_3,
+        }
+    }
+}
+
+/// Event state of a single event of protection equipment (ES).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SingleEventState {
+    /// Indeterminate (0).
+    Indeterminate0,
+    /// Off.
+    Off,
+    /// On.
+    On,
+    /// Indeterminate (3).
+    Indeterminate3,
+}
+
+impl SingleEventState {
+    fn from_raw(value: u32) -> Self {
+        match value & 0x03 {
+            0 => Self::Indeterminate0,
+            1 => Self::Off,
+            2 => Self::On,
+            _ => Self::Indeterminate3,
+        }
+    }
+
+    fn to_raw(self) -> u32 {
+        match self {
+            Self::Indeterminate0 => sys::EventState_IEC60870_EVENTSTATE_INDETERMINATE_0,
+            Self::Off => sys::EventState_IEC60870_EVENTSTATE_OFF,
+            Self::On => sys::EventState_IEC60870_EVENTSTATE_ON,
+            Self::Indeterminate3 => sys::EventState_IEC60870_EVENTSTATE_INDETERMINATE_3,
+        }
+    }
+}
+
+/// Binary counter reading (BCR).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CounterReading {
+    /// Counter value.
+    pub value: i32,
+    /// Sequence number (0-31).
+    pub sequence_number: u8,
+    /// Counter overflow since the last reading (CY).
+    pub carry: bool,
+    /// Counter was adjusted since the last reading (CA).
+    pub adjusted: bool,
+    /// Counter reading is invalid (IV).
+    pub invalid: bool,
+}
+
+impl CounterReading {
+    unsafe fn from_raw(bcr: sys::BinaryCounterReading) -> Self {
+        Self {
+            value: sys::BinaryCounterReading_getValue(bcr),
+            sequence_number: sys::BinaryCounterReading_getSequenceNumber(bcr) as u8,
+            carry: sys::BinaryCounterReading_hasCarry(bcr),
+            adjusted: sys::BinaryCounterReading_isAdjusted(bcr),
+            invalid: sys::BinaryCounterReading_isInvalid(bcr),
+        }
+    }
+
+    fn to_raw(self) -> sys::sBinaryCounterReading {
+        let mut raw = sys::sBinaryCounterReading::default();
+        unsafe {
+            sys::BinaryCounterReading_create(
+                &mut raw,
+                self.value,
+                self.sequence_number.into(),
+                self.carry,
+                self.adjusted,
+                self.invalid,
+            );
+        }
+        raw
+    }
+}
+
+/// Single point information (`M_SP_NA_1`, `M_SP_TA_1`, `M_SP_TB_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SinglePoint<T = ()> {
+    pub ioa: u32,
+    pub value: bool,
+    pub quality: Quality,
+    pub timestamp: T,
+}
+
+/// Double point information (`M_DP_NA_1`, `M_DP_TA_1`, `M_DP_TB_1`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DoublePoint<T = ()> {
+    pub ioa: u32,
+    pub value: DoublePointState,
+    pub quality: Quality,
+    pub timestamp: T,
+}
+
+/// Step position information (`M_ST_NA_1`, `M_ST_TA_1`, `M_ST_TB_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct StepPosition<T = ()> {
+    pub ioa: u32,
+    /// Step position (-64 to 63).
+    pub value: i8,
+    /// Equipment is in transient state.
+    pub transient: bool,
+    pub quality: Quality,
+    pub timestamp: T,
+}
+
+/// Bitstring of 32 bit (`M_BO_NA_1`, `M_BO_TA_1`, `M_BO_TB_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Bitstring32<T = ()> {
+    pub ioa: u32,
+    pub value: u32,
+    pub quality: Quality,
+    pub timestamp: T,
+}
+
+/// Measured value, normalized (`M_ME_NA_1`, `M_ME_TA_1`, `M_ME_TD_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MeasuredNormalized<T = ()> {
+    pub ioa: u32,
+    /// Normalized value (-1.0 to 1.0 - 2^-15).
+    pub value: f32,
+    pub quality: Quality,
+    pub timestamp: T,
+}
+
+/// Measured value, normalized without quality descriptor (`M_ME_ND_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MeasuredNormalizedWithoutQuality {
+    pub ioa: u32,
+    pub value: f32,
+}
+
+/// Measured value, scaled (`M_ME_NB_1`, `M_ME_TB_1`, `M_ME_TE_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MeasuredScaled<T = ()> {
+    pub ioa: u32,
+    pub value: i16,
+    pub quality: Quality,
+    pub timestamp: T,
+}
+
+/// Measured value, short floating point (`M_ME_NC_1`, `M_ME_TC_1`,
+/// `M_ME_TF_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MeasuredShort<T = ()> {
+    pub ioa: u32,
+    pub value: f32,
+    pub quality: Quality,
+    pub timestamp: T,
+}
+
+/// Integrated totals (`M_IT_NA_1`, `M_IT_TA_1`, `M_IT_TB_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct IntegratedTotals<T = ()> {
+    pub ioa: u32,
+    pub value: CounterReading,
+    pub timestamp: T,
+}
+
+/// Event of protection equipment (`M_EP_TA_1`, `M_EP_TD_1`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProtectionEvent<T> {
+    pub ioa: u32,
+    pub state: SingleEventState,
+    pub quality: Quality,
+    pub elapsed_time: Cp16Time2a,
+    pub timestamp: T,
+}
+
+/// Packed start events of protection equipment (`M_EP_TB_1`, `M_EP_TE_1`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PackedStartEvents<T> {
+    pub ioa: u32,
+    pub events: StartEvents,
+    pub quality: Quality,
+    pub elapsed_time: Cp16Time2a,
+    pub timestamp: T,
+}
+
+/// Packed output circuit information of protection equipment (`M_EP_TC_1`,
+/// `M_EP_TF_1`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PackedOutputCircuit<T> {
+    pub ioa: u32,
+    pub circuits: OutputCircuitInfo,
+    pub quality: Quality,
+    pub operating_time: Cp16Time2a,
+    pub timestamp: T,
+}
+
+/// Packed single point information with status change detection
+/// (`M_PS_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PackedSinglePoints {
+    pub ioa: u32,
+    /// Status of the 16 points (ST).
+    pub status: u16,
+    /// Status change detection of the 16 points (CD).
+    pub changes: u16,
+    pub quality: Quality,
+}
+
+/// End of initialization (`M_EI_NA_1`). Always sent with IOA 0.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EndOfInitialization {
+    /// Cause of initialization (COI).
+    pub cause: u8,
+}
+
+/// Single command (`C_SC_NA_1`, `C_SC_TA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SingleCommand<T = ()> {
+    pub ioa: u32,
+    pub state: bool,
+    /// Select (true) or execute (false).
+    pub select: bool,
+    /// Qualifier of command (QU).
+    pub qualifier: u8,
+    pub timestamp: T,
+}
+
+/// Double command (`C_DC_NA_1`, `C_DC_TA_1`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DoubleCommand<T = ()> {
+    pub ioa: u32,
+    pub state: DoublePointState,
+    /// Select (true) or execute (false).
+    pub select: bool,
+    /// Qualifier of command (QU).
+    pub qualifier: u8,
+    pub timestamp: T,
+}
+
+/// Regulating step command (`C_RC_NA_1`, `C_RC_TA_1`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StepCommand<T = ()> {
+    pub ioa: u32,
+    pub state: StepCommandState,
+    /// Select (true) or execute (false).
+    pub select: bool,
+    /// Qualifier of command (QU).
+    pub qualifier: u8,
+    pub timestamp: T,
+}
+
+/// Set-point command, normalized value (`C_SE_NA_1`, `C_SE_TA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SetpointNormalized<T = ()> {
+    pub ioa: u32,
+    pub value: f32,
+    /// Select (true) or execute (false).
+    pub select: bool,
+    /// Qualifier of set-point command (QL).
+    pub qualifier: u8,
+    pub timestamp: T,
+}
+
+/// Set-point command, scaled value (`C_SE_NB_1`, `C_SE_TB_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SetpointScaled<T = ()> {
+    pub ioa: u32,
+    pub value: i16,
+    /// Select (true) or execute (false).
+    pub select: bool,
+    /// Qualifier of set-point command (QL).
+    pub qualifier: u8,
+    pub timestamp: T,
+}
+
+/// Set-point command, short floating point value (`C_SE_NC_1`,
+/// `C_SE_TC_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SetpointShort<T = ()> {
+    pub ioa: u32,
+    pub value: f32,
+    /// Select (true) or execute (false).
+    pub select: bool,
+    /// Qualifier of set-point command (QL).
+    pub qualifier: u8,
+    pub timestamp: T,
+}
+
+/// Bitstring of 32 bit command (`C_BO_NA_1`, `C_BO_TA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Bitstring32Command<T = ()> {
+    pub ioa: u32,
+    pub value: u32,
+    pub timestamp: T,
+}
+
+/// Interrogation command (`C_IC_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Interrogation {
+    pub ioa: u32,
+    /// Qualifier of interrogation (QOI), e.g. `IEC60870_QOI_STATION`.
+    pub qualifier: u8,
+}
+
+/// Counter interrogation command (`C_CI_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CounterInterrogation {
+    pub ioa: u32,
+    /// Qualifier of counter interrogation (QCC = RQT + FRZ).
+    pub qualifier: u8,
+}
+
+/// Read command (`C_RD_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Read {
+    pub ioa: u32,
+}
+
+/// Clock synchronization command (`C_CS_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ClockSync {
+    pub ioa: u32,
+    pub time: Cp56Time2a,
+}
+
+/// Test command (`C_TS_NA_1`). Always sent with IOA 0 and the fixed test
+/// pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TestCommand {
+    /// Whether the received test pattern was correct.
+    pub valid: bool,
+}
+
+/// Test command with time tag (`C_TS_TA_1`). Always sent with IOA 0.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TestCommandWithTime {
+    /// Test sequence counter (TSC).
+    pub counter: u16,
+    pub timestamp: Cp56Time2a,
+}
+
+/// Reset process command (`C_RP_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ResetProcess {
+    pub ioa: u32,
+    /// Qualifier of reset process command (QRP).
+    pub qualifier: u8,
+}
+
+/// Delay acquisition command (`C_CD_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DelayAcquisition {
+    pub ioa: u32,
+    pub delay: Cp16Time2a,
+}
+
+/// Parameter of measured value, normalized (`P_ME_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ParameterNormalized {
+    pub ioa: u32,
+    pub value: f32,
+    /// Qualifier of parameter of measured values (QPM).
+    pub qualifier: u8,
+}
+
+/// Parameter of measured value, scaled (`P_ME_NB_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ParameterScaled {
+    pub ioa: u32,
+    pub value: i16,
+    /// Qualifier of parameter of measured values (QPM).
+    pub qualifier: u8,
+}
+
+/// Parameter of measured value, short floating point (`P_ME_NC_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ParameterShort {
+    pub ioa: u32,
+    pub value: f32,
+    /// Qualifier of parameter of measured values (QPM).
+    pub qualifier: u8,
+}
+
+/// Parameter activation (`P_AC_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ParameterActivation {
+    pub ioa: u32,
+    /// Qualifier of parameter activation (QPA).
+    pub qualifier: u8,
+}
+
+/// File ready (`F_FR_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FileReady {
+    pub ioa: u32,
+    /// Name of file (NOF).
+    pub name: u16,
+    pub length: u32,
+    /// Positive (true) or negative confirm of select.
+    pub positive: bool,
+}
+
+/// Section ready (`F_SR_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SectionReady {
+    pub ioa: u32,
+    /// Name of file (NOF).
+    pub name: u16,
+    /// Name of section (NOS).
+    pub section: u8,
+    pub length: u32,
+    pub not_ready: bool,
+}
+
+/// Call directory, select file, call file or call section (`F_SC_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FileCallOrSelect {
+    pub ioa: u32,
+    /// Name of file (NOF).
+    pub name: u16,
+    /// Name of section (NOS).
+    pub section: u8,
+    /// Select and call qualifier (SCQ), e.g. `IEC60870_SCQ_REQUEST_FILE`.
+    pub qualifier: u8,
+}
+
+/// Last section or last segment (`F_LS_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FileLastSegmentOrSection {
+    pub ioa: u32,
+    /// Name of file (NOF).
+    pub name: u16,
+    /// Name of section (NOS).
+    pub section: u8,
+    /// Last section or segment qualifier (LSQ), e.g.
+    /// `CS101_LSQ_FILE_TRANSFER_WITHOUT_DEACT`.
+    pub qualifier: u8,
+    /// Checksum (CHS), the sum of all octets modulo 256.
+    pub checksum: u8,
+}
+
+/// Ack file or ack section (`F_AF_NA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FileAck {
+    pub ioa: u32,
+    /// Name of file (NOF).
+    pub name: u16,
+    /// Name of section (NOS).
+    pub section: u8,
+    /// Acknowledge file or section qualifier (AFQ), e.g.
+    /// `CS101_AFQ_POS_ACK_FILE`.
+    pub qualifier: u8,
+}
+
+/// Segment (`F_SG_NA_1`).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FileSegment {
+    pub ioa: u32,
+    /// Name of file (NOF).
+    pub name: u16,
+    /// Name of section (NOS).
+    pub section: u8,
+    pub data: Vec<u8>,
+}
+
+/// Directory entry (`F_DR_TA_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FileDirectory {
+    pub ioa: u32,
+    /// Name of file (NOF).
+    pub name: u16,
+    pub length: u32,
+    /// Status of file (SOF): STATUS in bits 0-4, LFD, FOR and FA in bits
+    /// 5-7.
+    pub status: u8,
+    pub creation_time: Cp56Time2a,
+}
+
+impl FileDirectory {
+    /// Last file of the directory (LFD).
+    pub fn is_last(&self) -> bool {
+        self.status & 0x20 != 0
+    }
+
+    /// The entry names a subdirectory (FOR).
+    pub fn is_directory(&self) -> bool {
+        self.status & 0x40 != 0
+    }
+
+    /// File transfer is active (FA).
+    pub fn is_active(&self) -> bool {
+        self.status & 0x80 != 0
+    }
+}
+
+/// Query log, request archive file (`F_SC_NB_1`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct QueryLog {
+    pub ioa: u32,
+    /// Name of file (NOF).
+    pub name: u16,
+    pub start: Cp56Time2a,
+    pub stop: Cp56Time2a,
+}
+
+macro_rules! information_objects {
+    ($($variant:ident($ty:ty),)*) => {
+        /// An information object of any type supported by lib60870.
+        ///
+        /// Variants are named after the type identification they encode.
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, PartialEq)]
+        #[non_exhaustive]
+        pub enum InformationObject {
+            $(
+                #[doc = concat!("`", stringify!($variant), "`")]
+                $variant($ty),
+            )*
+        }
+
+        impl InformationObject {
+            /// The type identification of the object.
+            pub fn type_id(&self) -> TypeId {
+                match self {
+                    $(InformationObject::$variant(_) => TypeId::$variant,)*
+                }
+            }
+        }
+
+        $(
+            impl From<$ty> for InformationObject {
+                fn from(io: $ty) -> Self {
+                    InformationObject::$variant(io)
+                }
+            }
+        )*
+    };
+}
+
+information_objects! {
+    M_SP_NA_1(SinglePoint),
+    M_SP_TA_1(SinglePoint<Cp24Time2a>),
+    M_SP_TB_1(SinglePoint<Cp56Time2a>),
+    M_DP_NA_1(DoublePoint),
+    M_DP_TA_1(DoublePoint<Cp24Time2a>),
+    M_DP_TB_1(DoublePoint<Cp56Time2a>),
+    M_ST_NA_1(StepPosition),
+    M_ST_TA_1(StepPosition<Cp24Time2a>),
+    M_ST_TB_1(StepPosition<Cp56Time2a>),
+    M_BO_NA_1(Bitstring32),
+    M_BO_TA_1(Bitstring32<Cp24Time2a>),
+    M_BO_TB_1(Bitstring32<Cp56Time2a>),
+    M_ME_NA_1(MeasuredNormalized),
+    M_ME_TA_1(MeasuredNormalized<Cp24Time2a>),
+    M_ME_TD_1(MeasuredNormalized<Cp56Time2a>),
+    M_ME_NB_1(MeasuredScaled),
+    M_ME_TB_1(MeasuredScaled<Cp24Time2a>),
+    M_ME_TE_1(MeasuredScaled<Cp56Time2a>),
+    M_ME_NC_1(MeasuredShort),
+    M_ME_TC_1(MeasuredShort<Cp24Time2a>),
+    M_ME_TF_1(MeasuredShort<Cp56Time2a>),
+    M_ME_ND_1(MeasuredNormalizedWithoutQuality),
+    M_IT_NA_1(IntegratedTotals),
+    M_IT_TA_1(IntegratedTotals<Cp24Time2a>),
+    M_IT_TB_1(IntegratedTotals<Cp56Time2a>),
+    M_EP_TA_1(ProtectionEvent<Cp24Time2a>),
+    M_EP_TD_1(ProtectionEvent<Cp56Time2a>),
+    M_EP_TB_1(PackedStartEvents<Cp24Time2a>),
+    M_EP_TE_1(PackedStartEvents<Cp56Time2a>),
+    M_EP_TC_1(PackedOutputCircuit<Cp24Time2a>),
+    M_EP_TF_1(PackedOutputCircuit<Cp56Time2a>),
+    M_PS_NA_1(PackedSinglePoints),
+    M_EI_NA_1(EndOfInitialization),
+    C_SC_NA_1(SingleCommand),
+    C_SC_TA_1(SingleCommand<Cp56Time2a>),
+    C_DC_NA_1(DoubleCommand),
+    C_DC_TA_1(DoubleCommand<Cp56Time2a>),
+    C_RC_NA_1(StepCommand),
+    C_RC_TA_1(StepCommand<Cp56Time2a>),
+    C_SE_NA_1(SetpointNormalized),
+    C_SE_TA_1(SetpointNormalized<Cp56Time2a>),
+    C_SE_NB_1(SetpointScaled),
+    C_SE_TB_1(SetpointScaled<Cp56Time2a>),
+    C_SE_NC_1(SetpointShort),
+    C_SE_TC_1(SetpointShort<Cp56Time2a>),
+    C_BO_NA_1(Bitstring32Command),
+    C_BO_TA_1(Bitstring32Command<Cp56Time2a>),
+    C_IC_NA_1(Interrogation),
+    C_CI_NA_1(CounterInterrogation),
+    C_RD_NA_1(Read),
+    C_CS_NA_1(ClockSync),
+    C_TS_NA_1(TestCommand),
+    C_TS_TA_1(TestCommandWithTime),
+    C_RP_NA_1(ResetProcess),
+    C_CD_NA_1(DelayAcquisition),
+    P_ME_NA_1(ParameterNormalized),
+    P_ME_NB_1(ParameterScaled),
+    P_ME_NC_1(ParameterShort),
+    P_AC_NA_1(ParameterActivation),
+    F_FR_NA_1(FileReady),
+    F_SR_NA_1(SectionReady),
+    F_SC_NA_1(FileCallOrSelect),
+    F_LS_NA_1(FileLastSegmentOrSection),
+    F_AF_NA_1(FileAck),
+    F_SG_NA_1(FileSegment),
+    F_DR_TA_1(FileDirectory),
+    F_SC_NB_1(QueryLog),
+}
+
+fn quality(raw: u8) -> Quality {
+    Quality::from_bits_retain(raw)
+}
+
+impl InformationObject {
+    /// The information object address. Objects that are always sent with
+    /// address 0 (`M_EI_NA_1`, `C_TS_NA_1`, `C_TS_TA_1`) return 0.
+    pub fn ioa(&self) -> u32 {
+        use InformationObject::*;
+
+        match self {
+            M_SP_NA_1(io) => io.ioa,
+            M_SP_TA_1(io) => io.ioa,
+            M_SP_TB_1(io) => io.ioa,
+            M_DP_NA_1(io) => io.ioa,
+            M_DP_TA_1(io) => io.ioa,
+            M_DP_TB_1(io) => io.ioa,
+            M_ST_NA_1(io) => io.ioa,
+            M_ST_TA_1(io) => io.ioa,
+            M_ST_TB_1(io) => io.ioa,
+            M_BO_NA_1(io) => io.ioa,
+            M_BO_TA_1(io) => io.ioa,
+            M_BO_TB_1(io) => io.ioa,
+            M_ME_NA_1(io) => io.ioa,
+            M_ME_TA_1(io) => io.ioa,
+            M_ME_TD_1(io) => io.ioa,
+            M_ME_NB_1(io) => io.ioa,
+            M_ME_TB_1(io) => io.ioa,
+            M_ME_TE_1(io) => io.ioa,
+            M_ME_NC_1(io) => io.ioa,
+            M_ME_TC_1(io) => io.ioa,
+            M_ME_TF_1(io) => io.ioa,
+            M_ME_ND_1(io) => io.ioa,
+            M_IT_NA_1(io) => io.ioa,
+            M_IT_TA_1(io) => io.ioa,
+            M_IT_TB_1(io) => io.ioa,
+            M_EP_TA_1(io) => io.ioa,
+            M_EP_TD_1(io) => io.ioa,
+            M_EP_TB_1(io) => io.ioa,
+            M_EP_TE_1(io) => io.ioa,
+            M_EP_TC_1(io) => io.ioa,
+            M_EP_TF_1(io) => io.ioa,
+            M_PS_NA_1(io) => io.ioa,
+            M_EI_NA_1(_) => 0,
+            C_SC_NA_1(io) => io.ioa,
+            C_SC_TA_1(io) => io.ioa,
+            C_DC_NA_1(io) => io.ioa,
+            C_DC_TA_1(io) => io.ioa,
+            C_RC_NA_1(io) => io.ioa,
+            C_RC_TA_1(io) => io.ioa,
+            C_SE_NA_1(io) => io.ioa,
+            C_SE_TA_1(io) => io.ioa,
+            C_SE_NB_1(io) => io.ioa,
+            C_SE_TB_1(io) => io.ioa,
+            C_SE_NC_1(io) => io.ioa,
+            C_SE_TC_1(io) => io.ioa,
+            C_BO_NA_1(io) => io.ioa,
+            C_BO_TA_1(io) => io.ioa,
+            C_IC_NA_1(io) => io.ioa,
+            C_CI_NA_1(io) => io.ioa,
+            C_RD_NA_1(io) => io.ioa,
+            C_CS_NA_1(io) => io.ioa,
+            C_TS_NA_1(_) => 0,
+            C_TS_TA_1(_) => 0,
+            C_RP_NA_1(io) => io.ioa,
+            C_CD_NA_1(io) => io.ioa,
+            P_ME_NA_1(io) => io.ioa,
+            P_ME_NB_1(io) => io.ioa,
+            P_ME_NC_1(io) => io.ioa,
+            P_AC_NA_1(io) => io.ioa,
+            F_FR_NA_1(io) => io.ioa,
+            F_SR_NA_1(io) => io.ioa,
+            F_SC_NA_1(io) => io.ioa,
+            F_LS_NA_1(io) => io.ioa,
+            F_AF_NA_1(io) => io.ioa,
+            F_SG_NA_1(io) => io.ioa,
+            F_DR_TA_1(io) => io.ioa,
+            F_SC_NB_1(io) => io.ioa,
+        }
+    }
+
+    /// Decodes an information object returned by lib60870.
+    ///
+    /// # Safety
+    ///
+    /// `io` must point to a valid information object whose concrete type
+    /// matches its type identification.
+    pub(crate) unsafe fn from_raw(io: sys::InformationObject) -> Result<Self> {
+        use InformationObject as Io;
+
+        let raw_type = sys::InformationObject_getType(io);
+        let type_id = TypeId::try_from(raw_type as u8).map_err(|e| Error::UnknownTypeId(e.0))?;
+        let ioa = sys::InformationObject_getObjectAddress(io) as u32;
+
+        Ok(match type_id {
+            TypeId::M_SP_NA_1 => {
+                let p = io as sys::SinglePointInformation;
+                Io::M_SP_NA_1(SinglePoint {
+                    ioa,
+                    value: sys::SinglePointInformation_getValue(p),
+                    quality: quality(sys::SinglePointInformation_getQuality(p)),
+                    timestamp: (),
+                })
+            }
+            TypeId::M_SP_TA_1 => {
+                let p = io as sys::SinglePointInformation;
+                Io::M_SP_TA_1(SinglePoint {
+                    ioa,
+                    value: sys::SinglePointInformation_getValue(p),
+                    quality: quality(sys::SinglePointInformation_getQuality(p)),
+                    timestamp: Cp24Time2a::from_ptr(sys::SinglePointWithCP24Time2a_getTimestamp(
+                        io.cast(),
+                    )),
+                })
+            }
+            TypeId::M_SP_TB_1 => {
+                let p = io as sys::SinglePointInformation;
+                Io::M_SP_TB_1(SinglePoint {
+                    ioa,
+                    value: sys::SinglePointInformation_getValue(p),
+                    quality: quality(sys::SinglePointInformation_getQuality(p)),
+                    timestamp: Cp56Time2a::from_ptr(sys::SinglePointWithCP56Time2a_getTimestamp(
+                        io.cast(),
+                    )),
+                })
+            }
+            TypeId::M_DP_NA_1 => {
+                let p = io as sys::DoublePointInformation;
+                Io::M_DP_NA_1(DoublePoint {
+                    ioa,
+                    value: DoublePointState::from_raw(sys::DoublePointInformation_getValue(p)),
+                    quality: quality(sys::DoublePointInformation_getQuality(p)),
+                    timestamp: (),
+                })
+            }
+            TypeId::M_DP_TA_1 => {
+                let p = io as sys::DoublePointInformation;
+                Io::M_DP_TA_1(DoublePoint {
+                    ioa,
+                    value: DoublePointState::from_raw(sys::DoublePointInformation_getValue(p)),
+                    quality: quality(sys::DoublePointInformation_getQuality(p)),
+                    timestamp: Cp24Time2a::from_ptr(sys::DoublePointWithCP24Time2a_getTimestamp(
+                        io.cast(),
+                    )),
+                })
+            }
+            TypeId::M_DP_TB_1 => {
+                let p = io as sys::DoublePointInformation;
+                Io::M_DP_TB_1(DoublePoint {
+                    ioa,
+                    value: DoublePointState::from_raw(sys::DoublePointInformation_getValue(p)),
+                    quality: quality(sys::DoublePointInformation_getQuality(p)),
+                    timestamp: Cp56Time2a::from_ptr(sys::DoublePointWithCP56Time2a_getTimestamp(
+                        io.cast(),
+                    )),
+                })
+            }
+            TypeId::M_ST_NA_1 => {
+                let p = io as sys::StepPositionInformation;
+                Io::M_ST_NA_1(StepPosition {
+                    ioa,
+                    value: sys::StepPositionInformation_getValue(p) as i8,
+                    transient: sys::StepPositionInformation_isTransient(p),
+                    quality: quality(sys::StepPositionInformation_getQuality(p)),
+                    timestamp: (),
+                })
+            }
+            TypeId::M_ST_TA_1 => {
+                let p = io as sys::StepPositionInformation;
+                Io::M_ST_TA_1(StepPosition {
+                    ioa,
+                    value: sys::StepPositionInformation_getValue(p) as i8,
+                    transient: sys::StepPositionInformation_isTransient(p),
+                    quality: quality(sys::StepPositionInformation_getQuality(p)),
+                    timestamp: Cp24Time2a::from_ptr(sys::StepPositionWithCP24Time2a_getTimestamp(
+                        io.cast(),
+                    )),
+                })
+            }
+            TypeId::M_ST_TB_1 => {
+                let p = io as sys::StepPositionInformation;
+                Io::M_ST_TB_1(StepPosition {
+                    ioa,
+                    value: sys::StepPositionInformation_getValue(p) as i8,
+                    transient: sys::StepPositionInformation_isTransient(p),
+                    quality: quality(sys::StepPositionInformation_getQuality(p)),
+                    timestamp: Cp56Time2a::from_ptr(sys::StepPositionWithCP56Time2a_getTimestamp(
+                        io.cast(),
+                    )),
+                })
+            }
+            TypeId::M_BO_NA_1 => {
+                let p = io as sys::BitString32;
+                Io::M_BO_NA_1(Bitstring32 {
+                    ioa,
+                    value: sys::BitString32_getValue(p),
+                    quality: quality(sys::BitString32_getQuality(p)),
+                    timestamp: (),
+                })
+            }
+            TypeId::M_BO_TA_1 => {
+                let p = io as sys::BitString32;
+                Io::M_BO_TA_1(Bitstring32 {
+                    ioa,
+                    value: sys::BitString32_getValue(p),
+                    quality: quality(sys::BitString32_getQuality(p)),
+                    timestamp: Cp24Time2a::from_ptr(sys::Bitstring32WithCP24Time2a_getTimestamp(
+                        io.cast(),
+                    )),
+                })
+            }
+            TypeId::M_BO_TB_1 => {
+                let p = io as sys::BitString32;
+                Io::M_BO_TB_1(Bitstring32 {
+                    ioa,
+                    value: sys::BitString32_getValue(p),
+                    quality: quality(sys::BitString32_getQuality(p)),
+                    timestamp: Cp56Time2a::from_ptr(sys::Bitstring32WithCP56Time2a_getTimestamp(
+                        io.cast(),
+                    )),
+                })
+            }
+            TypeId::M_ME_NA_1 => {
+                let p = io as sys::MeasuredValueNormalized;
+                Io::M_ME_NA_1(MeasuredNormalized {
+                    ioa,
+                    value: sys::MeasuredValueNormalized_getValue(p),
+                    quality: quality(sys::MeasuredValueNormalized_getQuality(p)),
+                    timestamp: (),
+                })
+            }
+            TypeId::M_ME_TA_1 => {
+                let p = io as sys::MeasuredValueNormalized;
+                Io::M_ME_TA_1(MeasuredNormalized {
+                    ioa,
+                    value: sys::MeasuredValueNormalized_getValue(p),
+                    quality: quality(sys::MeasuredValueNormalized_getQuality(p)),
+                    timestamp: Cp24Time2a::from_ptr(
+                        sys::MeasuredValueNormalizedWithCP24Time2a_getTimestamp(io.cast()),
+                    ),
+                })
+            }
+            TypeId::M_ME_TD_1 => {
+                let p = io as sys::MeasuredValueNormalized;
+                Io::M_ME_TD_1(MeasuredNormalized {
+                    ioa,
+                    value: sys::MeasuredValueNormalized_getValue(p),
+                    quality: quality(sys::MeasuredValueNormalized_getQuality(p)),
+                    timestamp: Cp56Time2a::from_ptr(
+                        sys::MeasuredValueNormalizedWithCP56Time2a_getTimestamp(io.cast()),
+                    ),
+                })
+            }
+            TypeId::M_ME_NB_1 => {
+                let p = io as sys::MeasuredValueScaled;
+                Io::M_ME_NB_1(MeasuredScaled {
+                    ioa,
+                    value: sys::MeasuredValueScaled_getValue(p) as i16,
+                    quality: quality(sys::MeasuredValueScaled_getQuality(p)),
+                    timestamp: (),
+                })
+            }
+            TypeId::M_ME_TB_1 => {
+                let p = io as sys::MeasuredValueScaled;
+                Io::M_ME_TB_1(MeasuredScaled {
+                    ioa,
+                    value: sys::MeasuredValueScaled_getValue(p) as i16,
+                    quality: quality(sys::MeasuredValueScaled_getQuality(p)),
+                    timestamp: Cp24Time2a::from_ptr(
+                        sys::MeasuredValueScaledWithCP24Time2a_getTimestamp(io.cast()),
+                    ),
+                })
+            }
+            TypeId::M_ME_TE_1 => {
+                let p = io as sys::MeasuredValueScaled;
+                Io::M_ME_TE_1(MeasuredScaled {
+                    ioa,
+                    value: sys::MeasuredValueScaled_getValue(p) as i16,
+                    quality: quality(sys::MeasuredValueScaled_getQuality(p)),
+                    timestamp: Cp56Time2a::from_ptr(
+                        sys::MeasuredValueScaledWithCP56Time2a_getTimestamp(io.cast()),
+                    ),
+                })
+            }
+            TypeId::M_ME_NC_1 => {
+                let p = io as sys::MeasuredValueShort;
+                Io::M_ME_NC_1(MeasuredShort {
+                    ioa,
+                    value: sys::MeasuredValueShort_getValue(p),
+                    quality: quality(sys::MeasuredValueShort_getQuality(p)),
+                    timestamp: (),
+                })
+            }
+            TypeId::M_ME_TC_1 => {
+                let p = io as sys::MeasuredValueShort;
+                Io::M_ME_TC_1(MeasuredShort {
+                    ioa,
+                    value: sys::MeasuredValueShort_getValue(p),
+                    quality: quality(sys::MeasuredValueShort_getQuality(p)),
+                    timestamp: Cp24Time2a::from_ptr(
+                        sys::MeasuredValueShortWithCP24Time2a_getTimestamp(io.cast()),
+                    ),
+                })
+            }
+            TypeId::M_ME_TF_1 => {
+                let p = io as sys::MeasuredValueShort;
+                Io::M_ME_TF_1(MeasuredShort {
+                    ioa,
+                    value: sys::MeasuredValueShort_getValue(p),
+                    quality: quality(sys::MeasuredValueShort_getQuality(p)),
+                    timestamp: Cp56Time2a::from_ptr(
+                        sys::MeasuredValueShortWithCP56Time2a_getTimestamp(io.cast()),
+                    ),
+                })
+            }
+            TypeId::M_ME_ND_1 => Io::M_ME_ND_1(MeasuredNormalizedWithoutQuality {
+                ioa,
+                value: sys::MeasuredValueNormalizedWithoutQuality_getValue(io.cast()),
+            }),
+            TypeId::M_IT_NA_1 => Io::M_IT_NA_1(IntegratedTotals {
+                ioa,
+                value: CounterReading::from_raw(sys::IntegratedTotals_getBCR(io.cast())),
+                timestamp: (),
+            }),
+            TypeId::M_IT_TA_1 => Io::M_IT_TA_1(IntegratedTotals {
+                ioa,
+                value: CounterReading::from_raw(sys::IntegratedTotals_getBCR(io.cast())),
+                timestamp: Cp24Time2a::from_ptr(sys::IntegratedTotalsWithCP24Time2a_getTimestamp(
+                    io.cast(),
+                )),
+            }),
+            TypeId::M_IT_TB_1 => Io::M_IT_TB_1(IntegratedTotals {
+                ioa,
+                value: CounterReading::from_raw(sys::IntegratedTotals_getBCR(io.cast())),
+                timestamp: Cp56Time2a::from_ptr(sys::IntegratedTotalsWithCP56Time2a_getTimestamp(
+                    io.cast(),
+                )),
+            }),
+            TypeId::M_EP_TA_1 => {
+                let p = io as sys::EventOfProtectionEquipment;
+                let event = sys::EventOfProtectionEquipment_getEvent(p);
+                Io::M_EP_TA_1(ProtectionEvent {
+                    ioa,
+                    state: SingleEventState::from_raw(sys::SingleEvent_getEventState(event)),
+                    quality: quality(sys::SingleEvent_getQDP(event)),
+                    elapsed_time: Cp16Time2a::from_ptr(
+                        sys::EventOfProtectionEquipment_getElapsedTime(p),
+                    ),
+                    timestamp: Cp24Time2a::from_ptr(sys::EventOfProtectionEquipment_getTimestamp(
+                        p,
+                    )),
+                })
+            }
+            TypeId::M_EP_TD_1 => {
+                let p = io as sys::EventOfProtectionEquipmentWithCP56Time2a;
+                let event = sys::EventOfProtectionEquipmentWithCP56Time2a_getEvent(p);
+                Io::M_EP_TD_1(ProtectionEvent {
+                    ioa,
+                    state: SingleEventState::from_raw(sys::SingleEvent_getEventState(event)),
+                    quality: quality(sys::SingleEvent_getQDP(event)),
+                    elapsed_time: Cp16Time2a::from_ptr(
+                        sys::EventOfProtectionEquipmentWithCP56Time2a_getElapsedTime(p),
+                    ),
+                    timestamp: Cp56Time2a::from_ptr(
+                        sys::EventOfProtectionEquipmentWithCP56Time2a_getTimestamp(p),
+                    ),
+                })
+            }
+            TypeId::M_EP_TB_1 => {
+                let p = io as sys::PackedStartEventsOfProtectionEquipment;
+                Io::M_EP_TB_1(PackedStartEvents {
+                    ioa,
+                    events: StartEvents::from_bits_retain(
+                        sys::PackedStartEventsOfProtectionEquipment_getEvent(p),
+                    ),
+                    quality: quality(sys::PackedStartEventsOfProtectionEquipment_getQuality(p)),
+                    elapsed_time: Cp16Time2a::from_ptr(
+                        sys::PackedStartEventsOfProtectionEquipment_getElapsedTime(p),
+                    ),
+                    timestamp: Cp24Time2a::from_ptr(
+                        sys::PackedStartEventsOfProtectionEquipment_getTimestamp(p),
+                    ),
+                })
+            }
+            TypeId::M_EP_TE_1 => {
+                let p = io as sys::PackedStartEventsOfProtectionEquipmentWithCP56Time2a;
+                Io::M_EP_TE_1(PackedStartEvents {
+                    ioa,
+                    events: StartEvents::from_bits_retain(
+                        sys::PackedStartEventsOfProtectionEquipmentWithCP56Time2a_getEvent(p),
+                    ),
+                    quality: quality(
+                        sys::PackedStartEventsOfProtectionEquipmentWithCP56Time2a_getQuality(p),
+                    ),
+                    elapsed_time: Cp16Time2a::from_ptr(
+                        sys::PackedStartEventsOfProtectionEquipmentWithCP56Time2a_getElapsedTime(p),
+                    ),
+                    timestamp: Cp56Time2a::from_ptr(
+                        sys::PackedStartEventsOfProtectionEquipmentWithCP56Time2a_getTimestamp(p),
+                    ),
+                })
+            }
+            TypeId::M_EP_TC_1 => {
+                let p = io as sys::PackedOutputCircuitInfo;
+                Io::M_EP_TC_1(PackedOutputCircuit {
+                    ioa,
+                    circuits: OutputCircuitInfo::from_bits_retain(
+                        sys::PackedOutputCircuitInfo_getOCI(p),
+                    ),
+                    quality: quality(sys::PackedOutputCircuitInfo_getQuality(p)),
+                    operating_time: Cp16Time2a::from_ptr(
+                        sys::PackedOutputCircuitInfo_getOperatingTime(p),
+                    ),
+                    timestamp: Cp24Time2a::from_ptr(sys::PackedOutputCircuitInfo_getTimestamp(p)),
+                })
+            }
+            TypeId::M_EP_TF_1 => {
+                let p = io as sys::PackedOutputCircuitInfoWithCP56Time2a;
+                Io::M_EP_TF_1(PackedOutputCircuit {
+                    ioa,
+                    circuits: OutputCircuitInfo::from_bits_retain(
+                        sys::PackedOutputCircuitInfoWithCP56Time2a_getOCI(p),
+                    ),
+                    quality: quality(sys::PackedOutputCircuitInfoWithCP56Time2a_getQuality(p)),
+                    operating_time: Cp16Time2a::from_ptr(
+                        sys::PackedOutputCircuitInfoWithCP56Time2a_getOperatingTime(p),
+                    ),
+                    timestamp: Cp56Time2a::from_ptr(
+                        sys::PackedOutputCircuitInfoWithCP56Time2a_getTimestamp(p),
+                    ),
+                })
+            }
+            TypeId::M_PS_NA_1 => {
+                let p = io as sys::PackedSinglePointWithSCD;
+                let scd = sys::PackedSinglePointWithSCD_getSCD(p);
+                Io::M_PS_NA_1(PackedSinglePoints {
+                    ioa,
+                    status: sys::StatusAndStatusChangeDetection_getSTn(scd),
+                    changes: sys::StatusAndStatusChangeDetection_getCDn(scd),
+                    quality: quality(sys::PackedSinglePointWithSCD_getQuality(p)),
+                })
+            }
+            TypeId::M_EI_NA_1 => Io::M_EI_NA_1(EndOfInitialization {
+                cause: sys::EndOfInitialization_getCOI(io.cast()),
+            }),
+            TypeId::C_SC_NA_1 => {
+                let p = io as sys::SingleCommand;
+                Io::C_SC_NA_1(SingleCommand {
+                    ioa,
+                    state: sys::SingleCommand_getState(p),
+                    select: sys::SingleCommand_isSelect(p),
+                    qualifier: sys::SingleCommand_getQU(p) as u8,
+                    timestamp: (),
+                })
+            }
+            TypeId::C_SC_TA_1 => {
+                let p = io as sys::SingleCommand;
+                Io::C_SC_TA_1(SingleCommand {
+                    ioa,
+                    state: sys::SingleCommand_getState(p),
+                    select: sys::SingleCommand_isSelect(p),
+                    qualifier: sys::SingleCommand_getQU(p) as u8,
+                    timestamp: Cp56Time2a::from_ptr(sys::SingleCommandWithCP56Time2a_getTimestamp(
+                        io.cast(),
+                    )),
+                })
+            }
+            TypeId::C_DC_NA_1 => {
+                let p = io as sys::DoubleCommand;
+                Io::C_DC_NA_1(DoubleCommand {
+                    ioa,
+                    state: DoublePointState::from_raw(sys::DoubleCommand_getState(p) as u32),
+                    select: sys::DoubleCommand_isSelect(p),
+                    qualifier: sys::DoubleCommand_getQU(p) as u8,
+                    timestamp: (),
+                })
+            }
+            TypeId::C_DC_TA_1 => {
+                let p = io as sys::DoubleCommandWithCP56Time2a;
+                Io::C_DC_TA_1(DoubleCommand {
+                    ioa,
+                    state: DoublePointState::from_raw(
+                        sys::DoubleCommandWithCP56Time2a_getState(p) as u32
+                    ),
+                    select: sys::DoubleCommandWithCP56Time2a_isSelect(p),
+                    qualifier: sys::DoubleCommandWithCP56Time2a_getQU(p) as u8,
+                    timestamp: Cp56Time2a::from_ptr(sys::DoubleCommandWithCP56Time2a_getTimestamp(
+                        p,
+                    )),
+                })
+            }
+            TypeId::C_RC_NA_1 => {
+                let p = io as sys::StepCommand;
+                Io::C_RC_NA_1(StepCommand {
+                    ioa,
+                    state: StepCommandState::from_raw(sys::StepCommand_getState(p)),
+                    select: sys::StepCommand_isSelect(p),
+                    qualifier: sys::StepCommand_getQU(p) as u8,
+                    timestamp: (),
+                })
+            }
+            TypeId::C_RC_TA_1 => {
+                let p = io as sys::StepCommandWithCP56Time2a;
+                Io::C_RC_TA_1(StepCommand {
+                    ioa,
+                    state: StepCommandState::from_raw(sys::StepCommandWithCP56Time2a_getState(p)),
+                    select: sys::StepCommandWithCP56Time2a_isSelect(p),
+                    qualifier: sys::StepCommandWithCP56Time2a_getQU(p) as u8,
+                    timestamp: Cp56Time2a::from_ptr(sys::StepCommandWithCP56Time2a_getTimestamp(p)),
+                })
+            }
+            TypeId::C_SE_NA_1 => {
+                let p = io as sys::SetpointCommandNormalized;
+                Io::C_SE_NA_1(SetpointNormalized {
+                    ioa,
+                    value: sys::SetpointCommandNormalized_getValue(p),
+                    select: sys::SetpointCommandNormalized_isSelect(p),
+                    qualifier: sys::SetpointCommandNormalized_getQL(p) as u8,
+                    timestamp: (),
+                })
+            }
+            TypeId::C_SE_TA_1 => {
+                let p = io as sys::SetpointCommandNormalizedWithCP56Time2a;
+                Io::C_SE_TA_1(SetpointNormalized {
+                    ioa,
+                    value: sys::SetpointCommandNormalizedWithCP56Time2a_getValue(p),
+                    select: sys::SetpointCommandNormalizedWithCP56Time2a_isSelect(p),
+                    qualifier: sys::SetpointCommandNormalizedWithCP56Time2a_getQL(p) as u8,
+                    timestamp: Cp56Time2a::from_ptr(
+                        sys::SetpointCommandNormalizedWithCP56Time2a_getTimestamp(p),
+                    ),
+                })
+            }
+            TypeId::C_SE_NB_1 => {
+                let p = io as sys::SetpointCommandScaled;
+                Io::C_SE_NB_1(SetpointScaled {
+                    ioa,
+                    value: sys::SetpointCommandScaled_getValue(p) as i16,
+                    select: sys::SetpointCommandScaled_isSelect(p),
+                    qualifier: sys::SetpointCommandScaled_getQL(p) as u8,
+                    timestamp: (),
+                })
+            }
+            TypeId::C_SE_TB_1 => {
+                let p = io as sys::SetpointCommandScaledWithCP56Time2a;
+                Io::C_SE_TB_1(SetpointScaled {
+                    ioa,
+                    value: sys::SetpointCommandScaledWithCP56Time2a_getValue(p) as i16,
+                    select: sys::SetpointCommandScaledWithCP56Time2a_isSelect(p),
+                    qualifier: sys::SetpointCommandScaledWithCP56Time2a_getQL(p) as u8,
+                    timestamp: Cp56Time2a::from_ptr(
+                        sys::SetpointCommandScaledWithCP56Time2a_getTimestamp(p),
+                    ),
+                })
+            }
+            TypeId::C_SE_NC_1 => {
+                let p = io as sys::SetpointCommandShort;
+                Io::C_SE_NC_1(SetpointShort {
+                    ioa,
+                    value: sys::SetpointCommandShort_getValue(p),
+                    select: sys::SetpointCommandShort_isSelect(p),
+                    qualifier: sys::SetpointCommandShort_getQL(p) as u8,
+                    timestamp: (),
+                })
+            }
+            TypeId::C_SE_TC_1 => {
+                let p = io as sys::SetpointCommandShortWithCP56Time2a;
+                Io::C_SE_TC_1(SetpointShort {
+                    ioa,
+                    value: sys::SetpointCommandShortWithCP56Time2a_getValue(p),
+                    select: sys::SetpointCommandShortWithCP56Time2a_isSelect(p),
+                    qualifier: sys::SetpointCommandShortWithCP56Time2a_getQL(p) as u8,
+                    timestamp: Cp56Time2a::from_ptr(
+                        sys::SetpointCommandShortWithCP56Time2a_getTimestamp(p),
+                    ),
+                })
+            }
+            TypeId::C_BO_NA_1 => Io::C_BO_NA_1(Bitstring32Command {
+                ioa,
+                value: sys::Bitstring32Command_getValue(io.cast()),
+                timestamp: (),
+            }),
+            TypeId::C_BO_TA_1 => {
+                let p = io as sys::Bitstring32CommandWithCP56Time2a;
+                Io::C_BO_TA_1(Bitstring32Command {
+                    ioa,
+                    value: sys::Bitstring32CommandWithCP56Time2a_getValue(p),
+                    timestamp: Cp56Time2a::from_ptr(
+                        sys::Bitstring32CommandWithCP56Time2a_getTimestamp(p),
+                    ),
+                })
+            }
+            TypeId::C_IC_NA_1 => Io::C_IC_NA_1(Interrogation {
+                ioa,
+                qualifier: sys::InterrogationCommand_getQOI(io.cast()),
+            }),
+            TypeId::C_CI_NA_1 => Io::C_CI_NA_1(CounterInterrogation {
+                ioa,
+                qualifier: sys::CounterInterrogationCommand_getQCC(io.cast()),
+            }),
+            TypeId::C_RD_NA_1 => Io::C_RD_NA_1(Read { ioa }),
+            TypeId::C_CS_NA_1 => Io::C_CS_NA_1(ClockSync {
+                ioa,
+                time: Cp56Time2a::from_ptr(sys::ClockSynchronizationCommand_getTime(io.cast())),
+            }),
+            TypeId::C_TS_NA_1 => Io::C_TS_NA_1(TestCommand {
+                valid: sys::TestCommand_isValid(io.cast()),
+            }),
+            TypeId::C_TS_TA_1 => {
+                let p = io as sys::TestCommandWithCP56Time2a;
+                Io::C_TS_TA_1(TestCommandWithTime {
+                    counter: sys::TestCommandWithCP56Time2a_getCounter(p),
+                    timestamp: Cp56Time2a::from_ptr(sys::TestCommandWithCP56Time2a_getTimestamp(p)),
+                })
+            }
+            TypeId::C_RP_NA_1 => Io::C_RP_NA_1(ResetProcess {
+                ioa,
+                qualifier: sys::ResetProcessCommand_getQRP(io.cast()),
+            }),
+            TypeId::C_CD_NA_1 => Io::C_CD_NA_1(DelayAcquisition {
+                ioa,
+                delay: Cp16Time2a::from_ptr(sys::DelayAcquisitionCommand_getDelay(io.cast())),
+            }),
+            TypeId::P_ME_NA_1 => {
+                let p = io as sys::ParameterNormalizedValue;
+                Io::P_ME_NA_1(ParameterNormalized {
+                    ioa,
+                    value: sys::ParameterNormalizedValue_getValue(p),
+                    qualifier: sys::ParameterNormalizedValue_getQPM(p),
+                })
+            }
+            TypeId::P_ME_NB_1 => {
+                let p = io as sys::ParameterScaledValue;
+                Io::P_ME_NB_1(ParameterScaled {
+                    ioa,
+                    value: sys::ParameterScaledValue_getValue(p) as i16,
+                    qualifier: sys::ParameterScaledValue_getQPM(p),
+                })
+            }
+            TypeId::P_ME_NC_1 => {
+                let p = io as sys::ParameterFloatValue;
+                Io::P_ME_NC_1(ParameterShort {
+                    ioa,
+                    value: sys::ParameterFloatValue_getValue(p),
+                    qualifier: sys::ParameterFloatValue_getQPM(p),
+                })
+            }
+            TypeId::P_AC_NA_1 => Io::P_AC_NA_1(ParameterActivation {
+                ioa,
+                qualifier: sys::ParameterActivation_getQuality(io.cast()),
+            }),
+            TypeId::F_FR_NA_1 => {
+                let p = io as sys::FileReady;
+                Io::F_FR_NA_1(FileReady {
+                    ioa,
+                    name: sys::FileReady_getNOF(p),
+                    length: sys::FileReady_getLengthOfFile(p),
+                    positive: sys::FileReady_isPositive(p),
+                })
+            }
+            TypeId::F_SR_NA_1 => {
+                let p = io as sys::SectionReady;
+                Io::F_SR_NA_1(SectionReady {
+                    ioa,
+                    name: sys::SectionReady_getNOF(p),
+                    section: sys::SectionReady_getNameOfSection(p),
+                    length: sys::SectionReady_getLengthOfSection(p),
+                    not_ready: sys::SectionReady_isNotReady(p),
+                })
+            }
+            TypeId::F_SC_NA_1 => {
+                let p = io as sys::FileCallOrSelect;
+                Io::F_SC_NA_1(FileCallOrSelect {
+                    ioa,
+                    name: sys::FileCallOrSelect_getNOF(p),
+                    section: sys::FileCallOrSelect_getNameOfSection(p),
+                    qualifier: sys::FileCallOrSelect_getSCQ(p),
+                })
+            }
+            TypeId::F_LS_NA_1 => {
+                let p = io as sys::FileLastSegmentOrSection;
+                Io::F_LS_NA_1(FileLastSegmentOrSection {
+                    ioa,
+                    name: sys::FileLastSegmentOrSection_getNOF(p),
+                    section: sys::FileLastSegmentOrSection_getNameOfSection(p),
+                    qualifier: sys::FileLastSegmentOrSection_getLSQ(p),
+                    checksum: sys::FileLastSegmentOrSection_getCHS(p),
+                })
+            }
+            TypeId::F_AF_NA_1 => {
+                let p = io as sys::FileACK;
+                Io::F_AF_NA_1(FileAck {
+                    ioa,
+                    name: sys::FileACK_getNOF(p),
+                    section: sys::FileACK_getNameOfSection(p),
+                    qualifier: sys::FileACK_getAFQ(p),
+                })
+            }
+            TypeId::F_SG_NA_1 => {
+                let p = io as sys::FileSegment;
+                let data = sys::FileSegment_getSegmentData(p);
+                let len = sys::FileSegment_getLengthOfSegment(p) as usize;
+                Io::F_SG_NA_1(FileSegment {
+                    ioa,
+                    name: sys::FileSegment_getNOF(p),
+                    section: sys::FileSegment_getNameOfSection(p),
+                    data: if data.is_null() {
+                        Vec::new()
+                    } else {
+                        std::slice::from_raw_parts(data, len).to_vec()
+                    },
+                })
+            }
+            TypeId::F_DR_TA_1 => {
+                let p = io as sys::FileDirectory;
+                Io::F_DR_TA_1(FileDirectory {
+                    ioa,
+                    name: sys::FileDirectory_getNOF(p),
+                    length: sys::FileDirectory_getLengthOfFile(p),
+                    status: sys::FileDirectory_getSOF(p),
+                    creation_time: Cp56Time2a::from_ptr(sys::FileDirectory_getCreationTime(p)),
+                })
+            }
+            TypeId::F_SC_NB_1 => {
+                let p = io as sys::QueryLog;
+                Io::F_SC_NB_1(QueryLog {
+                    ioa,
+                    name: sys::QueryLog_getNOF(p),
+                    start: Cp56Time2a::from_ptr(sys::QueryLog_getRangeStartTime(p)),
+                    stop: Cp56Time2a::from_ptr(sys::QueryLog_getRangeStopTime(p)),
+                })
+            }
+            other => return Err(Error::UnsupportedType(other)),
+        })
+    }
+
+    /// Creates the lib60870 representation of the object.
+    ///
+    /// The caller owns the returned object and has to destroy it with
+    /// `InformationObject_destroy`.
+    pub(crate) fn to_raw(&self) -> Result<sys::InformationObject> {
+        use InformationObject as Io;
+
+        let io: sys::InformationObject = unsafe {
+            match self {
+                Io::M_SP_NA_1(io) => sys::SinglePointInformation_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                )
+                .cast(),
+                Io::M_SP_TA_1(io) => sys::SinglePointWithCP24Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_SP_TB_1(io) => sys::SinglePointWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_DP_NA_1(io) => sys::DoublePointInformation_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.to_raw(),
+                    io.quality.bits(),
+                )
+                .cast(),
+                Io::M_DP_TA_1(io) => sys::DoublePointWithCP24Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.to_raw(),
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_DP_TB_1(io) => sys::DoublePointWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.to_raw(),
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_ST_NA_1(io) => sys::StepPositionInformation_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.into(),
+                    io.transient,
+                    io.quality.bits(),
+                )
+                .cast(),
+                Io::M_ST_TA_1(io) => sys::StepPositionWithCP24Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.into(),
+                    io.transient,
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_ST_TB_1(io) => sys::StepPositionWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.into(),
+                    io.transient,
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_BO_NA_1(io) => sys::BitString32_createEx(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                )
+                .cast(),
+                Io::M_BO_TA_1(io) => sys::Bitstring32WithCP24Time2a_createEx(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_BO_TB_1(io) => sys::Bitstring32WithCP56Time2a_createEx(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_ME_NA_1(io) => sys::MeasuredValueNormalized_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                )
+                .cast(),
+                Io::M_ME_TA_1(io) => sys::MeasuredValueNormalizedWithCP24Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_ME_TD_1(io) => sys::MeasuredValueNormalizedWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_ME_NB_1(io) => sys::MeasuredValueScaled_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.into(),
+                    io.quality.bits(),
+                )
+                .cast(),
+                Io::M_ME_TB_1(io) => sys::MeasuredValueScaledWithCP24Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.into(),
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_ME_TE_1(io) => sys::MeasuredValueScaledWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.into(),
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_ME_NC_1(io) => sys::MeasuredValueShort_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                )
+                .cast(),
+                Io::M_ME_TC_1(io) => sys::MeasuredValueShortWithCP24Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_ME_TF_1(io) => sys::MeasuredValueShortWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.quality.bits(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_ME_ND_1(io) => sys::MeasuredValueNormalizedWithoutQuality_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                )
+                .cast(),
+                Io::M_IT_NA_1(io) => {
+                    let mut bcr = io.value.to_raw();
+                    sys::IntegratedTotals_create(ptr::null_mut(), io.ioa as i32, &mut bcr).cast()
+                }
+                Io::M_IT_TA_1(io) => {
+                    let mut bcr = io.value.to_raw();
+                    sys::IntegratedTotalsWithCP24Time2a_create(
+                        ptr::null_mut(),
+                        io.ioa as i32,
+                        &mut bcr,
+                        io.timestamp.as_ptr(),
+                    )
+                    .cast()
+                }
+                Io::M_IT_TB_1(io) => {
+                    let mut bcr = io.value.to_raw();
+                    sys::IntegratedTotalsWithCP56Time2a_create(
+                        ptr::null_mut(),
+                        io.ioa as i32,
+                        &mut bcr,
+                        io.timestamp.as_ptr(),
+                    )
+                    .cast()
+                }
+                Io::M_EP_TA_1(io) => {
+                    let mut event = single_event(io.state, io.quality);
+                    sys::EventOfProtectionEquipment_create(
+                        ptr::null_mut(),
+                        io.ioa as i32,
+                        &mut event,
+                        io.elapsed_time.as_ptr(),
+                        io.timestamp.as_ptr(),
+                    )
+                    .cast()
+                }
+                Io::M_EP_TD_1(io) => {
+                    let mut event = single_event(io.state, io.quality);
+                    sys::EventOfProtectionEquipmentWithCP56Time2a_create(
+                        ptr::null_mut(),
+                        io.ioa as i32,
+                        &mut event,
+                        io.elapsed_time.as_ptr(),
+                        io.timestamp.as_ptr(),
+                    )
+                    .cast()
+                }
+                Io::M_EP_TB_1(io) => sys::PackedStartEventsOfProtectionEquipment_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.events.bits(),
+                    io.quality.bits(),
+                    io.elapsed_time.as_ptr(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_EP_TE_1(io) => {
+                    sys::PackedStartEventsOfProtectionEquipmentWithCP56Time2a_create(
+                        ptr::null_mut(),
+                        io.ioa as i32,
+                        io.events.bits(),
+                        io.quality.bits(),
+                        io.elapsed_time.as_ptr(),
+                        io.timestamp.as_ptr(),
+                    )
+                    .cast()
+                }
+                Io::M_EP_TC_1(io) => sys::PackedOutputCircuitInfo_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.circuits.bits(),
+                    io.quality.bits(),
+                    io.operating_time.as_ptr(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_EP_TF_1(io) => sys::PackedOutputCircuitInfoWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.circuits.bits(),
+                    io.quality.bits(),
+                    io.operating_time.as_ptr(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::M_PS_NA_1(io) => {
+                    let [st0, st1] = io.status.to_le_bytes();
+                    let [cd0, cd1] = io.changes.to_le_bytes();
+                    let mut scd = sys::sStatusAndStatusChangeDetection {
+                        encodedValue: [st0, st1, cd0, cd1],
+                    };
+                    sys::PackedSinglePointWithSCD_create(
+                        ptr::null_mut(),
+                        io.ioa as i32,
+                        &mut scd,
+                        io.quality.bits(),
+                    )
+                    .cast()
+                }
+                Io::M_EI_NA_1(io) => {
+                    sys::EndOfInitialization_create(ptr::null_mut(), io.cause).cast()
+                }
+                Io::C_SC_NA_1(io) => sys::SingleCommand_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.state,
+                    io.select,
+                    io.qualifier.into(),
+                )
+                .cast(),
+                Io::C_SC_TA_1(io) => sys::SingleCommandWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.state,
+                    io.select,
+                    io.qualifier.into(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::C_DC_NA_1(io) => sys::DoubleCommand_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.state.to_raw() as i32,
+                    io.select,
+                    io.qualifier.into(),
+                )
+                .cast(),
+                Io::C_DC_TA_1(io) => sys::DoubleCommandWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.state.to_raw() as i32,
+                    io.select,
+                    io.qualifier.into(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::C_RC_NA_1(io) => sys::StepCommand_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.state.to_raw(),
+                    io.select,
+                    io.qualifier.into(),
+                )
+                .cast(),
+                Io::C_RC_TA_1(io) => sys::StepCommandWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.state.to_raw(),
+                    io.select,
+                    io.qualifier.into(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::C_SE_NA_1(io) => sys::SetpointCommandNormalized_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.select,
+                    io.qualifier.into(),
+                )
+                .cast(),
+                Io::C_SE_TA_1(io) => sys::SetpointCommandNormalizedWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.select,
+                    io.qualifier.into(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::C_SE_NB_1(io) => sys::SetpointCommandScaled_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.into(),
+                    io.select,
+                    io.qualifier.into(),
+                )
+                .cast(),
+                Io::C_SE_TB_1(io) => sys::SetpointCommandScaledWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.into(),
+                    io.select,
+                    io.qualifier.into(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::C_SE_NC_1(io) => sys::SetpointCommandShort_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.select,
+                    io.qualifier.into(),
+                )
+                .cast(),
+                Io::C_SE_TC_1(io) => sys::SetpointCommandShortWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.select,
+                    io.qualifier.into(),
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::C_BO_NA_1(io) => {
+                    sys::Bitstring32Command_create(ptr::null_mut(), io.ioa as i32, io.value).cast()
+                }
+                Io::C_BO_TA_1(io) => sys::Bitstring32CommandWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::C_IC_NA_1(io) => {
+                    sys::InterrogationCommand_create(ptr::null_mut(), io.ioa as i32, io.qualifier)
+                        .cast()
+                }
+                Io::C_CI_NA_1(io) => sys::CounterInterrogationCommand_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.qualifier,
+                )
+                .cast(),
+                Io::C_RD_NA_1(io) => sys::ReadCommand_create(ptr::null_mut(), io.ioa as i32).cast(),
+                Io::C_CS_NA_1(io) => sys::ClockSynchronizationCommand_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.time.as_ptr(),
+                )
+                .cast(),
+                Io::C_TS_NA_1(_) => sys::TestCommand_create(ptr::null_mut()).cast(),
+                Io::C_TS_TA_1(io) => sys::TestCommandWithCP56Time2a_create(
+                    ptr::null_mut(),
+                    io.counter,
+                    io.timestamp.as_ptr(),
+                )
+                .cast(),
+                Io::C_RP_NA_1(io) => {
+                    sys::ResetProcessCommand_create(ptr::null_mut(), io.ioa as i32, io.qualifier)
+                        .cast()
+                }
+                Io::C_CD_NA_1(io) => sys::DelayAcquisitionCommand_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.delay.as_ptr(),
+                )
+                .cast(),
+                Io::P_ME_NA_1(io) => sys::ParameterNormalizedValue_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.qualifier,
+                )
+                .cast(),
+                Io::P_ME_NB_1(io) => sys::ParameterScaledValue_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value.into(),
+                    io.qualifier,
+                )
+                .cast(),
+                Io::P_ME_NC_1(io) => sys::ParameterFloatValue_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.value,
+                    io.qualifier,
+                )
+                .cast(),
+                Io::P_AC_NA_1(io) => {
+                    sys::ParameterActivation_create(ptr::null_mut(), io.ioa as i32, io.qualifier)
+                        .cast()
+                }
+                Io::F_FR_NA_1(io) => sys::FileReady_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.name,
+                    io.length,
+                    io.positive,
+                )
+                .cast(),
+                Io::F_SR_NA_1(io) => sys::SectionReady_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.name,
+                    io.section,
+                    io.length,
+                    io.not_ready,
+                )
+                .cast(),
+                Io::F_SC_NA_1(io) => sys::FileCallOrSelect_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.name,
+                    io.section,
+                    io.qualifier,
+                )
+                .cast(),
+                Io::F_LS_NA_1(io) => sys::FileLastSegmentOrSection_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.name,
+                    io.section,
+                    io.qualifier,
+                    io.checksum,
+                )
+                .cast(),
+                Io::F_AF_NA_1(io) => sys::FileACK_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.name,
+                    io.section,
+                    io.qualifier,
+                )
+                .cast(),
+                Io::F_SG_NA_1(io) => {
+                    // the segment only references the data, which is copied
+                    // when the object is added to an ASDU
+                    let len = u8::try_from(io.data.len()).map_err(|_| Error::AsduFull)?;
+                    sys::FileSegment_create(
+                        ptr::null_mut(),
+                        io.ioa as i32,
+                        io.name,
+                        io.section,
+                        io.data.as_ptr() as *mut u8,
+                        len,
+                    )
+                    .cast()
+                }
+                Io::F_DR_TA_1(io) => sys::FileDirectory_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.name,
+                    io.length,
+                    io.status,
+                    io.creation_time.as_ptr(),
+                )
+                .cast(),
+                Io::F_SC_NB_1(io) => sys::QueryLog_create(
+                    ptr::null_mut(),
+                    io.ioa as i32,
+                    io.name,
+                    io.start.as_ptr(),
+                    io.stop.as_ptr(),
+                )
+                .cast(),
+            }
+        };
+        assert!(!io.is_null(), "failed to allocate information object");
+        Ok(io)
+    }
+}
+
+fn single_event(state: SingleEventState, quality: Quality) -> sys::tSingleEvent {
+    let mut event: sys::tSingleEvent = 0;
+    unsafe {
+        sys::SingleEvent_setEventState(&mut event, state.to_raw());
+        sys::SingleEvent_setQDP(&mut event, quality.bits());
+    }
+    event
+}
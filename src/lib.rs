@@ -1,19 +1,52 @@
-//! Raw FFI bindings to lib60870-C.
+//! Bindings to lib60870-C.
 //!
-//! This module contains the auto-generated bindgen bindings.
-//! For a safer API, use the types in the parent module.
+//! The crate root re-exports the auto-generated bindgen bindings. The
+//! [`asdu`], [`information_object`], [`parameters`], [`quality`],
+//! [`timestamp`] and [`types`] modules provide safe wrappers around the most
+//! common types, and [`cs101`] and [`cs104`] wrap the connections with Rust closures and
+//! traits in place of C callbacks. [`plugin`] runs custom ASDU processing in
+//! slaves, and [`file`](mod@file) adds file transfer on top of it. With the
+//! `rust-hal` feature, lib60870 runs on sockets, serial ports, threads and
+//! clocks implemented in Rust instead of its C platform layer, see `hal`.
+//!
+//! `quality::OutputCircuitInfo` is not re-exported at the crate root because
+//! the bindings already define an `OutputCircuitInfo` type alias.
+
+mod bindings {
+    #![allow(non_upper_case_globals)]
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+    #![allow(dead_code)]
+    #![allow(clippy::all)]
+    #![allow(unpredictable_function_pointer_comparisons)]
+
+    // build.rs writes either freshly generated bindings or the pre-generated ones
+    // for the target (docs.rs, `pregenerated` feature) to OUT_DIR
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+}
 
-#![allow(non_upper_case_globals)]
-#![allow(non_camel_case_types)]
-#![allow(non_snake_case)]
-#![allow(dead_code)]
-#![allow(clippy::all)]
-#![allow(unpredictable_function_pointer_comparisons)]
+pub use bindings::*;
 
-// On docs.rs, use pre-generated bindings (no network access to download C source)
-#[cfg(docsrs)]
-include!("bindings_pregenerated.rs");
+pub mod asdu;
+mod callback;
+pub mod cs101;
+pub mod cs104;
+pub mod error;
+pub mod file;
+#[cfg(feature = "rust-hal")]
+pub mod hal;
+pub mod information_object;
+pub mod parameters;
+pub mod plugin;
+pub mod quality;
+pub mod timestamp;
+#[cfg(feature = "tls")]
+pub mod tls;
+pub mod types;
 
-// For normal builds, use freshly generated bindings from build.rs
-#[cfg(not(docsrs))]
-include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+pub use asdu::{Asdu, AsduRef};
+pub use cs104::{Cs104Client, Cs104Server};
+pub use error::{Error, Result};
+pub use parameters::{ApciParameters, AppLayerParameters};
+pub use quality::{Quality, StartEvents};
+pub use types::{CauseOfTransmission, TypeId};
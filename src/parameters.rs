@@ -0,0 +1,208 @@
+//! Application layer, APCI and link layer parameters.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::bindings::{sCS101_AppLayerParameters, sCS104_APCIParameters, sLinkLayerParameters};
+
+/// Application layer parameters (sizes of the ASDU header fields).
+///
+/// The defaults are the ones mandated by IEC 60870-5-104. CS101 links
+/// commonly use smaller COT, CA and IOA fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AppLayerParameters {
+    /// Size of the type id in bytes (always 1).
+    pub size_of_type_id: u8,
+    /// Size of the variable structure qualifier in bytes (always 1).
+    pub size_of_vsq: u8,
+    /// Size of the cause of transmission in bytes (1, or 2 to include the OA).
+    pub size_of_cot: u8,
+    /// Originator address used for new ASDUs.
+    pub originator_address: u8,
+    /// Size of the common address in bytes (1 or 2).
+    pub size_of_ca: u8,
+    /// Size of the information object address in bytes (1, 2 or 3).
+    pub size_of_ioa: u8,
+    /// Maximum size of an encoded ASDU in bytes.
+    pub max_size_of_asdu: u8,
+}
+
+impl Default for AppLayerParameters {
+    fn default() -> Self {
+        Self {
+            size_of_type_id: 1,
+            size_of_vsq: 1,
+            size_of_cot: 2,
+            originator_address: 0,
+            size_of_ca: 2,
+            size_of_ioa: 3,
+            max_size_of_asdu: 249,
+        }
+    }
+}
+
+impl From<&sCS101_AppLayerParameters> for AppLayerParameters {
+    fn from(raw: &sCS101_AppLayerParameters) -> Self {
+        Self {
+            size_of_type_id: raw.sizeOfTypeId as u8,
+            size_of_vsq: raw.sizeOfVSQ as u8,
+            size_of_cot: raw.sizeOfCOT as u8,
+            originator_address: raw.originatorAddress as u8,
+            size_of_ca: raw.sizeOfCA as u8,
+            size_of_ioa: raw.sizeOfIOA as u8,
+            max_size_of_asdu: raw.maxSizeOfASDU as u8,
+        }
+    }
+}
+
+impl From<AppLayerParameters> for sCS101_AppLayerParameters {
+    fn from(params: AppLayerParameters) -> Self {
+        Self {
+            sizeOfTypeId: params.size_of_type_id.into(),
+            sizeOfVSQ: params.size_of_vsq.into(),
+            sizeOfCOT: params.size_of_cot.into(),
+            originatorAddress: params.originator_address.into(),
+            sizeOfCA: params.size_of_ca.into(),
+            sizeOfIOA: params.size_of_ioa.into(),
+            maxSizeOfASDU: params.max_size_of_asdu.into(),
+        }
+    }
+}
+
+// ASDUs keep a pointer to their parameters instead of a copy, so every
+// parameter set handed to C is leaked once and shared by all ASDUs using it.
+// Applications only ever use a handful of distinct sets.
+static INTERNED: Mutex<Vec<&'static sCS101_AppLayerParameters>> = Mutex::new(Vec::new());
+
+impl AppLayerParameters {
+    pub(crate) fn interned(&self) -> &'static sCS101_AppLayerParameters {
+        let raw = sCS101_AppLayerParameters::from(*self);
+        let mut interned = INTERNED.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(existing) = interned.iter().find(|p| ***p == raw) {
+            return existing;
+        }
+        let leaked: &'static sCS101_AppLayerParameters = Box::leak(Box::new(raw));
+        interned.push(leaked);
+        leaked
+    }
+}
+
+/// Parameters of the CS104 APCI (application protocol control information).
+///
+/// The defaults are the ones recommended by IEC 60870-5-104. Timeouts are
+/// transmitted to lib60870 in whole seconds, rounded up so that a
+/// sub-second timeout does not become zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApciParameters {
+    /// Maximum number of unconfirmed I-format APDUs sent (k).
+    pub k: u16,
+    /// Latest acknowledgement after receiving this many I-format APDUs (w).
+    pub w: u16,
+    /// Timeout of connection establishment (t0).
+    pub t0: Duration,
+    /// Timeout of send or test APDUs (t1).
+    pub t1: Duration,
+    /// Timeout for acknowledges in case of no data messages (t2 < t1).
+    pub t2: Duration,
+    /// Timeout for sending test frames in case of a long idle state (t3).
+    pub t3: Duration,
+}
+
+impl Default for ApciParameters {
+    fn default() -> Self {
+        Self {
+            k: 12,
+            w: 8,
+            t0: Duration::from_secs(10),
+            t1: Duration::from_secs(15),
+            t2: Duration::from_secs(10),
+            t3: Duration::from_secs(20),
+        }
+    }
+}
+
+impl From<&sCS104_APCIParameters> for ApciParameters {
+    fn from(raw: &sCS104_APCIParameters) -> Self {
+        let secs = |t: i32| Duration::from_secs(t.max(0) as u64);
+        Self {
+            k: raw.k as u16,
+            w: raw.w as u16,
+            t0: secs(raw.t0),
+            t1: secs(raw.t1),
+            t2: secs(raw.t2),
+            t3: secs(raw.t3),
+        }
+    }
+}
+
+impl From<ApciParameters> for sCS104_APCIParameters {
+    fn from(params: ApciParameters) -> Self {
+        let secs = |t: Duration| {
+            let secs = t.as_secs().saturating_add(u64::from(t.subsec_nanos() > 0));
+            secs.min(i32::MAX as u64) as i32
+        };
+        Self {
+            k: params.k.into(),
+            w: params.w.into(),
+            t0: secs(params.t0),
+            t1: secs(params.t1),
+            t2: secs(params.t2),
+            t3: secs(params.t3),
+        }
+    }
+}
+
+/// Parameters of the CS101 serial link layer.
+///
+/// The defaults are the ones used by lib60870.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LinkLayerParameters {
+    /// Size of the link address in bytes (0, 1 or 2).
+    pub address_length: u8,
+    /// Timeout for the acknowledgement of a message.
+    pub timeout_for_ack: Duration,
+    /// Timeout after which an unacknowledged message is repeated.
+    pub timeout_repeat: Duration,
+    /// Whether to acknowledge with the single character 0xE5.
+    pub use_single_char_ack: bool,
+    /// Interval of link state requests while the link is not available.
+    pub timeout_link_state: Duration,
+}
+
+impl Default for LinkLayerParameters {
+    fn default() -> Self {
+        Self {
+            address_length: 1,
+            timeout_for_ack: Duration::from_millis(200),
+            timeout_repeat: Duration::from_millis(1000),
+            use_single_char_ack: true,
+            timeout_link_state: Duration::from_millis(5000),
+        }
+    }
+}
+
+impl From<&sLinkLayerParameters> for LinkLayerParameters {
+    fn from(raw: &sLinkLayerParameters) -> Self {
+        let ms = |t: i32| Duration::from_millis(t.max(0) as u64);
+        Self {
+            address_length: raw.addressLength as u8,
+            timeout_for_ack: ms(raw.timeoutForAck),
+            timeout_repeat: ms(raw.timeoutRepeat),
+            use_single_char_ack: raw.useSingleCharACK,
+            timeout_link_state: ms(raw.timeoutLinkState),
+        }
+    }
+}
+
+impl From<LinkLayerParameters> for sLinkLayerParameters {
+    fn from(params: LinkLayerParameters) -> Self {
+        let ms = |t: Duration| t.as_millis().min(i32::MAX as u128) as i32;
+        Self {
+            addressLength: params.address_length.into(),
+            timeoutForAck: ms(params.timeout_for_ack),
+            timeoutRepeat: ms(params.timeout_repeat),
+            useSingleCharACK: params.use_single_char_ack,
+            timeoutLinkState: ms(params.timeout_link_state),
+        }
+    }
+}
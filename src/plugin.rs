@@ -0,0 +1,132 @@
+//! Slave plugins for custom ASDU processing.
+//!
+//! A [`SlavePlugin`] added to a [`Cs104Server`](crate::Cs104Server) or
+//! [`Cs101Slave`](crate::cs101::Cs101Slave) sees every received ASDU
+//! before the handler of the slave, and is called periodically to do work
+//! of its own, e.g. to send the next part of a long response.
+
+use std::os::raw::c_void;
+use std::sync::Mutex;
+
+use foreign_types::ForeignTypeRef;
+
+use crate::asdu::AsduRef;
+use crate::bindings::*;
+use crate::callback::guard;
+use crate::cs104::MasterConnection;
+
+/// What a [`SlavePlugin`] did with an ASDU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluginResult {
+    /// The ASDU is passed on to the next plugin and finally to the handler
+    /// of the slave.
+    NotHandled,
+    /// The plugin processed the ASDU.
+    Handled,
+    /// The ASDU is malformed and is dropped.
+    InvalidAsdu,
+}
+
+impl PluginResult {
+    fn to_raw(self) -> CS101_SlavePlugin_Result {
+        match self {
+            PluginResult::NotHandled => CS101_SlavePlugin_Result_CS101_PLUGIN_RESULT_NOT_HANDLED,
+            PluginResult::Handled => CS101_SlavePlugin_Result_CS101_PLUGIN_RESULT_HANDLED,
+            PluginResult::InvalidAsdu => CS101_SlavePlugin_Result_CS101_PLUGIN_RESULT_INVALID_ASDU,
+        }
+    }
+}
+
+/// A plugin of a CS101 slave or CS104 server (`CS101_SlavePlugin`).
+///
+/// Plugins are called in the order they were added. They run on the
+/// threads of lib60870; calls for different connections are serialized, so
+/// the methods take `&mut self`. A panic is caught at the FFI boundary and
+/// treated as [`PluginResult::NotHandled`].
+///
+/// ```no_run
+/// use lib60870_sys::cs104::MasterConnection;
+/// use lib60870_sys::plugin::{PluginResult, SlavePlugin};
+/// use lib60870_sys::{AsduRef, Cs104Server, TypeId};
+///
+/// /// Confirms every reset process command.
+/// struct ResetProcess;
+///
+/// impl SlavePlugin for ResetProcess {
+///     fn handle_asdu(&mut self, connection: &MasterConnection, asdu: &AsduRef) -> PluginResult {
+///         if asdu.type_id() != Some(TypeId::C_RP_NA_1) {
+///             return PluginResult::NotHandled;
+///         }
+///         let _ = connection.send_act_con(&mut asdu.to_owned(), false);
+///         PluginResult::Handled
+///     }
+/// }
+///
+/// let mut server = Cs104Server::builder().build(())?;
+/// server.add_plugin(ResetProcess)?;
+/// # Ok::<(), lib60870_sys::Error>(())
+/// ```
+#[allow(unused_variables)]
+pub trait SlavePlugin: Send + 'static {
+    /// A received ASDU, before it reaches the handler of the slave.
+    fn handle_asdu(&mut self, connection: &MasterConnection, asdu: &AsduRef) -> PluginResult;
+
+    /// Called periodically for every connection. Does nothing by default.
+    fn run_task(&mut self, connection: &MasterConnection) {}
+}
+
+/// A plugin added to a slave, kept alive by the slave until it has been
+/// destroyed.
+pub(crate) struct Plugin {
+    raw: Box<sCS101_SlavePlugin>,
+    _plugin: Box<dyn Send + Sync>,
+}
+
+impl Plugin {
+    /// Moves `plugin` to the heap and describes it to lib60870.
+    pub(crate) fn new<P: SlavePlugin>(plugin: P) -> Self {
+        let plugin = Box::new(Mutex::new(plugin));
+        let raw = Box::new(sCS101_SlavePlugin {
+            handleAsdu: Some(handle_asdu::<P>),
+            runTask: Some(run_task::<P>),
+            parameter: &*plugin as *const Mutex<P> as *mut c_void,
+        });
+        Plugin {
+            raw,
+            _plugin: plugin,
+        }
+    }
+
+    /// The `CS101_SlavePlugin` to add to the slave.
+    pub(crate) fn as_raw(&mut self) -> CS101_SlavePlugin {
+        &mut *self.raw
+    }
+}
+
+unsafe extern "C" fn handle_asdu<P: SlavePlugin>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+    asdu: CS101_ASDU,
+) -> CS101_SlavePlugin_Result {
+    let plugin = &*parameter.cast::<Mutex<P>>();
+    let connection = MasterConnection::from_raw(connection);
+    guard(PluginResult::NotHandled, || {
+        let mut plugin = plugin.lock().unwrap_or_else(|e| e.into_inner());
+        plugin.handle_asdu(&connection, AsduRef::from_ptr(asdu))
+    })
+    .to_raw()
+}
+
+unsafe extern "C" fn run_task<P: SlavePlugin>(
+    parameter: *mut c_void,
+    connection: IMasterConnection,
+) {
+    let plugin = &*parameter.cast::<Mutex<P>>();
+    let connection = MasterConnection::from_raw(connection);
+    guard((), || {
+        plugin
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .run_task(&connection)
+    });
+}
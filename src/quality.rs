@@ -0,0 +1,105 @@
+//! Quality descriptors and protection equipment flags.
+
+use std::fmt;
+
+use bitflags::bitflags;
+
+use crate::bindings::*;
+
+bitflags! {
+    /// Quality descriptor (QDS) of monitored information.
+    ///
+    /// Also used for the quality descriptor of protection equipment events
+    /// (QDP), which has no overflow bit but an elapsed time invalid bit.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Quality: u8 {
+        /// Overflow (OV).
+        const OVERFLOW = IEC60870_QUALITY_OVERFLOW as u8;
+        /// Elapsed time invalid (EI), protection events only.
+        const ELAPSED_TIME_INVALID = IEC60870_QUALITY_ELAPSED_TIME_INVALID as u8;
+        /// Blocked (BL).
+        const BLOCKED = IEC60870_QUALITY_BLOCKED as u8;
+        /// Substituted (SB).
+        const SUBSTITUTED = IEC60870_QUALITY_SUBSTITUTED as u8;
+        /// Not topical (NT).
+        const NON_TOPICAL = IEC60870_QUALITY_NON_TOPICAL as u8;
+        /// Invalid (IV).
+        const INVALID = IEC60870_QUALITY_INVALID as u8;
+    }
+}
+
+impl Quality {
+    /// No quality flag is set.
+    pub const GOOD: Quality = Quality::empty();
+
+    /// Whether none of the quality flags is set. Reserved bits are ignored.
+    pub fn is_good(self) -> bool {
+        self.intersection(Quality::all()).is_empty()
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_good() {
+            f.write_str("GOOD")
+        } else {
+            bitflags::parser::to_writer(&self.intersection(Quality::all()), f)
+        }
+    }
+}
+
+bitflags! {
+    /// Start events of protection equipment (SPE), used by `M_EP_TB_1` and
+    /// `M_EP_TE_1`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StartEvents: u8 {
+        /// General start of operation (GS).
+        const GENERAL = IEC60870_START_EVENT_GS as u8;
+        /// Start of operation phase L1 (SL1).
+        const L1 = IEC60870_START_EVENT_SL1 as u8;
+        /// Start of operation phase L2 (SL2).
+        const L2 = IEC60870_START_EVENT_SL2 as u8;
+        /// Start of operation phase L3 (SL3).
+        const L3 = IEC60870_START_EVENT_SL3 as u8;
+        /// Start of operation IE, earth current (SIE).
+        const EARTH_CURRENT = IEC60870_START_EVENT_SIE as u8;
+        /// Start of operation in reverse direction (SRD).
+        const REVERSE_DIRECTION = IEC60870_START_EVENT_SRD as u8;
+    }
+}
+
+impl fmt::Display for StartEvents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.intersection(StartEvents::all()).is_empty() {
+            f.write_str("NONE")
+        } else {
+            bitflags::parser::to_writer(&self.intersection(StartEvents::all()), f)
+        }
+    }
+}
+
+bitflags! {
+    /// Output circuit information of protection equipment (OCI), used by
+    /// `M_EP_TC_1` and `M_EP_TF_1`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct OutputCircuitInfo: u8 {
+        /// General command to output circuit (GC).
+        const GENERAL = IEC60870_OUTPUT_CI_GC as u8;
+        /// Command to output circuit phase L1 (CL1).
+        const L1 = IEC60870_OUTPUT_CI_CL1 as u8;
+        /// Command to output circuit phase L2 (CL2).
+        const L2 = IEC60870_OUTPUT_CI_CL2 as u8;
+        /// Command to output circuit phase L3 (CL3).
+        const L3 = IEC60870_OUTPUT_CI_CL3 as u8;
+    }
+}
+
+impl fmt::Display for OutputCircuitInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.intersection(OutputCircuitInfo::all()).is_empty() {
+            f.write_str("NONE")
+        } else {
+            bitflags::parser::to_writer(&self.intersection(OutputCircuitInfo::all()), f)
+        }
+    }
+}
@@ -0,0 +1,475 @@
+//! Binary time types (CP56Time2a, CP24Time2a, CP16Time2a).
+//!
+//! The calendar fields of a time tag carry neither a time zone nor a
+//! century. [`TimeConfig`] states how a device fills them in, and the
+//! conversions from and to [`SystemTime`] apply it explicitly:
+//!
+//! * two-digit years are mapped into the 100 year window starting at
+//!   [`TimeConfig::century_start`],
+//! * with [`TimeZone::Local`] the fields are local time and the summer time
+//!   (SU) bit adds one hour to the standard offset,
+//! * times with the invalid (IV) bit set are rejected; the substituted (SB)
+//!   bit is only reported.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::bindings::*;
+use crate::error::{Error, Result};
+
+const MS_PER_HOUR: i64 = 3_600_000;
+const MS_PER_DAY: i64 = 24 * MS_PER_HOUR;
+
+/// Seven octet binary time with date, used for full timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Cp56Time2a(pub(crate) sCP56Time2a);
+
+/// Three octet binary time (minutes, seconds and milliseconds).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Cp24Time2a(pub(crate) sCP24Time2a);
+
+/// Two octet binary time, used for elapsed and operating times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Cp16Time2a(pub(crate) sCP16Time2a);
+
+macro_rules! impl_encoded {
+    ($name:ident, $raw:ident, $len:literal) => {
+        impl $name {
+            /// Creates the time from its encoded octets.
+            pub fn from_bytes(bytes: [u8; $len]) -> Self {
+                Self($raw {
+                    encodedValue: bytes,
+                })
+            }
+
+            /// The encoded octets.
+            pub fn to_bytes(self) -> [u8; $len] {
+                self.0.encodedValue
+            }
+
+            /// Pointer for passing the time to lib60870, which never writes
+            /// through it.
+            pub(crate) fn as_ptr(&self) -> *mut $raw {
+                &self.0 as *const $raw as *mut $raw
+            }
+
+            /// Pointer for lib60870 functions that modify the time.
+            pub(crate) fn as_mut_ptr(&mut self) -> *mut $raw {
+                &mut self.0
+            }
+
+            /// Copies a time out of lib60870.
+            pub(crate) unsafe fn from_ptr(ptr: *const $raw) -> Self {
+                Self(*ptr)
+            }
+        }
+    };
+}
+
+impl_encoded!(Cp56Time2a, sCP56Time2a, 7);
+impl_encoded!(Cp24Time2a, sCP24Time2a, 3);
+impl_encoded!(Cp16Time2a, sCP16Time2a, 2);
+
+/// Time zone of the calendar fields of a time tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeZone {
+    /// The fields are UTC. The SU bit is ignored when decoding and never set
+    /// when encoding.
+    #[default]
+    Utc,
+    /// The fields are local time.
+    Local {
+        /// Offset of local standard time from UTC in seconds, positive east
+        /// of Greenwich.
+        standard_offset: i32,
+        /// Whether to encode times as summer time (standard offset plus one
+        /// hour, SU bit set). Decoding uses the SU bit of the time tag.
+        summer_time: bool,
+    },
+}
+
+/// How time tags are mapped to instants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeConfig {
+    /// Time zone of the calendar fields.
+    pub zone: TimeZone,
+    /// First year of the window two-digit years are mapped into. The default
+    /// of 2000 matches lib60870 (years 2000 to 2099).
+    pub century_start: i32,
+}
+
+impl Default for TimeConfig {
+    fn default() -> Self {
+        Self::UTC
+    }
+}
+
+impl TimeConfig {
+    /// UTC fields, years 2000 to 2099.
+    pub const UTC: TimeConfig = TimeConfig {
+        zone: TimeZone::Utc,
+        century_start: 2000,
+    };
+
+    /// Local time fields at the given standard offset from UTC (seconds east
+    /// of Greenwich), years 2000 to 2099.
+    pub fn local(standard_offset: i32, summer_time: bool) -> Self {
+        Self {
+            zone: TimeZone::Local {
+                standard_offset,
+                summer_time,
+            },
+            ..Self::UTC
+        }
+    }
+
+    /// Sets the first year of the century window.
+    pub fn with_century_start(self, century_start: i32) -> Self {
+        Self {
+            century_start,
+            ..self
+        }
+    }
+
+    /// Offset of the encoded fields from UTC in milliseconds.
+    fn encode_offset(&self) -> (i64, bool) {
+        match self.zone {
+            TimeZone::Utc => (0, false),
+            TimeZone::Local {
+                standard_offset,
+                summer_time,
+            } => (
+                i64::from(standard_offset) * 1000 + if summer_time { MS_PER_HOUR } else { 0 },
+                summer_time,
+            ),
+        }
+    }
+
+    /// Offset of decoded fields from UTC in milliseconds.
+    fn decode_offset(&self, summer_time: bool) -> i64 {
+        match self.zone {
+            TimeZone::Utc => 0,
+            TimeZone::Local {
+                standard_offset, ..
+            } => i64::from(standard_offset) * 1000 + if summer_time { MS_PER_HOUR } else { 0 },
+        }
+    }
+
+    fn full_year(&self, two_digit_year: i32) -> i32 {
+        self.century_start + (two_digit_year - self.century_start).rem_euclid(100)
+    }
+}
+
+impl Cp56Time2a {
+    /// Encodes an instant.
+    ///
+    /// Fails with [`Error::TimestampOutOfRange`] if the local year is outside
+    /// the century window of `config`.
+    pub fn from_system_time(time: SystemTime, config: &TimeConfig) -> Result<Self> {
+        let (offset, summer_time) = config.encode_offset();
+        let local = to_unix_ms(time) + offset;
+        let days = local.div_euclid(MS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        if !(config.century_start..config.century_start + 100).contains(&year) {
+            return Err(Error::TimestampOutOfRange);
+        }
+
+        // lib60870 encodes years 2000 to 2099, so encode the same date in
+        // that century and fix up the day of week afterwards
+        let same_date_2000 = days_from_civil(2000 + year.rem_euclid(100), month, day);
+        let shifted = local + (same_date_2000 - days) * MS_PER_DAY;
+
+        let mut time = Cp56Time2a::default();
+        unsafe {
+            CP56Time2a_createFromMsTimestamp(time.as_mut_ptr(), shifted as u64);
+            CP56Time2a_setDayOfWeek(time.as_mut_ptr(), weekday(days));
+            CP56Time2a_setSummerTime(time.as_mut_ptr(), summer_time);
+        }
+        Ok(time)
+    }
+
+    /// Decodes the time tag.
+    ///
+    /// Fails with [`Error::InvalidTimestamp`] if the IV bit is set or the
+    /// fields do not form a valid date and time.
+    pub fn to_system_time(&self, config: &TimeConfig) -> Result<SystemTime> {
+        if self.is_invalid() {
+            return Err(Error::InvalidTimestamp);
+        }
+
+        let (two_digit_year, month, day, hour, minute, second) = unsafe {
+            (
+                CP56Time2a_getYear(self.as_ptr()),
+                CP56Time2a_getMonth(self.as_ptr()),
+                CP56Time2a_getDayOfMonth(self.as_ptr()),
+                CP56Time2a_getHour(self.as_ptr()),
+                CP56Time2a_getMinute(self.as_ptr()),
+                CP56Time2a_getSecond(self.as_ptr()),
+            )
+        };
+        let year = config.full_year(two_digit_year);
+        if !(1..=12).contains(&month)
+            || !(1..=days_in_month(year, month)).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return Err(Error::InvalidTimestamp);
+        }
+
+        // lib60870 decodes the fields as a date in 2000 to 2099, move it to
+        // the configured century
+        let local = unsafe { CP56Time2a_toMsTimestamp(self.as_ptr()) } as i64
+            + (days_from_civil(year, month, day)
+                - days_from_civil(2000 + two_digit_year, month, day))
+                * MS_PER_DAY;
+        Ok(from_unix_ms(
+            local - config.decode_offset(self.is_summer_time()),
+        ))
+    }
+
+    /// Whether the invalid (IV) bit is set.
+    pub fn is_invalid(&self) -> bool {
+        unsafe { CP56Time2a_isInvalid(self.as_ptr()) }
+    }
+
+    /// Sets the invalid (IV) bit.
+    pub fn set_invalid(&mut self, value: bool) {
+        unsafe { CP56Time2a_setInvalid(self.as_mut_ptr(), value) }
+    }
+
+    /// Whether the substituted (SB) bit is set.
+    pub fn is_substituted(&self) -> bool {
+        unsafe { CP56Time2a_isSubstituted(self.as_ptr()) }
+    }
+
+    /// Sets the substituted (SB) bit.
+    pub fn set_substituted(&mut self, value: bool) {
+        unsafe { CP56Time2a_setSubstituted(self.as_mut_ptr(), value) }
+    }
+
+    /// Whether the summer time (SU) bit is set.
+    pub fn is_summer_time(&self) -> bool {
+        unsafe { CP56Time2a_isSummerTime(self.as_ptr()) }
+    }
+
+    /// Day of week, 1 (Monday) to 7 (Sunday), or 0 if not used.
+    pub fn day_of_week(&self) -> u8 {
+        unsafe { CP56Time2a_getDayOfWeek(self.as_ptr()) as u8 }
+    }
+}
+
+impl TryFrom<SystemTime> for Cp56Time2a {
+    type Error = Error;
+
+    /// Encodes the instant as UTC.
+    fn try_from(time: SystemTime) -> Result<Self> {
+        Cp56Time2a::from_system_time(time, &TimeConfig::UTC)
+    }
+}
+
+impl TryFrom<Cp56Time2a> for SystemTime {
+    type Error = Error;
+
+    /// Decodes the time tag as UTC.
+    fn try_from(time: Cp56Time2a) -> Result<Self> {
+        time.to_system_time(&TimeConfig::UTC)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Cp56Time2a {
+    type Error = Error;
+
+    /// Encodes the instant as UTC.
+    fn try_from(time: chrono::DateTime<chrono::Utc>) -> Result<Self> {
+        Cp56Time2a::try_from(SystemTime::from(time))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Cp56Time2a> for chrono::DateTime<chrono::Utc> {
+    type Error = Error;
+
+    /// Decodes the time tag as UTC.
+    fn try_from(time: Cp56Time2a) -> Result<Self> {
+        SystemTime::try_from(time).map(Into::into)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<::time::OffsetDateTime> for Cp56Time2a {
+    type Error = Error;
+
+    /// Encodes the instant as UTC.
+    fn try_from(time: ::time::OffsetDateTime) -> Result<Self> {
+        Cp56Time2a::try_from(SystemTime::from(time))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<Cp56Time2a> for ::time::OffsetDateTime {
+    type Error = Error;
+
+    /// Decodes the time tag as UTC.
+    fn try_from(time: Cp56Time2a) -> Result<Self> {
+        SystemTime::try_from(time).map(Into::into)
+    }
+}
+
+impl Cp24Time2a {
+    /// Encodes the minute, second and millisecond of an instant in the time
+    /// zone of `config`.
+    pub fn from_system_time(time: SystemTime, config: &TimeConfig) -> Self {
+        let (offset, _) = config.encode_offset();
+        let in_hour = (to_unix_ms(time) + offset).rem_euclid(MS_PER_HOUR) as i32;
+
+        let mut time = Cp24Time2a::default();
+        unsafe {
+            CP24Time2a_setMinute(time.as_mut_ptr(), in_hour / 60_000);
+            CP24Time2a_setSecond(time.as_mut_ptr(), in_hour / 1000 % 60);
+            CP24Time2a_setMillisecond(time.as_mut_ptr(), in_hour % 1000);
+        }
+        time
+    }
+
+    /// Decodes the time tag as the instant nearest to `reference`, which
+    /// supplies the date and hour.
+    ///
+    /// Fails with [`Error::InvalidTimestamp`] if the IV bit is set or the
+    /// fields are out of range.
+    pub fn to_system_time(&self, reference: SystemTime, config: &TimeConfig) -> Result<SystemTime> {
+        if self.is_invalid() {
+            return Err(Error::InvalidTimestamp);
+        }
+        let (minute, second, millisecond) = unsafe {
+            (
+                CP24Time2a_getMinute(self.as_ptr()),
+                CP24Time2a_getSecond(self.as_ptr()),
+                CP24Time2a_getMillisecond(self.as_ptr()),
+            )
+        };
+        if minute > 59 || second > 59 {
+            return Err(Error::InvalidTimestamp);
+        }
+        let in_hour =
+            i64::from(minute) * 60_000 + i64::from(second) * 1000 + i64::from(millisecond);
+
+        let (offset, _) = config.encode_offset();
+        let reference = to_unix_ms(reference) + offset;
+        let candidate = reference - reference.rem_euclid(MS_PER_HOUR) + in_hour;
+        let nearest = [candidate - MS_PER_HOUR, candidate, candidate + MS_PER_HOUR]
+            .into_iter()
+            .min_by_key(|t| (t - reference).abs())
+            .unwrap_or(candidate);
+        Ok(from_unix_ms(nearest - offset))
+    }
+
+    /// Whether the invalid (IV) bit is set.
+    pub fn is_invalid(&self) -> bool {
+        unsafe { CP24Time2a_isInvalid(self.as_ptr()) }
+    }
+
+    /// Sets the invalid (IV) bit.
+    pub fn set_invalid(&mut self, value: bool) {
+        unsafe { CP24Time2a_setInvalid(self.as_mut_ptr(), value) }
+    }
+
+    /// Whether the substituted (SB) bit is set.
+    pub fn is_substituted(&self) -> bool {
+        unsafe { CP24Time2a_isSubstituted(self.as_ptr()) }
+    }
+
+    /// Sets the substituted (SB) bit.
+    pub fn set_substituted(&mut self, value: bool) {
+        unsafe { CP24Time2a_setSubstituted(self.as_mut_ptr(), value) }
+    }
+}
+
+impl From<Cp16Time2a> for Duration {
+    fn from(time: Cp16Time2a) -> Self {
+        let ms = unsafe { CP16Time2a_getEplapsedTimeInMs(time.as_ptr()) };
+        Duration::from_millis(ms as u64)
+    }
+}
+
+impl TryFrom<Duration> for Cp16Time2a {
+    type Error = Error;
+
+    /// Fails with [`Error::TimestampOutOfRange`] for durations of a minute
+    /// or more.
+    fn try_from(duration: Duration) -> Result<Self> {
+        let ms = duration.as_millis();
+        if ms >= 60_000 {
+            return Err(Error::TimestampOutOfRange);
+        }
+        let mut time = Cp16Time2a::default();
+        unsafe { CP16Time2a_setEplapsedTimeInMs(time.as_mut_ptr(), ms as i32) };
+        Ok(time)
+    }
+}
+
+/// Milliseconds since the Unix epoch, rounded down.
+fn to_unix_ms(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => after.as_millis() as i64,
+        Err(e) => {
+            let before = e.duration();
+            let ms = before.as_millis() as i64;
+            if before.subsec_nanos() % 1_000_000 == 0 {
+                -ms
+            } else {
+                -ms - 1
+            }
+        }
+    }
+}
+
+fn from_unix_ms(ms: i64) -> SystemTime {
+    if ms >= 0 {
+        UNIX_EPOCH + Duration::from_millis(ms as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_millis(ms.unsigned_abs())
+    }
+}
+
+// Conversions between days since the Unix epoch and proleptic Gregorian
+// dates, after Howard Hinnant's chrono-compatible low-level date algorithms.
+
+fn days_from_civil(year: i32, month: i32, day: i32) -> i64 {
+    let year = i64::from(if month <= 2 { year - 1 } else { year });
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i32, i32, i32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month as i32, day as i32)
+}
+
+fn days_in_month(year: i32, month: i32) -> i32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// ISO day of week, 1 (Monday) to 7 (Sunday). 1970-01-01 was a Thursday.
+fn weekday(days: i64) -> i32 {
+    ((days + 3).rem_euclid(7) + 1) as i32
+}
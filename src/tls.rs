@@ -0,0 +1,732 @@
+//! TLS configuration for secure CS104 connections (IEC 62351-3).
+//!
+//! Requires the `tls` feature. A [`TlsConfig`] is built once and handed to
+//! [`Cs104ClientBuilder::tls`](crate::cs104::Cs104ClientBuilder::tls) or
+//! [`Cs104ServerBuilder::tls`](crate::cs104::Cs104ServerBuilder::tls).
+//!
+//! ```no_run
+//! use lib60870_sys::tls::{TlsConfig, TlsVersion};
+//! use lib60870_sys::Cs104Client;
+//!
+//! let config = TlsConfig::builder()
+//!     .own_certificate_file("client.pem")
+//!     .own_key_file("client.key", None)
+//!     .ca_certificate_file("root.pem")
+//!     .min_version(TlsVersion::Tls1_2)
+//!     .build()?;
+//! let client = Cs104Client::builder("127.0.0.1").tls(config).build()?;
+//! # Ok::<(), lib60870_sys::Error>(())
+//! ```
+
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::{mpsc, Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use crate::bindings::*;
+use crate::callback::guard;
+use crate::error::{Error, Result};
+
+type EventHandler = Box<dyn FnMut(TlsEvent) + Send>;
+
+/// TLS protocol version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TlsVersion {
+    /// SSL 3.0, insecure and only supported for legacy peers.
+    Ssl3_0,
+    /// TLS 1.0
+    Tls1_0,
+    /// TLS 1.1
+    Tls1_1,
+    /// TLS 1.2
+    Tls1_2,
+    /// TLS 1.3
+    Tls1_3,
+}
+
+impl TlsVersion {
+    pub(crate) fn to_raw(self) -> TLSConfigVersion {
+        match self {
+            TlsVersion::Ssl3_0 => TLSConfigVersion_TLS_VERSION_SSL_3_0,
+            TlsVersion::Tls1_0 => TLSConfigVersion_TLS_VERSION_TLS_1_0,
+            TlsVersion::Tls1_1 => TLSConfigVersion_TLS_VERSION_TLS_1_1,
+            TlsVersion::Tls1_2 => TLSConfigVersion_TLS_VERSION_TLS_1_2,
+            TlsVersion::Tls1_3 => TLSConfigVersion_TLS_VERSION_TLS_1_3,
+        }
+    }
+
+    #[allow(non_upper_case_globals)]
+    pub(crate) fn from_raw(version: TLSConfigVersion) -> Option<Self> {
+        match version {
+            TLSConfigVersion_TLS_VERSION_SSL_3_0 => Some(TlsVersion::Ssl3_0),
+            TLSConfigVersion_TLS_VERSION_TLS_1_0 => Some(TlsVersion::Tls1_0),
+            TLSConfigVersion_TLS_VERSION_TLS_1_1 => Some(TlsVersion::Tls1_1),
+            TLSConfigVersion_TLS_VERSION_TLS_1_2 => Some(TlsVersion::Tls1_2),
+            TLSConfigVersion_TLS_VERSION_TLS_1_3 => Some(TlsVersion::Tls1_3),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TlsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TlsVersion::Ssl3_0 => "SSL 3.0",
+            TlsVersion::Tls1_0 => "TLS 1.0",
+            TlsVersion::Tls1_1 => "TLS 1.1",
+            TlsVersion::Tls1_2 => "TLS 1.2",
+            TlsVersion::Tls1_3 => "TLS 1.3",
+        })
+    }
+}
+
+/// Severity of a [`TlsEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TlsEventLevel {
+    /// Normal operation, e.g. a session was established.
+    Info,
+    /// The session continues but may be insecure.
+    Warning,
+    /// A security incident; the session has been rejected or closed.
+    Incident,
+}
+
+impl TlsEventLevel {
+    #[allow(non_upper_case_globals)]
+    fn from_raw(level: TLSEventLevel) -> Self {
+        match level {
+            TLSEventLevel_TLS_SEC_EVT_INFO => TlsEventLevel::Info,
+            TLSEventLevel_TLS_SEC_EVT_WARNING => TlsEventLevel::Warning,
+            _ => TlsEventLevel::Incident,
+        }
+    }
+}
+
+/// What a [`TlsEvent`] reports, following the event codes of IEC 62351-3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TlsEventCode {
+    /// The peer does not support the required algorithms.
+    AlgorithmNotSupported,
+    /// The peer attempted unsecured communication.
+    UnsecureCommunication,
+    /// The peer did not provide a certificate.
+    CertificateUnavailable,
+    /// The peer certificate is malformed.
+    BadCertificate,
+    /// The peer certificate is too large.
+    CertificateSizeExceeded,
+    /// The peer certificate could not be validated.
+    CertificateValidationFailed,
+    /// A certificate is required but none was configured or received.
+    CertificateRequired,
+    /// The handshake failed for another reason.
+    HandshakeFailed,
+    /// The negotiated TLS version is insecure.
+    InsecureTlsVersion,
+    /// The session was renegotiated.
+    SessionRenegotiation,
+    /// The peer certificate has expired and was rejected.
+    CertificateExpired,
+    /// The peer certificate has been revoked.
+    CertificateRevoked,
+    /// The peer certificate is not on the allowlist.
+    CertificateNotConfigured,
+    /// The peer certificate is not signed by a trusted CA.
+    CertificateNotTrusted,
+    /// No common cipher suite.
+    NoCipher,
+    /// A session was established.
+    SessionEstablished,
+    /// The peer certificate has expired but was accepted because time
+    /// validation is disabled.
+    CertificateExpiredAccepted,
+    /// The peer certificate is not yet valid.
+    CertificateNotYetValid,
+    /// A certificate revocation list has expired.
+    CrlExpired,
+    /// A certificate revocation list is not yet valid.
+    CrlNotYetValid,
+    /// A code not known to this crate.
+    Unknown(i32),
+}
+
+impl TlsEventCode {
+    fn from_raw(code: c_int) -> Self {
+        match code as u32 {
+            TLS_EVENT_CODE_ALM_ALGO_NOT_SUPPORTED => TlsEventCode::AlgorithmNotSupported,
+            TLS_EVENT_CODE_ALM_UNSECURE_COMMUNICATION => TlsEventCode::UnsecureCommunication,
+            TLS_EVENT_CODE_ALM_CERT_UNAVAILABLE => TlsEventCode::CertificateUnavailable,
+            TLS_EVENT_CODE_ALM_BAD_CERT => TlsEventCode::BadCertificate,
+            TLS_EVENT_CODE_ALM_CERT_SIZE_EXCEEDED => TlsEventCode::CertificateSizeExceeded,
+            TLS_EVENT_CODE_ALM_CERT_VALIDATION_FAILED => TlsEventCode::CertificateValidationFailed,
+            TLS_EVENT_CODE_ALM_CERT_REQUIRED => TlsEventCode::CertificateRequired,
+            TLS_EVENT_CODE_ALM_HANDSHAKE_FAILED_UNKNOWN_REASON => TlsEventCode::HandshakeFailed,
+            TLS_EVENT_CODE_WRN_INSECURE_TLS_VERSION => TlsEventCode::InsecureTlsVersion,
+            TLS_EVENT_CODE_INF_SESSION_RENEGOTIATION => TlsEventCode::SessionRenegotiation,
+            TLS_EVENT_CODE_ALM_CERT_EXPIRED => TlsEventCode::CertificateExpired,
+            TLS_EVENT_CODE_ALM_CERT_REVOKED => TlsEventCode::CertificateRevoked,
+            TLS_EVENT_CODE_ALM_CERT_NOT_CONFIGURED => TlsEventCode::CertificateNotConfigured,
+            TLS_EVENT_CODE_ALM_CERT_NOT_TRUSTED => TlsEventCode::CertificateNotTrusted,
+            TLS_EVENT_CODE_ALM_NO_CIPHER => TlsEventCode::NoCipher,
+            TLS_EVENT_CODE_INF_SESSION_ESTABLISHED => TlsEventCode::SessionEstablished,
+            TLS_EVENT_CODE_WRN_CERT_EXPIRED => TlsEventCode::CertificateExpiredAccepted,
+            TLS_EVENT_CODE_WRN_CERT_NOT_YET_VALID => TlsEventCode::CertificateNotYetValid,
+            TLS_EVENT_CODE_WRN_CRL_EXPIRED => TlsEventCode::CrlExpired,
+            TLS_EVENT_CODE_WRN_CRL_NOT_YET_VALID => TlsEventCode::CrlNotYetValid,
+            _ => TlsEventCode::Unknown(code),
+        }
+    }
+}
+
+/// A security event reported by the TLS layer, for audit logging.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TlsEvent {
+    /// Severity of the event.
+    pub level: TlsEventLevel,
+    /// What happened.
+    pub code: TlsEventCode,
+    /// Human readable description from lib60870.
+    pub message: String,
+    /// Address and port of the peer, if the event belongs to a connection.
+    pub peer_address: Option<String>,
+    /// Certificate of the peer (DER), if it has sent one.
+    pub peer_certificate: Option<Vec<u8>>,
+    /// The negotiated protocol version, if the handshake got that far.
+    pub version: Option<TlsVersion>,
+}
+
+impl TlsEvent {
+    unsafe fn from_raw(
+        level: TLSEventLevel,
+        code: c_int,
+        message: *const c_char,
+        connection: TLSConnection,
+    ) -> Self {
+        let message = if message.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(message).to_string_lossy().into_owned()
+        };
+        let mut event = TlsEvent {
+            level: TlsEventLevel::from_raw(level),
+            code: TlsEventCode::from_raw(code),
+            message,
+            peer_address: None,
+            peer_certificate: None,
+            version: None,
+        };
+        if !connection.is_null() {
+            let mut buf = [0 as c_char; 60];
+            let address = TLSConnection_getPeerAddress(connection, buf.as_mut_ptr());
+            if !address.is_null() {
+                event.peer_address = Some(CStr::from_ptr(address).to_string_lossy().into_owned());
+            }
+
+            let mut size: c_int = 0;
+            let certificate = TLSConnection_getPeerCertificate(connection, &mut size);
+            if !certificate.is_null() && size > 0 {
+                event.peer_certificate =
+                    Some(std::slice::from_raw_parts(certificate, size as usize).to_vec());
+            }
+
+            event.version = TlsVersion::from_raw(TLSConnection_getTLSVersion(connection));
+        }
+        event
+    }
+}
+
+impl fmt::Display for TlsEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} {:?}", self.level, self.code)?;
+        if let Some(peer) = &self.peer_address {
+            write!(f, " from {peer}")?;
+        }
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// The step of building a [`TlsConfig`] that failed.
+///
+/// lib60870 does not report why a certificate, key or CRL was rejected;
+/// usually the file is missing, the format is unknown or the key password
+/// is wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TlsError {
+    /// The own certificate could not be loaded.
+    OwnCertificate,
+    /// The own private key could not be loaded.
+    OwnKey,
+    /// A CA certificate could not be loaded.
+    CaCertificate,
+    /// An allowed peer certificate could not be loaded.
+    AllowedCertificate,
+    /// A certificate revocation list could not be loaded.
+    Crl,
+    /// A path is not valid UTF-8 or contains a NUL byte.
+    InvalidPath,
+}
+
+impl fmt::Display for TlsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TlsError::OwnCertificate => "failed to load own certificate",
+            TlsError::OwnKey => "failed to load own private key",
+            TlsError::CaCertificate => "failed to load CA certificate",
+            TlsError::AllowedCertificate => "failed to load allowed certificate",
+            TlsError::Crl => "failed to load certificate revocation list",
+            TlsError::InvalidPath => "invalid path",
+        })
+    }
+}
+
+/// A certificate, key or CRL, either in memory or in a file.
+enum Source {
+    Bytes(Vec<u8>),
+    File(PathBuf),
+}
+
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Bytes(data) => write!(f, "<{} bytes>", data.len()),
+            Source::File(path) => path.fmt(f),
+        }
+    }
+}
+
+impl Source {
+    fn bytes(data: &[u8]) -> Self {
+        let mut data = data.to_vec();
+        // mbedtls only parses PEM if the terminating NUL is part of the buffer
+        if data.windows(10).any(|w| w == b"-----BEGIN") && data.last() != Some(&0) {
+            data.push(0);
+        }
+        Source::Bytes(data)
+    }
+
+    fn file(path: impl AsRef<Path>) -> Self {
+        Source::File(path.as_ref().to_owned())
+    }
+
+    /// Passes the data to the matching lib60870 function, mapping a
+    /// rejection to `error`.
+    unsafe fn load(
+        &self,
+        raw: TLSConfiguration,
+        error: TlsError,
+        from_bytes: unsafe extern "C" fn(TLSConfiguration, *mut u8, c_int) -> bool,
+        from_file: unsafe extern "C" fn(TLSConfiguration, *const c_char) -> bool,
+    ) -> Result<()> {
+        let ok = match self {
+            Source::Bytes(data) => {
+                let len = c_int::try_from(data.len()).map_err(|_| Error::Tls(error))?;
+                from_bytes(raw, data.as_ptr() as *mut u8, len)
+            }
+            Source::File(path) => from_file(raw, path_to_cstring(path)?.as_ptr()),
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(Error::Tls(error))
+        }
+    }
+}
+
+fn path_to_cstring(path: &Path) -> Result<CString> {
+    path.to_str()
+        .and_then(|path| CString::new(path).ok())
+        .ok_or(Error::Tls(TlsError::InvalidPath))
+}
+
+fn millis(duration: Duration) -> c_int {
+    duration.as_millis().min(c_int::MAX as u128) as c_int
+}
+
+/// Builder for a [`TlsConfig`].
+///
+/// Created by [`TlsConfig::builder`]. Certificates, keys and CRLs are given
+/// as PEM or DER, in memory or as file paths, and only loaded by
+/// [`build`](Self::build).
+#[derive(Default)]
+pub struct TlsConfigBuilder {
+    own_certificate: Option<Source>,
+    own_key: Option<(Source, Option<String>)>,
+    ca_certificates: Vec<Source>,
+    allowed_certificates: Vec<Source>,
+    crls: Vec<Source>,
+    allow_only_known_certificates: Option<bool>,
+    chain_validation: Option<bool>,
+    time_validation: Option<bool>,
+    min_version: Option<TlsVersion>,
+    max_version: Option<TlsVersion>,
+    cipher_suites: Vec<u16>,
+    session_resumption: Option<bool>,
+    session_resumption_interval: Option<Duration>,
+    renegotiation_time: Option<Duration>,
+    on_event: Option<EventHandler>,
+}
+
+impl TlsConfigBuilder {
+    /// Sets the own certificate (identity).
+    pub fn own_certificate(mut self, certificate: &[u8]) -> Self {
+        self.own_certificate = Some(Source::bytes(certificate));
+        self
+    }
+
+    /// Sets the own certificate (identity) from a file.
+    pub fn own_certificate_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.own_certificate = Some(Source::file(path));
+        self
+    }
+
+    /// Sets the own private key, with the password if it is encrypted.
+    pub fn own_key(mut self, key: &[u8], password: Option<&str>) -> Self {
+        self.own_key = Some((Source::bytes(key), password.map(str::to_owned)));
+        self
+    }
+
+    /// Sets the own private key from a file, with the password if it is
+    /// encrypted.
+    pub fn own_key_file(mut self, path: impl AsRef<Path>, password: Option<&str>) -> Self {
+        self.own_key = Some((Source::file(path), password.map(str::to_owned)));
+        self
+    }
+
+    /// Adds a CA certificate used to validate peer certificates.
+    pub fn ca_certificate(mut self, certificate: &[u8]) -> Self {
+        self.ca_certificates.push(Source::bytes(certificate));
+        self
+    }
+
+    /// Adds a CA certificate used to validate peer certificates from a file.
+    pub fn ca_certificate_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.ca_certificates.push(Source::file(path));
+        self
+    }
+
+    /// Adds a peer certificate to the allowlist.
+    ///
+    /// The allowlist is only enforced with
+    /// [`allow_only_known_certificates`](Self::allow_only_known_certificates).
+    pub fn allowed_certificate(mut self, certificate: &[u8]) -> Self {
+        self.allowed_certificates.push(Source::bytes(certificate));
+        self
+    }
+
+    /// Adds a peer certificate to the allowlist from a file.
+    pub fn allowed_certificate_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.allowed_certificates.push(Source::file(path));
+        self
+    }
+
+    /// Adds a certificate revocation list.
+    pub fn crl(mut self, crl: &[u8]) -> Self {
+        self.crls.push(Source::bytes(crl));
+        self
+    }
+
+    /// Adds a certificate revocation list from a file.
+    pub fn crl_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.crls.push(Source::file(path));
+        self
+    }
+
+    /// Only accepts peers whose certificate is on the allowlist, even if it
+    /// is signed by a trusted CA. Disabled by default.
+    pub fn allow_only_known_certificates(mut self, value: bool) -> Self {
+        self.allow_only_known_certificates = Some(value);
+        self
+    }
+
+    /// Enables validation of the certificate trust chain. Enabled by
+    /// default.
+    pub fn chain_validation(mut self, value: bool) -> Self {
+        self.chain_validation = Some(value);
+        self
+    }
+
+    /// Enables validation of the validity times of certificates and CRLs.
+    /// Enabled by default.
+    pub fn time_validation(mut self, value: bool) -> Self {
+        self.time_validation = Some(value);
+        self
+    }
+
+    /// Sets the lowest TLS version to accept.
+    pub fn min_version(mut self, version: TlsVersion) -> Self {
+        self.min_version = Some(version);
+        self
+    }
+
+    /// Sets the highest TLS version to accept.
+    pub fn max_version(mut self, version: TlsVersion) -> Self {
+        self.max_version = Some(version);
+        self
+    }
+
+    /// Adds a cipher suite by its IANA identifier, e.g. `0xC02F` for
+    /// `TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256`. Without any, the mbedtls
+    /// defaults are used.
+    pub fn cipher_suite(mut self, id: u16) -> Self {
+        self.cipher_suites.push(id);
+        self
+    }
+
+    /// Enables TLS session resumption. Enabled by default.
+    pub fn session_resumption(mut self, enable: bool) -> Self {
+        self.session_resumption = Some(enable);
+        self
+    }
+
+    /// Sets how long a cached session can be resumed (whole seconds).
+    pub fn session_resumption_interval(mut self, interval: Duration) -> Self {
+        self.session_resumption_interval = Some(interval);
+        self
+    }
+
+    /// Sets the time after which the session has to be renegotiated.
+    pub fn renegotiation_time(mut self, time: Duration) -> Self {
+        self.renegotiation_time = Some(time);
+        self
+    }
+
+    /// Sets the handler for security events of all connections using the
+    /// configuration.
+    ///
+    /// The handler runs on the connection threads of lib60870, one call at a
+    /// time, and must not block for long.
+    pub fn on_event(mut self, handler: impl FnMut(TlsEvent) + Send + 'static) -> Self {
+        self.on_event = Some(Box::new(handler));
+        self
+    }
+
+    /// Sends security events to a channel, e.g. to forward them to a log
+    /// collector from another thread. Events are dropped once the receiver
+    /// is gone.
+    pub fn event_sender(self, sender: mpsc::Sender<TlsEvent>) -> Self {
+        self.on_event(move |event| {
+            let _ = sender.send(event);
+        })
+    }
+
+    /// Creates the configuration, loading all certificates, keys and CRLs.
+    ///
+    /// Fails with [`Error::Tls`] naming the first item that could not be
+    /// loaded.
+    pub fn build(mut self) -> Result<TlsConfig> {
+        let raw = unsafe { TLSConfiguration_create() };
+        assert!(!raw.is_null(), "TLSConfiguration_create failed");
+        // destroys the configuration if loading fails
+        let config = RawConfig {
+            raw,
+            on_event: self
+                .on_event
+                .take()
+                .map(|handler| Box::new(Mutex::new(handler))),
+            role: OnceLock::new(),
+        };
+        unsafe {
+            if let Some(handler) = &config.on_event {
+                let parameter = &**handler as *const Mutex<EventHandler> as *mut c_void;
+                TLSConfiguration_setEventHandler(raw, Some(security_event), parameter);
+            }
+            self.apply(raw)?;
+        }
+        Ok(TlsConfig {
+            inner: Arc::new(config),
+        })
+    }
+
+    unsafe fn apply(self, raw: TLSConfiguration) -> Result<()> {
+        if let Some(certificate) = &self.own_certificate {
+            certificate.load(
+                raw,
+                TlsError::OwnCertificate,
+                TLSConfiguration_setOwnCertificate,
+                TLSConfiguration_setOwnCertificateFromFile,
+            )?;
+        }
+        if let Some((key, password)) = &self.own_key {
+            let password = password
+                .as_deref()
+                .map(CString::new)
+                .transpose()
+                .map_err(|_| Error::NulByte)?;
+            let password = password.as_ref().map_or(ptr::null(), |p| p.as_ptr());
+            let ok = match key {
+                Source::Bytes(data) => {
+                    let len =
+                        c_int::try_from(data.len()).map_err(|_| Error::Tls(TlsError::OwnKey))?;
+                    TLSConfiguration_setOwnKey(raw, data.as_ptr() as *mut u8, len, password)
+                }
+                Source::File(path) => {
+                    let path = path_to_cstring(path)?;
+                    TLSConfiguration_setOwnKeyFromFile(raw, path.as_ptr(), password)
+                }
+            };
+            if !ok {
+                return Err(Error::Tls(TlsError::OwnKey));
+            }
+        }
+        for certificate in &self.ca_certificates {
+            certificate.load(
+                raw,
+                TlsError::CaCertificate,
+                TLSConfiguration_addCACertificate,
+                TLSConfiguration_addCACertificateFromFile,
+            )?;
+        }
+        for certificate in &self.allowed_certificates {
+            certificate.load(
+                raw,
+                TlsError::AllowedCertificate,
+                TLSConfiguration_addAllowedCertificate,
+                TLSConfiguration_addAllowedCertificateFromFile,
+            )?;
+        }
+        for crl in &self.crls {
+            crl.load(
+                raw,
+                TlsError::Crl,
+                TLSConfiguration_addCRL,
+                TLSConfiguration_addCRLFromFile,
+            )?;
+        }
+
+        if let Some(value) = self.allow_only_known_certificates {
+            TLSConfiguration_setAllowOnlyKnownCertificates(raw, value);
+        }
+        if let Some(value) = self.chain_validation {
+            TLSConfiguration_setChainValidation(raw, value);
+        }
+        if let Some(value) = self.time_validation {
+            TLSConfiguration_setTimeValidation(raw, value);
+        }
+        if let Some(version) = self.min_version {
+            TLSConfiguration_setMinTlsVersion(raw, version.to_raw());
+        }
+        if let Some(version) = self.max_version {
+            TLSConfiguration_setMaxTlsVersion(raw, version.to_raw());
+        }
+        for id in self.cipher_suites {
+            TLSConfiguration_addCipherSuite(raw, id.into());
+        }
+        if let Some(enable) = self.session_resumption {
+            TLSConfiguration_enableSessionResumption(raw, enable);
+        }
+        if let Some(interval) = self.session_resumption_interval {
+            let seconds = interval.as_secs().min(c_int::MAX as u64) as c_int;
+            TLSConfiguration_setSessionResumptionInterval(raw, seconds);
+        }
+        if let Some(time) = self.renegotiation_time {
+            TLSConfiguration_setRenegotiationTime(raw, millis(time));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for TlsConfigBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TlsConfigBuilder")
+            .field("own_certificate", &self.own_certificate)
+            .field("ca_certificates", &self.ca_certificates)
+            .field("allowed_certificates", &self.allowed_certificates)
+            .field("crls", &self.crls)
+            .field("min_version", &self.min_version)
+            .field("max_version", &self.max_version)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The side of the connections a [`TlsConfig`] is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Role {
+    Client,
+    Server,
+}
+
+struct RawConfig {
+    raw: TLSConfiguration,
+    // dropped after the configuration that calls it
+    on_event: Option<Box<Mutex<EventHandler>>>,
+    // set by the first client or server built with the configuration
+    role: OnceLock<Role>,
+}
+
+// The configuration is not modified after it has been built; lib60870 only
+// reads it when establishing sessions.
+unsafe impl Send for RawConfig {}
+unsafe impl Sync for RawConfig {}
+
+impl Drop for RawConfig {
+    fn drop(&mut self) {
+        unsafe { TLSConfiguration_destroy(self.raw) }
+    }
+}
+
+/// A TLS configuration: own identity, trusted CAs, allowlist, CRLs and
+/// protocol settings.
+///
+/// Clones share the same configuration, which lives as long as the last
+/// clone or connection using it. lib60870 switches a configuration to
+/// client mode when a client is created with it, so the same configuration
+/// cannot be used for both clients and servers: building a client with a
+/// configuration a server has been built with, or the other way round,
+/// fails with [`Error::TlsRoleMismatch`].
+#[derive(Clone)]
+pub struct TlsConfig {
+    inner: Arc<RawConfig>,
+}
+
+impl TlsConfig {
+    /// Starts building a configuration.
+    pub fn builder() -> TlsConfigBuilder {
+        TlsConfigBuilder::default()
+    }
+
+    /// The underlying `TLSConfiguration`. It must not be destroyed.
+    pub fn as_raw(&self) -> TLSConfiguration {
+        self.inner.raw
+    }
+
+    /// Records that the configuration is used for `role`, failing if it is
+    /// already used for the other one.
+    pub(crate) fn claim(&self, role: Role) -> Result<()> {
+        if *self.inner.role.get_or_init(|| role) == role {
+            Ok(())
+        } else {
+            Err(Error::TlsRoleMismatch)
+        }
+    }
+}
+
+impl fmt::Debug for TlsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TlsConfig").finish_non_exhaustive()
+    }
+}
+
+unsafe extern "C" fn security_event(
+    parameter: *mut c_void,
+    level: TLSEventLevel,
+    code: c_int,
+    message: *const c_char,
+    connection: TLSConnection,
+) {
+    let handler = &*parameter.cast::<Mutex<EventHandler>>();
+    let event = TlsEvent::from_raw(level, code, message, connection);
+    guard((), || {
+        // a panic in an earlier call leaves the mutex poisoned, not broken
+        let mut handler = handler.lock().unwrap_or_else(|e| e.into_inner());
+        handler(event)
+    });
+}
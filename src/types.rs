@@ -0,0 +1,279 @@
+//! Type identifications and causes of transmission.
+
+use std::ffi::CStr;
+use std::fmt;
+
+use crate::bindings::*;
+
+macro_rules! u8_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident / $error:ident ($what:literal) {
+            $($variant:ident = $value:expr,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[non_exhaustive]
+        #[repr(u8)]
+        pub enum $name {
+            $($variant = $value as u8,)*
+        }
+
+        impl $name {
+            #[allow(dead_code)]
+            fn variant_name(self) -> &'static str {
+                match self {
+                    $($name::$variant => stringify!($variant),)*
+                }
+            }
+        }
+
+        impl TryFrom<u8> for $name {
+            type Error = $error;
+
+            fn try_from(value: u8) -> Result<Self, $error> {
+                $(
+                    if value == $name::$variant as u8 {
+                        return Ok($name::$variant);
+                    }
+                )*
+                Err($error(value))
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(value: $name) -> u8 {
+                value as u8
+            }
+        }
+
+        #[doc = concat!("Error returned when converting an unknown ", $what, ".")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $error(pub u8);
+
+        impl fmt::Display for $error {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, concat!("unknown ", $what, " {}"), self.0)
+            }
+        }
+
+        impl std::error::Error for $error {}
+    };
+}
+
+u8_enum! {
+    /// ASDU type identification.
+    ///
+    /// Variants are named after the mnemonics of IEC 60870-5-101/104.
+    #[allow(non_camel_case_types)]
+    pub enum TypeId / UnknownTypeId ("type id") {
+        M_SP_NA_1 = IEC60870_5_TypeID_M_SP_NA_1,
+        M_SP_TA_1 = IEC60870_5_TypeID_M_SP_TA_1,
+        M_DP_NA_1 = IEC60870_5_TypeID_M_DP_NA_1,
+        M_DP_TA_1 = IEC60870_5_TypeID_M_DP_TA_1,
+        M_ST_NA_1 = IEC60870_5_TypeID_M_ST_NA_1,
+        M_ST_TA_1 = IEC60870_5_TypeID_M_ST_TA_1,
+        M_BO_NA_1 = IEC60870_5_TypeID_M_BO_NA_1,
+        M_BO_TA_1 = IEC60870_5_TypeID_M_BO_TA_1,
+        M_ME_NA_1 = IEC60870_5_TypeID_M_ME_NA_1,
+        M_ME_TA_1 = IEC60870_5_TypeID_M_ME_TA_1,
+        M_ME_NB_1 = IEC60870_5_TypeID_M_ME_NB_1,
+        M_ME_TB_1 = IEC60870_5_TypeID_M_ME_TB_1,
+        M_ME_NC_1 = IEC60870_5_TypeID_M_ME_NC_1,
+        M_ME_TC_1 = IEC60870_5_TypeID_M_ME_TC_1,
+        M_IT_NA_1 = IEC60870_5_TypeID_M_IT_NA_1,
+        M_IT_TA_1 = IEC60870_5_TypeID_M_IT_TA_1,
+        M_EP_TA_1 = IEC60870_5_TypeID_M_EP_TA_1,
+        M_EP_TB_1 = IEC60870_5_TypeID_M_EP_TB_1,
+        M_EP_TC_1 = IEC60870_5_TypeID_M_EP_TC_1,
+        M_PS_NA_1 = IEC60870_5_TypeID_M_PS_NA_1,
+        M_ME_ND_1 = IEC60870_5_TypeID_M_ME_ND_1,
+        M_SP_TB_1 = IEC60870_5_TypeID_M_SP_TB_1,
+        M_DP_TB_1 = IEC60870_5_TypeID_M_DP_TB_1,
+        M_ST_TB_1 = IEC60870_5_TypeID_M_ST_TB_1,
+        M_BO_TB_1 = IEC60870_5_TypeID_M_BO_TB_1,
+        M_ME_TD_1 = IEC60870_5_TypeID_M_ME_TD_1,
+        M_ME_TE_1 = IEC60870_5_TypeID_M_ME_TE_1,
+        M_ME_TF_1 = IEC60870_5_TypeID_M_ME_TF_1,
+        M_IT_TB_1 = IEC60870_5_TypeID_M_IT_TB_1,
+        M_EP_TD_1 = IEC60870_5_TypeID_M_EP_TD_1,
+        M_EP_TE_1 = IEC60870_5_TypeID_M_EP_TE_1,
+        M_EP_TF_1 = IEC60870_5_TypeID_M_EP_TF_1,
+        S_IT_TC_1 = IEC60870_5_TypeID_S_IT_TC_1,
+        C_SC_NA_1 = IEC60870_5_TypeID_C_SC_NA_1,
+        C_DC_NA_1 = IEC60870_5_TypeID_C_DC_NA_1,
+        C_RC_NA_1 = IEC60870_5_TypeID_C_RC_NA_1,
+        C_SE_NA_1 = IEC60870_5_TypeID_C_SE_NA_1,
+        C_SE_NB_1 = IEC60870_5_TypeID_C_SE_NB_1,
+        C_SE_NC_1 = IEC60870_5_TypeID_C_SE_NC_1,
+        C_BO_NA_1 = IEC60870_5_TypeID_C_BO_NA_1,
+        C_SC_TA_1 = IEC60870_5_TypeID_C_SC_TA_1,
+        C_DC_TA_1 = IEC60870_5_TypeID_C_DC_TA_1,
+        C_RC_TA_1 = IEC60870_5_TypeID_C_RC_TA_1,
+        C_SE_TA_1 = IEC60870_5_TypeID_C_SE_TA_1,
+        C_SE_TB_1 = IEC60870_5_TypeID_C_SE_TB_1,
+        C_SE_TC_1 = IEC60870_5_TypeID_C_SE_TC_1,
+        C_BO_TA_1 = IEC60870_5_TypeID_C_BO_TA_1,
+        M_EI_NA_1 = IEC60870_5_TypeID_M_EI_NA_1,
+        S_CH_NA_1 = IEC60870_5_TypeID_S_CH_NA_1,
+        S_RP_NA_1 = IEC60870_5_TypeID_S_RP_NA_1,
+        S_AR_NA_1 = IEC60870_5_TypeID_S_AR_NA_1,
+        S_KR_NA_1 = IEC60870_5_TypeID_S_KR_NA_1,
+        S_KS_NA_1 = IEC60870_5_TypeID_S_KS_NA_1,
+        S_KC_NA_1 = IEC60870_5_TypeID_S_KC_NA_1,
+        S_ER_NA_1 = IEC60870_5_TypeID_S_ER_NA_1,
+        S_US_NA_1 = IEC60870_5_TypeID_S_US_NA_1,
+        S_UQ_NA_1 = IEC60870_5_TypeID_S_UQ_NA_1,
+        S_UR_NA_1 = IEC60870_5_TypeID_S_UR_NA_1,
+        S_UK_NA_1 = IEC60870_5_TypeID_S_UK_NA_1,
+        S_UA_NA_1 = IEC60870_5_TypeID_S_UA_NA_1,
+        S_UC_NA_1 = IEC60870_5_TypeID_S_UC_NA_1,
+        C_IC_NA_1 = IEC60870_5_TypeID_C_IC_NA_1,
+        C_CI_NA_1 = IEC60870_5_TypeID_C_CI_NA_1,
+        C_RD_NA_1 = IEC60870_5_TypeID_C_RD_NA_1,
+        C_CS_NA_1 = IEC60870_5_TypeID_C_CS_NA_1,
+        C_TS_NA_1 = IEC60870_5_TypeID_C_TS_NA_1,
+        C_RP_NA_1 = IEC60870_5_TypeID_C_RP_NA_1,
+        C_CD_NA_1 = IEC60870_5_TypeID_C_CD_NA_1,
+        C_TS_TA_1 = IEC60870_5_TypeID_C_TS_TA_1,
+        P_ME_NA_1 = IEC60870_5_TypeID_P_ME_NA_1,
+        P_ME_NB_1 = IEC60870_5_TypeID_P_ME_NB_1,
+        P_ME_NC_1 = IEC60870_5_TypeID_P_ME_NC_1,
+        P_AC_NA_1 = IEC60870_5_TypeID_P_AC_NA_1,
+        F_FR_NA_1 = IEC60870_5_TypeID_F_FR_NA_1,
+        F_SR_NA_1 = IEC60870_5_TypeID_F_SR_NA_1,
+        F_SC_NA_1 = IEC60870_5_TypeID_F_SC_NA_1,
+        F_LS_NA_1 = IEC60870_5_TypeID_F_LS_NA_1,
+        F_AF_NA_1 = IEC60870_5_TypeID_F_AF_NA_1,
+        F_SG_NA_1 = IEC60870_5_TypeID_F_SG_NA_1,
+        F_DR_TA_1 = IEC60870_5_TypeID_F_DR_TA_1,
+        F_SC_NB_1 = IEC60870_5_TypeID_F_SC_NB_1,
+    }
+}
+
+impl TypeId {
+    /// The standard mnemonic, e.g. `M_SP_NA_1`.
+    pub fn mnemonic(self) -> &'static str {
+        self.variant_name()
+    }
+
+    /// Process or system information in monitor direction (`M_*`).
+    pub fn is_monitor(self) -> bool {
+        self.mnemonic().starts_with('M')
+    }
+
+    /// Process or system information in control direction (`C_*`).
+    pub fn is_command(self) -> bool {
+        self.mnemonic().starts_with('C')
+    }
+
+    /// Parameters in control direction (`P_*`).
+    pub fn is_parameter(self) -> bool {
+        self.mnemonic().starts_with('P')
+    }
+
+    /// File transfer (`F_*`).
+    pub fn is_file_transfer(self) -> bool {
+        self.mnemonic().starts_with('F')
+    }
+
+    /// Secure authentication (`S_*`).
+    pub fn is_security(self) -> bool {
+        self.mnemonic().starts_with('S')
+    }
+
+    /// Whether the information objects carry a time tag (`*_T?_1`).
+    pub fn has_timestamp(self) -> bool {
+        self.mnemonic().as_bytes().get(5) == Some(&b'T')
+    }
+}
+
+impl fmt::Display for TypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.mnemonic())
+    }
+}
+
+u8_enum! {
+    /// Cause of transmission.
+    pub enum CauseOfTransmission / UnknownCauseOfTransmission ("cause of transmission") {
+        Periodic = CS101_CauseOfTransmission_CS101_COT_PERIODIC,
+        BackgroundScan = CS101_CauseOfTransmission_CS101_COT_BACKGROUND_SCAN,
+        Spontaneous = CS101_CauseOfTransmission_CS101_COT_SPONTANEOUS,
+        Initialized = CS101_CauseOfTransmission_CS101_COT_INITIALIZED,
+        Request = CS101_CauseOfTransmission_CS101_COT_REQUEST,
+        Activation = CS101_CauseOfTransmission_CS101_COT_ACTIVATION,
+        ActivationCon = CS101_CauseOfTransmission_CS101_COT_ACTIVATION_CON,
+        Deactivation = CS101_CauseOfTransmission_CS101_COT_DEACTIVATION,
+        DeactivationCon = CS101_CauseOfTransmission_CS101_COT_DEACTIVATION_CON,
+        ActivationTermination = CS101_CauseOfTransmission_CS101_COT_ACTIVATION_TERMINATION,
+        ReturnInfoRemote = CS101_CauseOfTransmission_CS101_COT_RETURN_INFO_REMOTE,
+        ReturnInfoLocal = CS101_CauseOfTransmission_CS101_COT_RETURN_INFO_LOCAL,
+        FileTransfer = CS101_CauseOfTransmission_CS101_COT_FILE_TRANSFER,
+        Authentication = CS101_CauseOfTransmission_CS101_COT_AUTHENTICATION,
+        MaintenanceOfAuthSessionKey = CS101_CauseOfTransmission_CS101_COT_MAINTENANCE_OF_AUTH_SESSION_KEY,
+        MaintenanceOfUserRoleAndUpdateKey = CS101_CauseOfTransmission_CS101_COT_MAINTENANCE_OF_USER_ROLE_AND_UPDATE_KEY,
+        InterrogatedByStation = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_STATION,
+        InterrogatedByGroup1 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_1,
+        InterrogatedByGroup2 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_2,
+        InterrogatedByGroup3 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_3,
+        InterrogatedByGroup4 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_4,
+        InterrogatedByGroup5 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_5,
+        InterrogatedByGroup6 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_6,
+        InterrogatedByGroup7 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_7,
+        InterrogatedByGroup8 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_8,
+        InterrogatedByGroup9 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_9,
+        InterrogatedByGroup10 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_10,
+        InterrogatedByGroup11 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_11,
+        InterrogatedByGroup12 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_12,
+        InterrogatedByGroup13 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_13,
+        InterrogatedByGroup14 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_14,
+        InterrogatedByGroup15 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_15,
+        InterrogatedByGroup16 = CS101_CauseOfTransmission_CS101_COT_INTERROGATED_BY_GROUP_16,
+        RequestedByGeneralCounter = CS101_CauseOfTransmission_CS101_COT_REQUESTED_BY_GENERAL_COUNTER,
+        RequestedByGroup1Counter = CS101_CauseOfTransmission_CS101_COT_REQUESTED_BY_GROUP_1_COUNTER,
+        RequestedByGroup2Counter = CS101_CauseOfTransmission_CS101_COT_REQUESTED_BY_GROUP_2_COUNTER,
+        RequestedByGroup3Counter = CS101_CauseOfTransmission_CS101_COT_REQUESTED_BY_GROUP_3_COUNTER,
+        RequestedByGroup4Counter = CS101_CauseOfTransmission_CS101_COT_REQUESTED_BY_GROUP_4_COUNTER,
+        UnknownTypeId = CS101_CauseOfTransmission_CS101_COT_UNKNOWN_TYPE_ID,
+        UnknownCot = CS101_CauseOfTransmission_CS101_COT_UNKNOWN_COT,
+        UnknownCa = CS101_CauseOfTransmission_CS101_COT_UNKNOWN_CA,
+        UnknownIoa = CS101_CauseOfTransmission_CS101_COT_UNKNOWN_IOA,
+    }
+}
+
+impl CauseOfTransmission {
+    /// The name used by lib60870, e.g. `SPONTANEOUS`.
+    pub fn mnemonic(self) -> &'static str {
+        unsafe {
+            CStr::from_ptr(CS101_CauseOfTransmission_toString(
+                self as CS101_CauseOfTransmission,
+            ))
+        }
+        .to_str()
+        .unwrap_or("unknown")
+    }
+
+    /// Whether the cause answers a station or group interrogation.
+    pub fn is_interrogation(self) -> bool {
+        (Self::InterrogatedByStation..=Self::InterrogatedByGroup16).contains(&self)
+    }
+
+    /// Whether the cause answers a counter interrogation.
+    pub fn is_counter_interrogation(self) -> bool {
+        (Self::RequestedByGeneralCounter..=Self::RequestedByGroup4Counter).contains(&self)
+    }
+
+    /// Whether the cause is a negative response to an unknown type, cause or
+    /// address.
+    pub fn is_error(self) -> bool {
+        (Self::UnknownTypeId..=Self::UnknownIoa).contains(&self)
+    }
+}
+
+impl fmt::Display for CauseOfTransmission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.mnemonic())
+    }
+}
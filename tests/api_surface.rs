@@ -0,0 +1,128 @@
+//! Checks that the bindings only expose the lib60870 API.
+//!
+//! bindgen follows every `#include`, so without the allowlist in
+//! `bindgen_allowlist.rs` the crate would re-export libc functions (`malloc`,
+//! `memset`, ...) and hundreds of platform constants.
+
+use std::path::Path;
+
+use regex::Regex;
+
+include!("../bindgen_allowlist.rs");
+
+/// Helper types emitted by bindgen itself.
+const BINDGEN_HELPERS: &[&str] = &[
+    "__BindgenBitfieldUnit",
+    "__BindgenUnionField",
+    "__IncompleteArrayField",
+];
+
+/// Symbols that must never appear in the bindings.
+const FORBIDDEN_SYMBOLS: &[&str] = &["malloc", "free", "memset", "memcpy", "arc4random", "index"];
+
+/// Symbols that must always be present.
+const REQUIRED_SYMBOLS: &[&str] = &[
+    "CS104_Connection_create",
+    "CS104_Slave_create",
+    "CS101_ASDU_create",
+    "CS101_Master_create",
+    "SinglePointInformation_create",
+    "CP56Time2a_toMsTimestamp",
+    "IEC60870_QUALITY_INVALID",
+    "Socket_read",
+    "Hal_getTimeInMs",
+];
+
+/// Extracts the names of all public items declared by a bindings file.
+fn public_symbols(bindings: &str) -> Vec<String> {
+    let top_level = Regex::new(
+        r"^pub (?:fn|const|static|type|struct|union|enum) ([A-Za-z0-9_]+)|^pub use self::\w+ as (\w+);",
+    )
+    .unwrap();
+    let foreign = Regex::new(r"^    pub (?:fn|static) ([A-Za-z0-9_]+)").unwrap();
+
+    let mut symbols = Vec::new();
+    let mut in_extern_block = false;
+    for line in bindings.lines() {
+        if line.ends_with("extern \"C\" {") {
+            in_extern_block = true;
+        } else if line == "}" {
+            in_extern_block = false;
+        }
+
+        let captures = if in_extern_block {
+            foreign.captures(line)
+        } else {
+            top_level.captures(line)
+        };
+        if let Some(captures) = captures {
+            let name = captures.get(1).or_else(|| captures.get(2)).unwrap();
+            symbols.push(name.as_str().to_string());
+        }
+    }
+    symbols
+}
+
+fn unexpected_symbols(bindings: &str) -> Vec<String> {
+    let api = Regex::new(&format!("^(?:{})$", LIB60870_API_PATTERNS.join("|"))).unwrap();
+
+    public_symbols(bindings)
+        .into_iter()
+        .filter(|name| {
+            // Struct tags of allowlisted typedefs, e.g. `sCS101_ASDU` for `CS101_ASDU`
+            let untagged = name
+                .strip_prefix('s')
+                .or_else(|| name.strip_prefix('t'))
+                .unwrap_or(name);
+            !api.is_match(name)
+                && !api.is_match(untagged)
+                && !BINDGEN_HELPERS.contains(&name.as_str())
+        })
+        .collect()
+}
+
+fn check_bindings(name: &str, bindings: &str) {
+    let symbols = public_symbols(bindings);
+
+    let unexpected = unexpected_symbols(bindings);
+    assert!(
+        unexpected.is_empty(),
+        "{} exposes symbols outside the lib60870 API: {:?}",
+        name,
+        unexpected
+    );
+
+    for symbol in FORBIDDEN_SYMBOLS {
+        assert!(
+            !symbols.iter().any(|s| s == symbol),
+            "{} exposes {}",
+            name,
+            symbol
+        );
+    }
+
+    for symbol in REQUIRED_SYMBOLS {
+        assert!(
+            symbols.iter().any(|s| s == symbol),
+            "{} is missing {}",
+            name,
+            symbol
+        );
+    }
+}
+
+#[test]
+fn bindings_only_expose_lib60870_api() {
+    let bindings = include_str!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+    check_bindings("bindings.rs", bindings);
+}
+
+#[test]
+fn pregenerated_bindings_only_expose_lib60870_api() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/bindings");
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let bindings = std::fs::read_to_string(&path).unwrap();
+        check_bindings(&path.display().to_string(), &bindings);
+    }
+}
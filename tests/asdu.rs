@@ -0,0 +1,115 @@
+//! Owned ASDUs: header fields, cloning and the size limit.
+
+use lib60870_sys::information_object::{InformationObject, MeasuredScaled, SinglePoint};
+use lib60870_sys::{AppLayerParameters, Asdu, CauseOfTransmission, Error, Quality, TypeId};
+
+fn scaled(ioa: u32) -> InformationObject {
+    InformationObject::M_ME_NB_1(MeasuredScaled {
+        ioa,
+        value: 1000,
+        quality: Quality::GOOD,
+        timestamp: (),
+    })
+}
+
+#[test]
+fn header_fields() {
+    let parameters = AppLayerParameters {
+        originator_address: 7,
+        ..AppLayerParameters::default()
+    };
+    let mut asdu = Asdu::new(&parameters, CauseOfTransmission::Spontaneous, 1);
+    assert_eq!(asdu.cot(), Some(CauseOfTransmission::Spontaneous));
+    assert_eq!(asdu.originator_address(), 7);
+    assert_eq!(asdu.common_address(), 1);
+    assert_eq!(asdu.parameters(), parameters);
+    assert!(asdu.is_empty());
+    assert!(asdu.payload().is_empty());
+
+    asdu.set_type_id(TypeId::C_IC_NA_1);
+    asdu.set_cot(CauseOfTransmission::ActivationCon);
+    asdu.set_common_address(0xFFFF);
+    asdu.set_test(true);
+    asdu.set_negative(true);
+    asdu.set_sequence(true);
+    assert_eq!(asdu.type_id(), Some(TypeId::C_IC_NA_1));
+    assert_eq!(asdu.raw_type_id(), 100);
+    assert_eq!(asdu.cot(), Some(CauseOfTransmission::ActivationCon));
+    assert_eq!(asdu.raw_cot(), 7);
+    assert_eq!(asdu.common_address(), 0xFFFF);
+    assert!(asdu.is_test());
+    assert!(asdu.is_negative());
+    assert!(asdu.is_sequence());
+}
+
+#[test]
+fn clone_is_independent() {
+    let mut asdu = Asdu::new(
+        &AppLayerParameters::default(),
+        CauseOfTransmission::Periodic,
+        3,
+    );
+    asdu.add_object(&scaled(100)).unwrap();
+
+    let mut clone = asdu.clone();
+    clone.set_common_address(4);
+    clone.add_object(&scaled(101)).unwrap();
+    assert_eq!(asdu.common_address(), 3);
+    assert_eq!(asdu.len(), 1);
+    assert_eq!(clone.len(), 2);
+    assert_eq!(clone.parameters(), asdu.parameters());
+
+    let addresses: Vec<u32> = clone.elements().map(|element| element.address()).collect();
+    assert_eq!(addresses, [100, 101]);
+}
+
+#[test]
+fn full_asdu_rejects_objects() {
+    let parameters = AppLayerParameters::default();
+    let mut asdu = Asdu::new(&parameters, CauseOfTransmission::Spontaneous, 1);
+    let mut ioa = 0;
+    let error = loop {
+        ioa += 1;
+        if let Err(error) = asdu.add_object(&scaled(ioa)) {
+            break error;
+        }
+        assert!(ioa < 1000, "ASDU never became full");
+    };
+    assert_eq!(error, Error::AsduFull);
+    assert_eq!(asdu.len(), ioa as usize - 1);
+
+    // type (1), VSQ (1), COT (2) and CA (2), then IOA (3), SVA (2) and QDS (1)
+    // per object
+    let used = 6 + asdu.payload().len();
+    assert!(used <= usize::from(parameters.max_size_of_asdu));
+    assert!(used + 6 > usize::from(parameters.max_size_of_asdu));
+}
+
+#[test]
+fn mismatching_objects_are_rejected() {
+    let mut asdu = Asdu::new(
+        &AppLayerParameters::default(),
+        CauseOfTransmission::Spontaneous,
+        1,
+    );
+    asdu.add_object(&scaled(10)).unwrap();
+    let single_point = InformationObject::M_SP_NA_1(SinglePoint {
+        ioa: 11,
+        value: true,
+        quality: Quality::GOOD,
+        timestamp: (),
+    });
+    assert_eq!(asdu.add_object(&single_point), Err(Error::AsduFull));
+
+    // a sequence only takes consecutive addresses
+    let mut sequence = Asdu::new(
+        &AppLayerParameters::default(),
+        CauseOfTransmission::Spontaneous,
+        1,
+    );
+    sequence.set_sequence(true);
+    sequence.add_object(&scaled(10)).unwrap();
+    sequence.add_object(&scaled(11)).unwrap();
+    assert_eq!(sequence.add_object(&scaled(13)), Err(Error::AsduFull));
+    assert_eq!(sequence.len(), 2);
+}
@@ -0,0 +1,30 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use std::net::TcpListener;
+use std::time::Duration;
+
+use lib60870_sys::cs104::{Cs104ClientBuilder, Cs104ServerBuilder};
+use lib60870_sys::{Cs104Client, Cs104Server};
+
+/// How long a test waits for something to arrive.
+pub const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A loopback port nothing listens on at the moment.
+pub fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+/// A server listening on `port` of the loopback interface.
+pub fn local_server(port: u16) -> Cs104ServerBuilder {
+    Cs104Server::builder().local_address("127.0.0.1").port(port)
+}
+
+/// A client for the server at `port` of the loopback interface.
+pub fn local_client(port: u16) -> Cs104ClientBuilder {
+    Cs104Client::builder("127.0.0.1").port(port)
+}
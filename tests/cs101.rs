@@ -0,0 +1,385 @@
+//! CS101 master and slave talking over a pair of pseudo terminals.
+#![cfg(all(unix, feature = "rust-hal", not(feature = "no-threads")))]
+
+mod common;
+
+use std::io::{ErrorKind, Read, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use lib60870_sys::cs101::{
+    Cs101Master, Cs101Slave, Cs101SlaveHandler, LinkLayerMode, LinkLayerState, MasterConnection,
+    Parity, SerialPort,
+};
+use lib60870_sys::file::{FileProvider, FileServer};
+use lib60870_sys::information_object::{FileDirectory, InformationObject, MeasuredScaled};
+use lib60870_sys::parameters::LinkLayerParameters;
+use lib60870_sys::timestamp::Cp56Time2a;
+use lib60870_sys::{
+    AppLayerParameters, Asdu, AsduRef, CauseOfTransmission, Error, Quality, TypeId,
+};
+use serialport::{SerialPort as _, TTYPort};
+
+use common::TIMEOUT;
+
+const SLAVE: u16 = 3;
+
+/// Two pseudo terminals whose controlling sides are relayed to each other,
+/// so that their terminal devices behave like the ends of a null modem
+/// cable.
+struct NullModem {
+    names: [String; 2],
+    stop: Arc<AtomicBool>,
+    relays: Vec<JoinHandle<()>>,
+    // kept open so the terminals do not hang up while nobody uses them
+    _terminals: [TTYPort; 2],
+}
+
+impl NullModem {
+    fn new() -> Self {
+        let (mut a, terminal_a) = TTYPort::pair().unwrap();
+        let (mut b, terminal_b) = TTYPort::pair().unwrap();
+        a.set_timeout(Duration::from_millis(20)).unwrap();
+        b.set_timeout(Duration::from_millis(20)).unwrap();
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let relays = vec![
+            relay(
+                a.try_clone_native().unwrap(),
+                b.try_clone_native().unwrap(),
+                &stop,
+            ),
+            relay(b, a, &stop),
+        ];
+        Self {
+            names: [terminal_a.name().unwrap(), terminal_b.name().unwrap()],
+            stop,
+            relays,
+            _terminals: [terminal_a, terminal_b],
+        }
+    }
+
+    fn port(&self, end: usize) -> SerialPort {
+        SerialPort::new(&self.names[end], 9600, Parity::None).unwrap()
+    }
+}
+
+impl Drop for NullModem {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        for relay in self.relays.drain(..) {
+            relay.join().unwrap();
+        }
+    }
+}
+
+fn relay(mut from: TTYPort, mut to: TTYPort, stop: &Arc<AtomicBool>) -> JoinHandle<()> {
+    let stop = Arc::clone(stop);
+    thread::spawn(move || {
+        let mut buf = [0; 256];
+        while !stop.load(Ordering::SeqCst) {
+            match from.read(&mut buf) {
+                // nobody reads a terminal that is not open on the other side
+                Ok(len) => {
+                    let _ = to.write_all(&buf[..len]);
+                }
+                Err(error) if error.kind() == ErrorKind::TimedOut => {}
+                Err(error) => panic!("relay failed: {}", error),
+            }
+        }
+    })
+}
+
+fn link_layer_parameters() -> LinkLayerParameters {
+    LinkLayerParameters {
+        timeout_for_ack: Duration::from_millis(100),
+        timeout_repeat: Duration::from_millis(300),
+        ..LinkLayerParameters::default()
+    }
+}
+
+fn scaled(cot: CauseOfTransmission, ioa: u32, value: i16) -> Asdu {
+    let mut asdu = Asdu::new(&AppLayerParameters::default(), cot, 1);
+    asdu.add_object(&InformationObject::M_ME_NB_1(MeasuredScaled {
+        ioa,
+        value,
+        quality: Quality::GOOD,
+        timestamp: (),
+    }))
+    .unwrap();
+    asdu
+}
+
+#[derive(Debug, PartialEq)]
+enum Call {
+    Interrogation(u8),
+    Read(u32),
+}
+
+struct Station {
+    calls: Mutex<mpsc::Sender<Call>>,
+}
+
+impl Station {
+    fn record(&self, call: Call) {
+        let _ = self.calls.lock().unwrap().send(call);
+    }
+}
+
+impl Cs101SlaveHandler for Station {
+    fn interrogation(&self, connection: &MasterConnection, asdu: &AsduRef, qualifier: u8) -> bool {
+        self.record(Call::Interrogation(qualifier));
+        let mut confirmation = asdu.to_owned();
+        connection.send_act_con(&mut confirmation, false).unwrap();
+        let response = scaled(CauseOfTransmission::InterrogatedByStation, 100, -42);
+        connection.send_asdu(&response).unwrap();
+        connection.send_act_term(&mut confirmation).unwrap();
+        true
+    }
+
+    fn read(&self, _connection: &MasterConnection, _asdu: &AsduRef, ioa: u32) -> bool {
+        self.record(Call::Read(ioa));
+        true
+    }
+}
+
+/// Polls the slave until the master has received a value `accept` returns
+/// `true` for.
+fn poll_until<T>(master: &Cs101Master, rx: &mpsc::Receiver<T>, accept: impl Fn(&T) -> bool) {
+    let deadline = Instant::now() + TIMEOUT;
+    while Instant::now() < deadline {
+        master.poll(SLAVE);
+        if let Ok(value) = rx.recv_timeout(Duration::from_millis(50)) {
+            if accept(&value) {
+                return;
+            }
+        }
+    }
+    panic!("timed out");
+}
+
+#[test]
+fn builder_options_and_full_queues() {
+    let modem = NullModem::new();
+    let app_layer = AppLayerParameters {
+        size_of_ca: 1,
+        size_of_ioa: 2,
+        ..AppLayerParameters::default()
+    };
+    let slave = Cs101Slave::builder(modem.port(0), LinkLayerMode::Unbalanced)
+        .link_layer_parameters(link_layer_parameters())
+        .app_layer_parameters(app_layer)
+        .queue_sizes(2, 1)
+        .link_address(SLAVE)
+        .build(())
+        .unwrap();
+    assert_eq!(slave.mode(), LinkLayerMode::Unbalanced);
+    assert_eq!(slave.link_layer_parameters(), link_layer_parameters());
+    assert_eq!(slave.app_layer_parameters(), app_layer);
+
+    let asdu = scaled(CauseOfTransmission::Spontaneous, 100, 1);
+    slave.enqueue_class1(&asdu).unwrap();
+    assert!(!slave.is_class1_queue_full());
+    slave.enqueue_class1(&asdu).unwrap();
+    assert!(slave.is_class1_queue_full());
+    assert_eq!(slave.enqueue_class1(&asdu), Err(Error::QueueFull));
+
+    slave.enqueue_class2(&asdu).unwrap();
+    assert!(slave.is_class2_queue_full());
+    assert_eq!(slave.enqueue_class2(&asdu), Err(Error::QueueFull));
+
+    slave.flush_queues();
+    assert!(!slave.is_class1_queue_full());
+    assert!(!slave.is_class2_queue_full());
+
+    let master = Cs101Master::builder(modem.port(1), LinkLayerMode::Balanced)
+        .link_layer_parameters(link_layer_parameters())
+        .app_layer_parameters(app_layer)
+        .queue_size(4)
+        .own_address(2)
+        .build()
+        .unwrap();
+    assert_eq!(master.mode(), LinkLayerMode::Balanced);
+    assert_eq!(master.link_layer_parameters(), link_layer_parameters());
+    assert_eq!(master.app_layer_parameters(), app_layer);
+}
+
+#[test]
+fn master_polls_slave() {
+    let modem = NullModem::new();
+    let (calls, call_rx) = mpsc::channel();
+    let mut slave = Cs101Slave::builder(modem.port(0), LinkLayerMode::Unbalanced)
+        .link_layer_parameters(link_layer_parameters())
+        .link_address(SLAVE)
+        .build(Station {
+            calls: Mutex::new(calls),
+        })
+        .unwrap();
+
+    let (states, state_rx) = mpsc::channel();
+    let (asdus, asdu_rx) = mpsc::channel();
+    let mut master = Cs101Master::builder(modem.port(1), LinkLayerMode::Unbalanced)
+        .link_layer_parameters(link_layer_parameters())
+        .slave(SLAVE)
+        .on_link_state_changed(move |address, state| {
+            let _ = states.send((address, state));
+        })
+        .on_asdu(move |address, asdu| {
+            let objects: Vec<_> = asdu.objects().map(Result::unwrap).collect();
+            let _ = asdus.send((address, asdu.type_id(), asdu.cot(), objects));
+        })
+        .build()
+        .unwrap();
+    assert_eq!(master.link_state(SLAVE), None);
+
+    slave.start();
+    master.start();
+    poll_until(&master, &state_rx, |&state| {
+        state == (SLAVE, LinkLayerState::Available)
+    });
+    assert_eq!(master.link_state(SLAVE), Some(LinkLayerState::Available));
+
+    // the handler answers through the class 1 queue
+    master.use_slave_address(SLAVE);
+    master.send_interrogation(CauseOfTransmission::Activation, 1, 20);
+    poll_until(&master, &asdu_rx, |(address, type_id, cot, objects)| {
+        assert_eq!(*address, SLAVE);
+        *type_id == Some(TypeId::M_ME_NB_1)
+            && *cot == Some(CauseOfTransmission::InterrogatedByStation)
+            && matches!(
+                objects[..],
+                [InformationObject::M_ME_NB_1(MeasuredScaled {
+                    ioa: 100,
+                    value: -42,
+                    ..
+                })]
+            )
+    });
+    assert_eq!(call_rx.recv_timeout(TIMEOUT), Ok(Call::Interrogation(20)));
+
+    master.send_read(1, 200);
+    let deadline = Instant::now() + TIMEOUT;
+    loop {
+        master.poll(SLAVE);
+        match call_rx.recv_timeout(Duration::from_millis(50)) {
+            Ok(call) => break assert_eq!(call, Call::Read(200)),
+            Err(_) => assert!(Instant::now() < deadline, "timed out"),
+        }
+    }
+
+    // class 2 data is only sent when polled
+    slave
+        .enqueue_class2(&scaled(CauseOfTransmission::Periodic, 300, 7))
+        .unwrap();
+    poll_until(&master, &asdu_rx, |(_, _, cot, objects)| {
+        *cot == Some(CauseOfTransmission::Periodic)
+            && matches!(
+                objects[..],
+                [InformationObject::M_ME_NB_1(MeasuredScaled {
+                    ioa: 300,
+                    value: 7,
+                    ..
+                })]
+            )
+    });
+
+    // the link fails once the slave stops answering
+    drop(slave);
+    poll_until(&master, &state_rx, |&state| {
+        state == (SLAVE, LinkLayerState::Error)
+    });
+    assert_eq!(master.link_state(SLAVE), Some(LinkLayerState::Error));
+
+    // run_once is a no-op while the background thread runs the master
+    master.run_once();
+    master.stop();
+}
+
+fn record() -> Vec<u8> {
+    (0..300u32).map(|i| (i * 11 % 253) as u8).collect()
+}
+
+struct Records;
+
+impl FileProvider for Records {
+    fn directory(&self, _common_address: u16) -> Vec<FileDirectory> {
+        vec![FileDirectory {
+            ioa: 400,
+            name: 1,
+            length: 300,
+            status: 0,
+            creation_time: Cp56Time2a::default(),
+        }]
+    }
+
+    fn read(&self, common_address: u16, ioa: u32, name: u16) -> Option<Vec<u8>> {
+        (common_address, ioa, name).eq(&(1, 400, 1)).then(record)
+    }
+}
+
+#[test]
+fn master_downloads_file() {
+    let modem = NullModem::new();
+    let (calls, _call_rx) = mpsc::channel();
+    let mut slave = Cs101Slave::builder(modem.port(0), LinkLayerMode::Unbalanced)
+        .link_layer_parameters(link_layer_parameters())
+        .link_address(SLAVE)
+        .build(Station {
+            calls: Mutex::new(calls),
+        })
+        .unwrap();
+    slave
+        .add_plugin(FileServer::new(Records).section_size(100))
+        .unwrap();
+
+    let (states, state_rx) = mpsc::channel();
+    let mut master = Cs101Master::builder(modem.port(1), LinkLayerMode::Unbalanced)
+        .link_layer_parameters(link_layer_parameters())
+        .slave(SLAVE)
+        .on_link_state_changed(move |address, state| {
+            let _ = states.send((address, state));
+        })
+        .build()
+        .unwrap();
+
+    slave.start();
+    master.start();
+    poll_until(&master, &state_rx, |&state| {
+        state == (SLAVE, LinkLayerState::Available)
+    });
+
+    // the transfer polls the selected slave itself
+    master.use_slave_address(SLAVE);
+    let directory = master.directory(1).unwrap();
+    assert_eq!(directory.len(), 1);
+    assert!(directory[0].is_last());
+    assert_eq!(master.download(1, 400, 1).unwrap(), record());
+    assert_eq!(
+        master.download(1, 400, 2).unwrap_err(),
+        Error::FileUnavailable
+    );
+
+    master.stop();
+    slave.stop();
+}
+
+#[test]
+fn file_transfer_times_out_after_response_timeout() {
+    let modem = NullModem::new();
+    let mut master = Cs101Master::builder(modem.port(1), LinkLayerMode::Unbalanced)
+        .link_layer_parameters(link_layer_parameters())
+        .slave(SLAVE)
+        .file_response_timeout(Duration::from_millis(300))
+        .build()
+        .unwrap();
+
+    // nothing answers on the other end of the line
+    master.use_slave_address(SLAVE);
+    let start = Instant::now();
+    assert_eq!(master.directory(1).unwrap_err(), Error::Timeout);
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(300));
+    assert!(elapsed < TIMEOUT);
+}
@@ -0,0 +1,192 @@
+//! CS104 client and server talking over loopback.
+#![cfg(not(feature = "no-threads"))]
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+
+use lib60870_sys::cs104::{ConnectionEvent, MasterConnection, PeerConnectionEvent, SlaveHandler};
+use lib60870_sys::information_object::{InformationObject, MeasuredScaled, SingleCommand};
+use lib60870_sys::{Asdu, AsduRef, CauseOfTransmission, Quality, TypeId};
+
+use common::{free_port, local_client, local_server, TIMEOUT};
+
+#[derive(Default)]
+struct Rtu {
+    activations: AtomicUsize,
+    commands: AtomicUsize,
+}
+
+impl SlaveHandler for Rtu {
+    fn connection_event(&self, _connection: &MasterConnection, event: PeerConnectionEvent) {
+        if event == PeerConnectionEvent::Activated {
+            self.activations.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn interrogation(&self, connection: &MasterConnection, asdu: &AsduRef, qualifier: u8) -> bool {
+        assert_eq!(qualifier, 20);
+        let mut confirmation = asdu.to_owned();
+        connection.send_act_con(&mut confirmation, false).unwrap();
+
+        let mut response = Asdu::new(
+            &connection.app_layer_parameters(),
+            CauseOfTransmission::InterrogatedByStation,
+            asdu.common_address(),
+        );
+        response
+            .add_object(&InformationObject::M_ME_NB_1(MeasuredScaled {
+                ioa: 100,
+                value: -42,
+                quality: Quality::GOOD,
+                timestamp: (),
+            }))
+            .unwrap();
+        connection.send_asdu(&response).unwrap();
+
+        connection.send_act_term(&mut confirmation).unwrap();
+        true
+    }
+
+    fn asdu(&self, connection: &MasterConnection, asdu: &AsduRef) -> bool {
+        if asdu.type_id() != Some(TypeId::C_SC_NA_1) {
+            return false;
+        }
+        self.commands.fetch_add(1, Ordering::SeqCst);
+        connection
+            .send_act_con(&mut asdu.to_owned(), false)
+            .unwrap();
+        true
+    }
+}
+
+#[test]
+fn interrogation_and_command() {
+    let port = free_port();
+    let server = local_server(port).build(Rtu::default()).unwrap();
+    server.start();
+    assert!(server.is_running());
+
+    let (events, event_rx) = mpsc::channel();
+    let (asdus, asdu_rx) = mpsc::channel();
+    let client = local_client(port)
+        .on_connection_event(move |event| {
+            let _ = events.send(event);
+        })
+        .on_asdu(move |asdu| {
+            let _ = asdus.send(asdu.to_owned());
+        })
+        .build()
+        .unwrap();
+
+    client.connect().unwrap();
+    assert_eq!(event_rx.recv_timeout(TIMEOUT), Ok(ConnectionEvent::Opened));
+    client.start_dt();
+    assert_eq!(
+        event_rx.recv_timeout(TIMEOUT),
+        Ok(ConnectionEvent::StartDtConfirmed)
+    );
+    assert_eq!(server.handler().activations.load(Ordering::SeqCst), 1);
+
+    client
+        .send_interrogation(CauseOfTransmission::Activation, 1, 20)
+        .unwrap();
+    let con = asdu_rx.recv_timeout(TIMEOUT).unwrap();
+    assert_eq!(con.type_id(), Some(TypeId::C_IC_NA_1));
+    assert_eq!(con.cot(), Some(CauseOfTransmission::ActivationCon));
+
+    let data = asdu_rx.recv_timeout(TIMEOUT).unwrap();
+    assert_eq!(data.cot(), Some(CauseOfTransmission::InterrogatedByStation));
+    assert_eq!(data.common_address(), 1);
+    let objects: Vec<_> = data.objects().collect::<Result<_, _>>().unwrap();
+    assert_eq!(
+        objects,
+        [InformationObject::M_ME_NB_1(MeasuredScaled {
+            ioa: 100,
+            value: -42,
+            quality: Quality::GOOD,
+            timestamp: (),
+        })]
+    );
+
+    let term = asdu_rx.recv_timeout(TIMEOUT).unwrap();
+    assert_eq!(term.cot(), Some(CauseOfTransmission::ActivationTermination));
+
+    client
+        .send_command(
+            CauseOfTransmission::Activation,
+            1,
+            &InformationObject::C_SC_NA_1(SingleCommand {
+                ioa: 5000,
+                state: true,
+                select: false,
+                qualifier: 0,
+                timestamp: (),
+            }),
+        )
+        .unwrap();
+    let con = asdu_rx.recv_timeout(TIMEOUT).unwrap();
+    assert_eq!(con.type_id(), Some(TypeId::C_SC_NA_1));
+    assert_eq!(con.cot(), Some(CauseOfTransmission::ActivationCon));
+    assert!(!con.is_negative());
+    assert_eq!(server.handler().commands.load(Ordering::SeqCst), 1);
+
+    drop(client);
+    server.stop();
+    assert!(!server.is_running());
+}
+
+#[test]
+fn unhandled_command_is_rejected() {
+    let port = free_port();
+    let server = local_server(port).build(()).unwrap();
+    server.start();
+
+    let (asdus, asdu_rx) = mpsc::channel();
+    let client = local_client(port)
+        .on_asdu(move |asdu| {
+            let _ = asdus.send(asdu.to_owned());
+        })
+        .build()
+        .unwrap();
+    client.connect().unwrap();
+    client.start_dt();
+
+    client.send_read(1, 100).unwrap();
+    let response = asdu_rx.recv_timeout(TIMEOUT).unwrap();
+    assert_eq!(response.cot(), Some(CauseOfTransmission::UnknownTypeId));
+}
+
+#[test]
+fn panicking_handler_does_not_unwind_into_c() {
+    struct Panics;
+
+    impl SlaveHandler for Panics {
+        fn read(&self, _connection: &MasterConnection, _asdu: &AsduRef, _ioa: u32) -> bool {
+            panic!("handler panicked");
+        }
+    }
+
+    let port = free_port();
+    let server = local_server(port).build(Panics).unwrap();
+    server.start();
+
+    let (asdus, asdu_rx) = mpsc::channel();
+    let client = local_client(port)
+        .on_asdu(move |asdu| {
+            let _ = asdus.send(asdu.to_owned());
+            panic!("client handler panicked");
+        })
+        .build()
+        .unwrap();
+    client.connect().unwrap();
+    client.start_dt();
+
+    // both panics are caught and the connection keeps working
+    for _ in 0..2 {
+        client.send_read(1, 100).unwrap();
+        let response = asdu_rx.recv_timeout(TIMEOUT).unwrap();
+        assert_eq!(response.cot(), Some(CauseOfTransmission::UnknownTypeId));
+    }
+}
@@ -0,0 +1,267 @@
+//! Redundancy groups of a CS104 server with several clients over loopback.
+#![cfg(not(feature = "no-threads"))]
+
+mod common;
+
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use lib60870_sys::cs104::{
+    ConnectionEvent, MasterConnection, RedundancyGroup, ServerMode, SlaveHandler,
+};
+use lib60870_sys::information_object::{InformationObject, MeasuredScaled};
+use lib60870_sys::{Asdu, CauseOfTransmission, Cs104Client, Cs104Server, Error, Quality};
+
+use common::{free_port, local_client, local_server, TIMEOUT};
+
+const LOCALHOST: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+/// Records which group changed its active connection.
+#[derive(Default)]
+struct Station {
+    changes: Mutex<Vec<(String, bool)>>,
+}
+
+impl SlaveHandler for Station {
+    fn redundancy_group_changed(
+        &self,
+        _connection: &MasterConnection,
+        group: &RedundancyGroup,
+        active: bool,
+    ) {
+        self.changes
+            .lock()
+            .unwrap()
+            .push((group.name().to_owned(), active));
+    }
+}
+
+impl Station {
+    /// The changes so far, once there are at least `count`.
+    fn changes(&self, count: usize) -> Vec<(String, bool)> {
+        let deadline = Instant::now() + TIMEOUT;
+        loop {
+            let changes = self.changes.lock().unwrap().clone();
+            if changes.len() >= count || Instant::now() > deadline {
+                return changes;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+struct Client {
+    client: Cs104Client,
+    events: Receiver<ConnectionEvent>,
+    asdus: Receiver<Asdu>,
+}
+
+impl Client {
+    fn connect(port: u16) -> Self {
+        let (events, events_rx) = mpsc::channel();
+        let (asdus, asdus_rx) = mpsc::channel();
+        let client = local_client(port)
+            .on_connection_event(move |event| {
+                let _ = events.send(event);
+            })
+            .on_asdu(move |asdu| {
+                let _ = asdus.send(asdu.to_owned());
+            })
+            .build()
+            .unwrap();
+        client.connect().unwrap();
+        Client {
+            client,
+            events: events_rx,
+            asdus: asdus_rx,
+        }
+    }
+
+    fn start_dt(&self) {
+        self.client.start_dt();
+        loop {
+            let event = self.events.recv_timeout(TIMEOUT).unwrap();
+            if event == ConnectionEvent::StartDtConfirmed {
+                return;
+            }
+        }
+    }
+
+    /// Waits for the measurement at `ioa`, skipping others that were sent
+    /// again after a failover.
+    fn receive(&self, ioa: u32) -> bool {
+        let deadline = Instant::now() + TIMEOUT;
+        while let Some(left) = deadline.checked_duration_since(Instant::now()) {
+            let Ok(asdu) = self.asdus.recv_timeout(left) else {
+                break;
+            };
+            if asdu.element(0).map(|element| element.address()) == Some(ioa) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+fn measurement(server: &Cs104Server<Station>, ioa: u32) -> Asdu {
+    let mut asdu = Asdu::new(
+        &server.app_layer_parameters(),
+        CauseOfTransmission::Spontaneous,
+        1,
+    );
+    asdu.add_object(&InformationObject::M_ME_NB_1(MeasuredScaled {
+        ioa,
+        value: 1,
+        quality: Quality::GOOD,
+        timestamp: (),
+    }))
+    .unwrap();
+    asdu
+}
+
+#[test]
+fn group_fails_over_to_other_client() {
+    let port = free_port();
+    let server = local_server(port)
+        .redundancy_group(RedundancyGroup::new("scada").allowed_client(LOCALHOST))
+        .build(Station::default())
+        .unwrap();
+    assert_eq!(server.redundancy_groups().len(), 1);
+    server.start();
+
+    let first = Client::connect(port);
+    first.start_dt();
+    server.enqueue_asdu(&measurement(&server, 1));
+    assert!(first.receive(1));
+
+    // the second client takes over the event queue of the group
+    let second = Client::connect(port);
+    second.start_dt();
+    server.enqueue_asdu(&measurement(&server, 2));
+    assert!(second.receive(2));
+    assert!(first
+        .asdus
+        .try_iter()
+        .all(|asdu| asdu.element(0).unwrap().address() != 2));
+
+    // events queued while no connection is active go to the next one
+    second.client.close();
+    server.enqueue_asdu(&measurement(&server, 3));
+    first.start_dt();
+    assert!(first.receive(3));
+
+    // activated three times, and the first client deactivated in between
+    let changes = server.handler().changes(4);
+    let scada = |active| ("scada".to_owned(), active);
+    assert_eq!(
+        changes
+            .iter()
+            .filter(|change| **change == scada(true))
+            .count(),
+        3
+    );
+    assert!(changes.contains(&scada(false)));
+
+    first.client.close();
+    server.stop();
+}
+
+#[test]
+fn unknown_clients_join_catch_all_group() {
+    let port = free_port();
+    let remote =
+        RedundancyGroup::new("remote").allowed_socket_addresses(["10.1.2.3:2404".parse().unwrap()]);
+    assert_eq!(remote.clients(), ["10.1.2.3".parse::<IpAddr>().unwrap()]);
+    let local = RedundancyGroup::catch_all("local");
+    assert!(local.is_catch_all());
+
+    let server = local_server(port)
+        .redundancy_group(remote)
+        .redundancy_group(local)
+        .build(Station::default())
+        .unwrap();
+    server.start();
+
+    let client = Client::connect(port);
+    client.start_dt();
+    assert_eq!(server.handler().changes(1), [("local".to_owned(), true)]);
+
+    client.client.close();
+    server.stop();
+}
+
+#[test]
+fn mapped_addresses_do_not_allow_ipv4_clients() {
+    let mapped: IpAddr = "::ffff:127.0.0.1".parse().unwrap();
+    let group = RedundancyGroup::new("mapped").allowed_client(mapped);
+    assert_eq!(group.clients(), [mapped]);
+    assert!(!group.is_catch_all());
+
+    let port = free_port();
+    let server = local_server(port)
+        .redundancy_group(group)
+        .redundancy_group(RedundancyGroup::catch_all("others"))
+        .build(Station::default())
+        .unwrap();
+    server.start();
+
+    let client = Client::connect(port);
+    client.start_dt();
+    assert_eq!(server.handler().changes(1), [("others".to_owned(), true)]);
+
+    client.client.close();
+    server.stop();
+}
+
+// Windows listeners only accept IPv6 clients by default
+#[cfg(not(windows))]
+#[test]
+fn ipv4_clients_of_ipv6_listener_join_catch_all_group() {
+    let port = free_port();
+    let server = Cs104Server::builder()
+        .local_address("::")
+        .port(port)
+        .redundancy_group(RedundancyGroup::new("scada").allowed_client(LOCALHOST))
+        .redundancy_group(RedundancyGroup::catch_all("others"))
+        .build(Station::default())
+        .unwrap();
+    server.start();
+
+    // the client's address is ::ffff:127.0.0.1 on the dual-stack listener
+    let client = Client::connect(port);
+    client.start_dt();
+    assert_eq!(server.handler().changes(1), [("others".to_owned(), true)]);
+    server.enqueue_asdu(&measurement(&server, 1));
+    assert!(client.receive(1));
+
+    client.client.close();
+    server.stop();
+}
+
+#[test]
+fn groups_need_multiple_groups_mode() {
+    let group = || RedundancyGroup::new("scada").allowed_client(LOCALHOST);
+    let error = Cs104Server::builder()
+        .server_mode(ServerMode::ConnectionIsRedundancyGroup)
+        .redundancy_group(group())
+        .build(Station::default())
+        .err();
+    assert_eq!(error, Some(Error::InvalidServerMode));
+
+    // the order of the calls does not matter
+    let error = Cs104Server::builder()
+        .redundancy_group(group())
+        .server_mode(ServerMode::SingleRedundancyGroup)
+        .build(Station::default())
+        .err();
+    assert_eq!(error, Some(Error::InvalidServerMode));
+
+    let server = Cs104Server::builder()
+        .server_mode(ServerMode::MultipleRedundancyGroups)
+        .redundancy_group(group())
+        .build(Station::default())
+        .unwrap();
+    assert_eq!(server.redundancy_groups(), [group()]);
+}
@@ -0,0 +1,59 @@
+//! Threadless CS104 server driven by a tokio task.
+#![cfg(all(feature = "tokio", not(feature = "no-threads")))]
+
+mod common;
+
+use std::time::Duration;
+
+use lib60870_sys::cs104::{ConnectionEvent, ThreadlessRunner};
+use lib60870_sys::information_object::{InformationObject, SinglePoint};
+use lib60870_sys::{AppLayerParameters, Asdu, CauseOfTransmission, Quality};
+
+use common::{free_port, local_client, local_server, TIMEOUT};
+
+#[tokio::test]
+async fn spontaneous_data_and_shutdown() {
+    let port = free_port();
+    let server = local_server(port).build(()).unwrap();
+    let runner = ThreadlessRunner::new(server).tick_interval(Duration::from_millis(5));
+    let events = runner.sender();
+    let shutdown = runner.shutdown_handle();
+    let task = tokio::spawn(runner.run());
+
+    let (client, mut asdus) = local_client(port).build_async().unwrap();
+    let mut state = client.events();
+
+    tokio::time::timeout(TIMEOUT, async {
+        client.connect().await.unwrap();
+        client.start_dt().await.unwrap();
+
+        let mut asdu = Asdu::new(
+            &AppLayerParameters::default(),
+            CauseOfTransmission::Spontaneous,
+            1,
+        );
+        asdu.add_object(&InformationObject::M_SP_NA_1(SinglePoint {
+            ioa: 10,
+            value: true,
+            quality: Quality::GOOD,
+            timestamp: (),
+        }))
+        .unwrap();
+        events.send(asdu).await.unwrap();
+
+        let received = asdus.recv().await.unwrap();
+        assert_eq!(received.cot(), Some(CauseOfTransmission::Spontaneous));
+        assert_eq!(received.element(0).unwrap().address(), 10);
+
+        shutdown.shutdown().await;
+        let server = task.await.unwrap();
+        assert!(!server.is_running());
+
+        state
+            .wait_for(|event| *event == ConnectionEvent::Closed)
+            .await
+            .unwrap();
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,94 @@
+//! Threadless CS104 server driven by a tokio task, talking to a peer that
+//! speaks raw APDUs. Unlike the client, the peer needs no lib60870 threads,
+//! so this also runs with the `no-threads` feature.
+#![cfg(feature = "tokio")]
+
+mod common;
+
+use std::time::Duration;
+
+use lib60870_sys::cs104::ThreadlessRunner;
+use lib60870_sys::information_object::{InformationObject, SinglePoint};
+use lib60870_sys::{AppLayerParameters, Asdu, CauseOfTransmission, Quality};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use common::{free_port, local_server, TIMEOUT};
+
+const STARTDT_ACT: [u8; 6] = [0x68, 0x04, 0x07, 0x00, 0x00, 0x00];
+const STARTDT_CON: [u8; 6] = [0x68, 0x04, 0x0B, 0x00, 0x00, 0x00];
+
+/// Reads one APDU, start byte and length included.
+async fn read_apdu(stream: &mut TcpStream) -> Vec<u8> {
+    let mut apdu = vec![0; 2];
+    stream.read_exact(&mut apdu).await.unwrap();
+    assert_eq!(apdu[0], 0x68);
+    apdu.resize(2 + usize::from(apdu[1]), 0);
+    stream.read_exact(&mut apdu[2..]).await.unwrap();
+    apdu
+}
+
+/// Connects to the server at `port` once it listens.
+async fn connect(port: u16) -> TcpStream {
+    loop {
+        match TcpStream::connect(("127.0.0.1", port)).await {
+            Ok(stream) => return stream,
+            Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+        }
+    }
+}
+
+#[tokio::test]
+async fn raw_peer_starts_data_transfer() {
+    let port = free_port();
+    let server = local_server(port).build(()).unwrap();
+    let runner = ThreadlessRunner::new(server).tick_interval(Duration::from_millis(5));
+    let events = runner.sender();
+    let shutdown = runner.shutdown_handle();
+    let task = tokio::spawn(runner.run());
+
+    tokio::time::timeout(TIMEOUT, async {
+        let mut peer = connect(port).await;
+        peer.write_all(&STARTDT_ACT).await.unwrap();
+        assert_eq!(read_apdu(&mut peer).await, STARTDT_CON);
+
+        let mut asdu = Asdu::new(
+            &AppLayerParameters::default(),
+            CauseOfTransmission::Spontaneous,
+            1,
+        );
+        asdu.add_object(&InformationObject::M_SP_NA_1(SinglePoint {
+            ioa: 10,
+            value: true,
+            quality: Quality::GOOD,
+            timestamp: (),
+        }))
+        .unwrap();
+        events.send(asdu).await.unwrap();
+
+        // an I frame with the first sequence numbers, then the ASDU
+        let apdu = read_apdu(&mut peer).await;
+        assert_eq!(apdu[2..6], [0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            apdu[6..],
+            [
+                0x01, // M_SP_NA_1
+                0x01, // one object
+                0x03, 0x00, // spontaneous, originator 0
+                0x01, 0x00, // common address 1
+                0x0A, 0x00, 0x00, // ioa 10
+                0x01, // on, good quality
+            ]
+        );
+
+        shutdown.shutdown().await;
+        let server = task.await.unwrap();
+        assert!(!server.is_running());
+
+        // the server closes the connection when it stops, by reset or EOF
+        let mut rest = Vec::new();
+        let _ = peer.read_to_end(&mut rest).await;
+    })
+    .await
+    .unwrap();
+}
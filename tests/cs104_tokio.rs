@@ -0,0 +1,162 @@
+//! Async CS104 client against a server over loopback.
+#![cfg(all(feature = "tokio", not(feature = "no-threads")))]
+
+mod common;
+
+use lib60870_sys::cs104::{ConnectionEvent, MasterConnection, SlaveHandler};
+use lib60870_sys::information_object::{InformationObject, MeasuredScaled, SingleCommand};
+use std::time::Duration;
+
+use lib60870_sys::{Asdu, AsduRef, CauseOfTransmission, Error, Quality, TypeId};
+
+use common::{free_port, local_client, local_server, TIMEOUT};
+
+struct Rtu;
+
+impl SlaveHandler for Rtu {
+    fn interrogation(&self, connection: &MasterConnection, asdu: &AsduRef, _qualifier: u8) -> bool {
+        let mut confirmation = asdu.to_owned();
+        connection.send_act_con(&mut confirmation, false).unwrap();
+        let mut response = Asdu::new(
+            &connection.app_layer_parameters(),
+            CauseOfTransmission::InterrogatedByStation,
+            asdu.common_address(),
+        );
+        response
+            .add_object(&InformationObject::M_ME_NB_1(MeasuredScaled {
+                ioa: 100,
+                value: 7,
+                quality: Quality::GOOD,
+                timestamp: (),
+            }))
+            .unwrap();
+        connection.send_asdu(&response).unwrap();
+        connection.send_act_term(&mut confirmation).unwrap();
+        true
+    }
+
+    fn asdu(&self, connection: &MasterConnection, asdu: &AsduRef) -> bool {
+        if asdu.type_id() != Some(TypeId::C_SC_NA_1) {
+            return false;
+        }
+        // IOA 1 is the only switch
+        let ioa = asdu.element(0).unwrap().address();
+        connection
+            .send_act_con(&mut asdu.to_owned(), ioa != 1)
+            .unwrap();
+        true
+    }
+}
+
+/// Confirms interrogations but never terminates them.
+struct Unterminated;
+
+impl SlaveHandler for Unterminated {
+    fn interrogation(&self, connection: &MasterConnection, asdu: &AsduRef, _qualifier: u8) -> bool {
+        connection
+            .send_act_con(&mut asdu.to_owned(), false)
+            .unwrap();
+        true
+    }
+}
+
+fn switch(ioa: u32) -> InformationObject {
+    InformationObject::C_SC_NA_1(SingleCommand {
+        ioa,
+        state: true,
+        select: false,
+        qualifier: 0,
+        timestamp: (),
+    })
+}
+
+#[tokio::test]
+async fn commands_resolve_on_confirmation() {
+    let port = free_port();
+    let server = local_server(port).build(Rtu).unwrap();
+    server.start();
+
+    let (client, mut asdus) = local_client(port).build_async().unwrap();
+    let events = client.events();
+    assert_eq!(*events.borrow(), ConnectionEvent::Closed);
+
+    tokio::time::timeout(TIMEOUT, async {
+        client.connect().await.unwrap();
+        client.start_dt().await.unwrap();
+        assert_eq!(*events.borrow(), ConnectionEvent::StartDtConfirmed);
+
+        client.interrogation(1, 20).await.unwrap();
+        let cots: Vec<_> = [
+            asdus.recv().await.unwrap(),
+            asdus.recv().await.unwrap(),
+            asdus.recv().await.unwrap(),
+        ]
+        .iter()
+        .map(|asdu| asdu.cot().unwrap())
+        .collect();
+        assert_eq!(
+            cots,
+            [
+                CauseOfTransmission::ActivationCon,
+                CauseOfTransmission::InterrogatedByStation,
+                CauseOfTransmission::ActivationTermination,
+            ]
+        );
+
+        let con = client.command(1, &switch(1)).await.unwrap();
+        assert_eq!(con.type_id(), Some(TypeId::C_SC_NA_1));
+        assert_eq!(
+            client.command(1, &switch(2)).await.unwrap_err(),
+            Error::Rejected(CauseOfTransmission::ActivationCon)
+        );
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn unknown_command_is_rejected() {
+    let port = free_port();
+    let server = local_server(port).build(()).unwrap();
+    server.start();
+
+    let (client, _asdus) = local_client(port).build_async().unwrap();
+    tokio::time::timeout(TIMEOUT, async {
+        client.connect().await.unwrap();
+        client.start_dt().await.unwrap();
+        assert_eq!(
+            client.command(1, &switch(1)).await.unwrap_err(),
+            Error::Rejected(CauseOfTransmission::UnknownTypeId)
+        );
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn missing_termination_times_out() {
+    let port = free_port();
+    let server = local_server(port).build(Unterminated).unwrap();
+    server.start();
+
+    let (client, _asdus) = local_client(port)
+        .termination_timeout(Duration::from_millis(200))
+        .build_async()
+        .unwrap();
+    tokio::time::timeout(TIMEOUT, async {
+        client.connect().await.unwrap();
+        client.start_dt().await.unwrap();
+        assert_eq!(client.interrogation(1, 20).await, Err(Error::Timeout));
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn connect_fails_without_server() {
+    let (client, _asdus) = local_client(free_port()).build_async().unwrap();
+    let result = tokio::time::timeout(TIMEOUT, client.connect())
+        .await
+        .unwrap();
+    assert_eq!(result, Err(Error::ConnectFailed));
+}
@@ -0,0 +1,159 @@
+//! File server plugin against the blocking and async clients over loopback.
+#![cfg(not(feature = "no-threads"))]
+
+mod common;
+
+#[cfg(feature = "tokio")]
+use lib60870_sys::cs104::AsyncCs104Client;
+use lib60870_sys::file::{checksum, FileProvider, FileServer};
+use lib60870_sys::information_object::FileDirectory;
+use lib60870_sys::timestamp::Cp56Time2a;
+use lib60870_sys::Error;
+
+use common::{free_port, local_client, local_server, TIMEOUT};
+
+fn record() -> Vec<u8> {
+    (0..1000u32).map(|i| (i * 7 % 251) as u8).collect()
+}
+
+struct Records;
+
+impl FileProvider for Records {
+    fn directory(&self, common_address: u16) -> Vec<FileDirectory> {
+        if common_address != 1 {
+            return Vec::new();
+        }
+        vec![
+            FileDirectory {
+                ioa: 300,
+                name: 1,
+                length: 1000,
+                status: 0,
+                creation_time: Cp56Time2a::default(),
+            },
+            FileDirectory {
+                ioa: 300,
+                name: 2,
+                length: 0,
+                status: 0,
+                creation_time: Cp56Time2a::default(),
+            },
+        ]
+    }
+
+    fn read(&self, common_address: u16, ioa: u32, name: u16) -> Option<Vec<u8>> {
+        match (common_address, ioa, name) {
+            (1, 300, 1) => Some(record()),
+            (1, 300, 2) => Some(Vec::new()),
+            // one octet more than the length of file can describe
+            (1, 300, 3) => Some(vec![0; 0x100_0000]),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+async fn connect(port: u16) -> AsyncCs104Client {
+    let (client, _asdus) = local_client(port).build_async().unwrap();
+    client.connect().await.unwrap();
+    client.start_dt().await.unwrap();
+    client
+}
+
+#[test]
+fn checksum_is_sum_modulo_256() {
+    assert_eq!(checksum(&[]), 0);
+    assert_eq!(checksum(&[200, 100, 1]), 45);
+}
+
+#[test]
+fn blocking_client_lists_and_downloads_files() {
+    let port = free_port();
+    let mut server = local_server(port).build(()).unwrap();
+    server
+        .add_plugin(FileServer::new(Records).section_size(400))
+        .unwrap();
+    server.start();
+    let client = local_client(port).build().unwrap();
+    client.connect().unwrap();
+    client.start_dt();
+
+    // the server only answers once STARTDT has been confirmed
+    let deadline = std::time::Instant::now() + TIMEOUT;
+    let directory = loop {
+        match client.directory(1) {
+            Err(Error::NotSent) if std::time::Instant::now() < deadline => {
+                std::thread::sleep(std::time::Duration::from_millis(10))
+            }
+            result => break result.unwrap(),
+        }
+    };
+    assert_eq!(directory.len(), 2);
+    assert!(directory[1].is_last());
+    assert!(client.directory(2).unwrap().is_empty());
+
+    assert_eq!(client.download(1, 300, 1).unwrap(), record());
+    let mut written = Vec::new();
+    assert_eq!(client.download_to(1, 300, 1, &mut written).unwrap(), 1000);
+    assert_eq!(written, record());
+    assert_eq!(
+        client.download(1, 300, 9).unwrap_err(),
+        Error::FileUnavailable
+    );
+
+    client.close();
+    server.stop();
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn files_are_listed_and_downloaded() {
+    let port = free_port();
+    let mut server = local_server(port).build(()).unwrap();
+    // several sections with several segments each
+    server
+        .add_plugin(FileServer::new(Records).section_size(400))
+        .unwrap();
+    server.start();
+    let client = connect(port).await;
+
+    let directory = client.directory(1).await.unwrap();
+    assert_eq!(directory.len(), 2);
+    assert_eq!((directory[0].name, directory[0].length), (1, 1000));
+    assert!(!directory[0].is_last());
+    assert!(directory[1].is_last());
+    assert!(client.directory(2).await.unwrap().is_empty());
+
+    assert_eq!(client.download(1, 300, 1).await.unwrap(), record());
+    assert_eq!(client.download(1, 300, 2).await.unwrap(), Vec::<u8>::new());
+
+    let mut written = Vec::new();
+    let length = client.download_to(1, 300, 1, &mut written).await.unwrap();
+    assert_eq!(length, 1000);
+    assert_eq!(written, record());
+
+    client.close();
+    server.stop();
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn missing_and_oversized_files_are_unavailable() {
+    let port = free_port();
+    let mut server = local_server(port).build(()).unwrap();
+    server.add_plugin(FileServer::new(Records)).unwrap();
+    server.start();
+    let client = connect(port).await;
+
+    assert_eq!(
+        client.download(1, 300, 9).await.unwrap_err(),
+        Error::FileUnavailable
+    );
+    assert_eq!(
+        client.download(1, 300, 3).await.unwrap_err(),
+        Error::FileUnavailable
+    );
+
+    client.close();
+    server.stop();
+}
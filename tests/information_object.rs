@@ -0,0 +1,506 @@
+//! Every information object type through an ASDU and back.
+
+use std::collections::HashSet;
+
+use lib60870_sys::information_object::*;
+use lib60870_sys::quality::OutputCircuitInfo;
+use lib60870_sys::timestamp::{Cp16Time2a, Cp24Time2a, Cp56Time2a};
+use lib60870_sys::{AppLayerParameters, Asdu, CauseOfTransmission, Quality, StartEvents};
+
+const QUALITY: Quality = Quality::BLOCKED.union(Quality::INVALID);
+const QDS: Quality = Quality::OVERFLOW.union(Quality::NON_TOPICAL);
+
+fn cp56() -> Cp56Time2a {
+    // 2024-03-12 (Tuesday) 10:34:52.501
+    Cp56Time2a::from_bytes([0x95, 0xCD, 0x22, 0x2A, 0x4C, 0x03, 0x18])
+}
+
+fn cp24() -> Cp24Time2a {
+    Cp24Time2a::from_bytes([0x95, 0xCD, 0x22])
+}
+
+fn cp16() -> Cp16Time2a {
+    Cp16Time2a::from_bytes([0x10, 0x27])
+}
+
+fn counter() -> CounterReading {
+    CounterReading {
+        value: -123_456,
+        sequence_number: 17,
+        carry: true,
+        adjusted: false,
+        invalid: true,
+    }
+}
+
+/// The normalized value of an object, which is only kept to 1/32768.
+fn normalized_value(object: &mut InformationObject) -> Option<&mut f32> {
+    match object {
+        InformationObject::M_ME_NA_1(io) => Some(&mut io.value),
+        InformationObject::M_ME_TA_1(io) => Some(&mut io.value),
+        InformationObject::M_ME_TD_1(io) => Some(&mut io.value),
+        InformationObject::M_ME_ND_1(io) => Some(&mut io.value),
+        InformationObject::C_SE_NA_1(io) => Some(&mut io.value),
+        InformationObject::C_SE_TA_1(io) => Some(&mut io.value),
+        InformationObject::P_ME_NA_1(io) => Some(&mut io.value),
+        _ => None,
+    }
+}
+
+fn round_trip(object: &InformationObject) {
+    let mut asdu = Asdu::new(
+        &AppLayerParameters::default(),
+        CauseOfTransmission::Spontaneous,
+        1,
+    );
+    asdu.add_object(object).unwrap();
+    assert_eq!(asdu.type_id(), Some(object.type_id()));
+    assert_eq!(asdu.len(), 1);
+
+    let mut decoded = asdu.objects().next().unwrap().unwrap();
+    let mut expected = object.clone();
+    if let (Some(value), Some(expected)) = (
+        normalized_value(&mut decoded),
+        normalized_value(&mut expected),
+    ) {
+        assert!((*value - *expected).abs() < 2.0 / 32768.0, "{:?}", object);
+        *value = *expected;
+    }
+    assert_eq!(decoded, *object);
+}
+
+fn objects() -> Vec<InformationObject> {
+    use InformationObject as Io;
+
+    vec![
+        Io::M_SP_NA_1(SinglePoint {
+            ioa: 1,
+            value: true,
+            quality: QUALITY,
+            timestamp: (),
+        }),
+        Io::M_SP_TA_1(SinglePoint {
+            ioa: 2,
+            value: false,
+            quality: Quality::SUBSTITUTED,
+            timestamp: cp24(),
+        }),
+        Io::M_SP_TB_1(SinglePoint {
+            ioa: 3,
+            value: true,
+            quality: Quality::GOOD,
+            timestamp: cp56(),
+        }),
+        Io::M_DP_NA_1(DoublePoint {
+            ioa: 4,
+            value: DoublePointState::On,
+            quality: QUALITY,
+            timestamp: (),
+        }),
+        Io::M_DP_TA_1(DoublePoint {
+            ioa: 5,
+            value: DoublePointState::Indeterminate,
+            quality: Quality::NON_TOPICAL,
+            timestamp: cp24(),
+        }),
+        Io::M_DP_TB_1(DoublePoint {
+            ioa: 6,
+            value: DoublePointState::Off,
+            quality: Quality::GOOD,
+            timestamp: cp56(),
+        }),
+        Io::M_ST_NA_1(StepPosition {
+            ioa: 7,
+            value: -64,
+            transient: true,
+            quality: QDS,
+            timestamp: (),
+        }),
+        Io::M_ST_TA_1(StepPosition {
+            ioa: 8,
+            value: 63,
+            transient: false,
+            quality: QUALITY,
+            timestamp: cp24(),
+        }),
+        Io::M_ST_TB_1(StepPosition {
+            ioa: 9,
+            value: -5,
+            transient: true,
+            quality: Quality::GOOD,
+            timestamp: cp56(),
+        }),
+        Io::M_BO_NA_1(Bitstring32 {
+            ioa: 10,
+            value: 0xDEAD_BEEF,
+            quality: QDS,
+            timestamp: (),
+        }),
+        Io::M_BO_TA_1(Bitstring32 {
+            ioa: 11,
+            value: 1,
+            quality: QUALITY,
+            timestamp: cp24(),
+        }),
+        Io::M_BO_TB_1(Bitstring32 {
+            ioa: 12,
+            value: 0x8000_0000,
+            quality: Quality::GOOD,
+            timestamp: cp56(),
+        }),
+        Io::M_ME_NA_1(MeasuredNormalized {
+            ioa: 13,
+            value: 0.5,
+            quality: QDS,
+            timestamp: (),
+        }),
+        Io::M_ME_TA_1(MeasuredNormalized {
+            ioa: 14,
+            value: -0.25,
+            quality: QUALITY,
+            timestamp: cp24(),
+        }),
+        Io::M_ME_TD_1(MeasuredNormalized {
+            ioa: 15,
+            value: -1.0,
+            quality: Quality::GOOD,
+            timestamp: cp56(),
+        }),
+        Io::M_ME_NB_1(MeasuredScaled {
+            ioa: 16,
+            value: i16::MIN,
+            quality: QDS,
+            timestamp: (),
+        }),
+        Io::M_ME_TB_1(MeasuredScaled {
+            ioa: 17,
+            value: i16::MAX,
+            quality: QUALITY,
+            timestamp: cp24(),
+        }),
+        Io::M_ME_TE_1(MeasuredScaled {
+            ioa: 18,
+            value: -1,
+            quality: Quality::GOOD,
+            timestamp: cp56(),
+        }),
+        Io::M_ME_NC_1(MeasuredShort {
+            ioa: 19,
+            value: 230.125,
+            quality: QDS,
+            timestamp: (),
+        }),
+        Io::M_ME_TC_1(MeasuredShort {
+            ioa: 20,
+            value: -1.5e9,
+            quality: QUALITY,
+            timestamp: cp24(),
+        }),
+        Io::M_ME_TF_1(MeasuredShort {
+            ioa: 21,
+            value: f32::MIN_POSITIVE,
+            quality: Quality::GOOD,
+            timestamp: cp56(),
+        }),
+        Io::M_ME_ND_1(MeasuredNormalizedWithoutQuality {
+            ioa: 22,
+            value: 0.75,
+        }),
+        Io::M_IT_NA_1(IntegratedTotals {
+            ioa: 23,
+            value: counter(),
+            timestamp: (),
+        }),
+        Io::M_IT_TA_1(IntegratedTotals {
+            ioa: 24,
+            value: CounterReading {
+                value: i32::MAX,
+                sequence_number: 31,
+                carry: false,
+                adjusted: true,
+                invalid: false,
+            },
+            timestamp: cp24(),
+        }),
+        Io::M_IT_TB_1(IntegratedTotals {
+            ioa: 25,
+            value: counter(),
+            timestamp: cp56(),
+        }),
+        Io::M_EP_TA_1(ProtectionEvent {
+            ioa: 26,
+            state: SingleEventState::On,
+            quality: Quality::ELAPSED_TIME_INVALID | Quality::INVALID,
+            elapsed_time: cp16(),
+            timestamp: cp24(),
+        }),
+        Io::M_EP_TD_1(ProtectionEvent {
+            ioa: 27,
+            state: SingleEventState::Off,
+            quality: Quality::GOOD,
+            elapsed_time: cp16(),
+            timestamp: cp56(),
+        }),
+        Io::M_EP_TB_1(PackedStartEvents {
+            ioa: 28,
+            events: StartEvents::GENERAL | StartEvents::EARTH_CURRENT,
+            quality: QUALITY,
+            elapsed_time: cp16(),
+            timestamp: cp24(),
+        }),
+        Io::M_EP_TE_1(PackedStartEvents {
+            ioa: 29,
+            events: StartEvents::all(),
+            quality: Quality::GOOD,
+            elapsed_time: cp16(),
+            timestamp: cp56(),
+        }),
+        Io::M_EP_TC_1(PackedOutputCircuit {
+            ioa: 30,
+            circuits: OutputCircuitInfo::L1 | OutputCircuitInfo::L3,
+            quality: Quality::SUBSTITUTED,
+            operating_time: cp16(),
+            timestamp: cp24(),
+        }),
+        Io::M_EP_TF_1(PackedOutputCircuit {
+            ioa: 31,
+            circuits: OutputCircuitInfo::all(),
+            quality: Quality::GOOD,
+            operating_time: cp16(),
+            timestamp: cp56(),
+        }),
+        Io::M_PS_NA_1(PackedSinglePoints {
+            ioa: 32,
+            status: 0xA5A5,
+            changes: 0x0F0F,
+            quality: QDS,
+        }),
+        Io::M_EI_NA_1(EndOfInitialization { cause: 2 }),
+        Io::C_SC_NA_1(SingleCommand {
+            ioa: 34,
+            state: true,
+            select: true,
+            qualifier: 3,
+            timestamp: (),
+        }),
+        Io::C_SC_TA_1(SingleCommand {
+            ioa: 35,
+            state: false,
+            select: false,
+            qualifier: 31,
+            timestamp: cp56(),
+        }),
+        Io::C_DC_NA_1(DoubleCommand {
+            ioa: 36,
+            state: DoublePointState::On,
+            select: true,
+            qualifier: 1,
+            timestamp: (),
+        }),
+        Io::C_DC_TA_1(DoubleCommand {
+            ioa: 37,
+            state: DoublePointState::Off,
+            select: false,
+            qualifier: 2,
+            timestamp: cp56(),
+        }),
+        Io::C_RC_NA_1(StepCommand {
+            ioa: 38,
+            state: StepCommandState::Higher,
+            select: false,
+            qualifier: 0,
+            timestamp: (),
+        }),
+        Io::C_RC_TA_1(StepCommand {
+            ioa: 39,
+            state: StepCommandState::Lower,
+            select: true,
+            qualifier: 3,
+            timestamp: cp56(),
+        }),
+        Io::C_SE_NA_1(SetpointNormalized {
+            ioa: 40,
+            value: -0.5,
+            select: true,
+            qualifier: 5,
+            timestamp: (),
+        }),
+        Io::C_SE_TA_1(SetpointNormalized {
+            ioa: 41,
+            value: 0.125,
+            select: false,
+            qualifier: 127,
+            timestamp: cp56(),
+        }),
+        Io::C_SE_NB_1(SetpointScaled {
+            ioa: 42,
+            value: -1234,
+            select: true,
+            qualifier: 0,
+            timestamp: (),
+        }),
+        Io::C_SE_TB_1(SetpointScaled {
+            ioa: 43,
+            value: 4321,
+            select: false,
+            qualifier: 1,
+            timestamp: cp56(),
+        }),
+        Io::C_SE_NC_1(SetpointShort {
+            ioa: 44,
+            value: 50.0625,
+            select: false,
+            qualifier: 2,
+            timestamp: (),
+        }),
+        Io::C_SE_TC_1(SetpointShort {
+            ioa: 45,
+            value: -0.001,
+            select: true,
+            qualifier: 3,
+            timestamp: cp56(),
+        }),
+        Io::C_BO_NA_1(Bitstring32Command {
+            ioa: 46,
+            value: 0x1234_5678,
+            timestamp: (),
+        }),
+        Io::C_BO_TA_1(Bitstring32Command {
+            ioa: 47,
+            value: u32::MAX,
+            timestamp: cp56(),
+        }),
+        Io::C_IC_NA_1(Interrogation {
+            ioa: 0,
+            qualifier: 20,
+        }),
+        Io::C_CI_NA_1(CounterInterrogation {
+            ioa: 0,
+            qualifier: 0x45,
+        }),
+        Io::C_RD_NA_1(Read { ioa: 48 }),
+        Io::C_CS_NA_1(ClockSync {
+            ioa: 0,
+            time: cp56(),
+        }),
+        Io::C_TS_NA_1(TestCommand { valid: true }),
+        Io::C_TS_TA_1(TestCommandWithTime {
+            counter: 0xBEEF,
+            timestamp: cp56(),
+        }),
+        Io::C_RP_NA_1(ResetProcess {
+            ioa: 0,
+            qualifier: 1,
+        }),
+        Io::C_CD_NA_1(DelayAcquisition {
+            ioa: 0,
+            delay: cp16(),
+        }),
+        Io::P_ME_NA_1(ParameterNormalized {
+            ioa: 49,
+            value: 0.25,
+            qualifier: 1,
+        }),
+        Io::P_ME_NB_1(ParameterScaled {
+            ioa: 50,
+            value: -300,
+            qualifier: 2,
+        }),
+        Io::P_ME_NC_1(ParameterShort {
+            ioa: 51,
+            value: 12.5,
+            qualifier: 3,
+        }),
+        Io::P_AC_NA_1(ParameterActivation {
+            ioa: 52,
+            qualifier: 3,
+        }),
+        Io::F_FR_NA_1(FileReady {
+            ioa: 53,
+            name: 2,
+            length: 0x01_2345,
+            positive: true,
+        }),
+        Io::F_SR_NA_1(SectionReady {
+            ioa: 54,
+            name: 2,
+            section: 1,
+            length: 1000,
+            not_ready: false,
+        }),
+        Io::F_SC_NA_1(FileCallOrSelect {
+            ioa: 55,
+            name: 2,
+            section: 1,
+            qualifier: 2,
+        }),
+        Io::F_LS_NA_1(FileLastSegmentOrSection {
+            ioa: 56,
+            name: 2,
+            section: 1,
+            qualifier: 1,
+            checksum: 0xAB,
+        }),
+        Io::F_AF_NA_1(FileAck {
+            ioa: 57,
+            name: 2,
+            section: 1,
+            qualifier: 1,
+        }),
+        Io::F_SG_NA_1(FileSegment {
+            ioa: 58,
+            name: 2,
+            section: 1,
+            data: vec![1, 2, 3, 4, 0xFF],
+        }),
+        Io::F_DR_TA_1(FileDirectory {
+            ioa: 59,
+            name: 3,
+            length: 4096,
+            status: 0x20,
+            creation_time: cp56(),
+        }),
+        Io::F_SC_NB_1(QueryLog {
+            ioa: 60,
+            name: 4,
+            start: Cp56Time2a::default(),
+            stop: cp56(),
+        }),
+    ]
+}
+
+#[test]
+fn every_type_round_trips() {
+    let objects = objects();
+    for object in &objects {
+        round_trip(object);
+    }
+
+    let types: HashSet<_> = objects.iter().map(InformationObject::type_id).collect();
+    assert_eq!(types.len(), objects.len());
+    assert_eq!(types.len(), 67);
+}
+
+#[test]
+fn objects_of_one_type_share_an_asdu() {
+    let mut asdu = Asdu::new(
+        &AppLayerParameters::default(),
+        CauseOfTransmission::Periodic,
+        1,
+    );
+    let objects: Vec<_> = (100..105)
+        .map(|ioa| {
+            InformationObject::M_ME_TF_1(MeasuredShort {
+                ioa,
+                value: ioa as f32 / 4.0,
+                quality: Quality::GOOD,
+                timestamp: cp56(),
+            })
+        })
+        .collect();
+    for object in &objects {
+        asdu.add_object(object).unwrap();
+    }
+    let decoded: Vec<_> = asdu.objects().map(Result::unwrap).collect();
+    assert_eq!(decoded, objects);
+}
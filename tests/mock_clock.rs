@@ -0,0 +1,151 @@
+//! Timeouts of lib60870 on a virtual clock.
+#![cfg(all(feature = "mock-clock", not(feature = "no-threads")))]
+
+mod common;
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::time::{Duration, Instant, SystemTime};
+
+use lib60870_sys::cs104::ConnectionEvent;
+use lib60870_sys::hal::MockClock;
+use lib60870_sys::{Cs104Client, Hal_getMonotonicTimeInMs, Hal_getTimeInMs};
+
+use common::TIMEOUT;
+
+/// A client connected to `listener`, which reports its connection events
+/// to the returned channel, and the accepted connection.
+fn connected_client(
+    listener: &TcpListener,
+) -> (Cs104Client, mpsc::Receiver<ConnectionEvent>, TcpStream) {
+    let port = listener.local_addr().unwrap().port();
+    let (events, event_rx) = mpsc::channel();
+    let client = Cs104Client::builder("127.0.0.1")
+        .port(port)
+        .on_connection_event(move |event| {
+            let _ = events.send(event);
+        })
+        .build()
+        .unwrap();
+    client.connect().unwrap();
+    let (connection, _) = listener.accept().unwrap();
+    assert_eq!(event_rx.recv_timeout(TIMEOUT), Ok(ConnectionEvent::Opened));
+    (client, event_rx, connection)
+}
+
+#[test]
+fn hal_time_follows_clock() {
+    let clock = MockClock::install();
+    let (time, monotonic) = unsafe { (Hal_getTimeInMs(), Hal_getMonotonicTimeInMs()) };
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(unsafe { Hal_getTimeInMs() }, time);
+    assert_eq!(unsafe { Hal_getMonotonicTimeInMs() }, monotonic);
+
+    clock.advance(Duration::from_secs(15));
+    assert_eq!(unsafe { Hal_getTimeInMs() }, time + 15_000);
+    assert_eq!(unsafe { Hal_getMonotonicTimeInMs() }, monotonic + 15_000);
+    assert!(clock.now() > SystemTime::now() + Duration::from_secs(14));
+
+    // the monotonic time does not run backwards without the clock
+    drop(clock);
+    assert!(unsafe { Hal_getMonotonicTimeInMs() } >= monotonic + 15_000);
+    let real = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    assert!(unsafe { Hal_getTimeInMs() }.abs_diff(real) < 1000);
+}
+
+#[test]
+fn t1_expires_without_waiting() {
+    // accepts the connection but never confirms STARTDT
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let clock = MockClock::install();
+    let (client, event_rx, _connection) = connected_client(&listener);
+    client.start_dt();
+
+    // the clock stands still, so t1 (15 s) does not expire
+    assert!(event_rx.recv_timeout(Duration::from_millis(300)).is_err());
+
+    clock.advance(client.apci_parameters().t1 + Duration::from_secs(1));
+    assert_eq!(
+        event_rx.recv_timeout(Duration::from_secs(2)),
+        Ok(ConnectionEvent::Closed)
+    );
+}
+
+#[test]
+fn t3_sends_test_frames() {
+    const TESTFR_ACT: [u8; 6] = [0x68, 0x04, 0x43, 0x00, 0x00, 0x00];
+    const TESTFR_CON: [u8; 6] = [0x68, 0x04, 0x83, 0x00, 0x00, 0x00];
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let clock = MockClock::install();
+    let (client, event_rx, mut connection) = connected_client(&listener);
+    let apci = client.apci_parameters();
+
+    // the connection is idle, but t3 (20 s) does not expire
+    connection
+        .set_read_timeout(Some(Duration::from_millis(300)))
+        .unwrap();
+    let mut frame = [0; 6];
+    assert!(connection.read_exact(&mut frame).is_err());
+
+    clock.advance(apci.t3 + Duration::from_secs(1));
+    connection.set_read_timeout(Some(TIMEOUT)).unwrap();
+    connection.read_exact(&mut frame).unwrap();
+    assert_eq!(frame, TESTFR_ACT);
+
+    // a confirmed test frame keeps the connection open past t1
+    connection.write_all(&TESTFR_CON).unwrap();
+    std::thread::sleep(Duration::from_millis(300));
+    clock.advance(apci.t1 + Duration::from_secs(1));
+    assert!(event_rx.recv_timeout(Duration::from_millis(500)).is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn cs101_link_layer_repeats_after_ack_timeout() {
+    use lib60870_sys::cs101::{Cs101Master, LinkLayerMode, LinkLayerState, Parity, SerialPort};
+    use lib60870_sys::parameters::LinkLayerParameters;
+    use serialport::{SerialPort as _, TTYPort};
+
+    // request status of link, from the primary station to address 3
+    const REQUEST_STATUS: [u8; 5] = [0x10, 0x49, 0x03, 0x4C, 0x16];
+
+    // the slave end of the line is read here and never answers
+    let (mut line, terminal) = TTYPort::pair().unwrap();
+    let parameters = LinkLayerParameters::default();
+    let clock = MockClock::install();
+    let mut master = Cs101Master::builder(
+        SerialPort::new(&terminal.name().unwrap(), 9600, Parity::None).unwrap(),
+        LinkLayerMode::Unbalanced,
+    )
+    .link_layer_parameters(parameters)
+    .slave(3)
+    .build()
+    .unwrap();
+    master.start();
+    master.poll(3);
+
+    let mut frame = [0; 5];
+    line.set_timeout(TIMEOUT).unwrap();
+    line.read_exact(&mut frame).unwrap();
+    assert_eq!(frame, REQUEST_STATUS);
+
+    // the clock stands still, so the request is not repeated
+    line.set_timeout(Duration::from_millis(300)).unwrap();
+    assert!(line.read_exact(&mut frame).is_err());
+
+    clock.advance(parameters.timeout_repeat + parameters.timeout_for_ack);
+    line.set_timeout(TIMEOUT).unwrap();
+    line.read_exact(&mut frame).unwrap();
+    assert_eq!(frame, REQUEST_STATUS);
+    let deadline = Instant::now() + TIMEOUT;
+    while master.link_state(3) != Some(LinkLayerState::Error) {
+        assert!(Instant::now() < deadline, "link did not fail");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    master.stop();
+}
@@ -0,0 +1,30 @@
+//! Conversions of the parameters to and from lib60870.
+
+use std::time::Duration;
+
+use lib60870_sys::{sCS104_APCIParameters, ApciParameters};
+
+#[test]
+fn apci_timeouts_round_up_to_seconds() {
+    let parameters = ApciParameters {
+        t0: Duration::from_millis(500),
+        t1: Duration::from_millis(15_001),
+        t2: Duration::from_secs(10),
+        t3: Duration::ZERO,
+        ..ApciParameters::default()
+    };
+    let raw = sCS104_APCIParameters::from(parameters);
+    assert_eq!((raw.k, raw.w), (12, 8));
+    assert_eq!((raw.t0, raw.t1, raw.t2, raw.t3), (1, 16, 10, 0));
+
+    let raw = sCS104_APCIParameters::from(ApciParameters {
+        t3: Duration::MAX,
+        ..ApciParameters::default()
+    });
+    assert_eq!(raw.t3, i32::MAX);
+
+    assert_eq!(
+        ApciParameters::from(&sCS104_APCIParameters::from(ApciParameters::default())),
+        ApciParameters::default()
+    );
+}
@@ -0,0 +1,124 @@
+//! Slave plugins of a CS104 server over loopback.
+#![cfg(not(feature = "no-threads"))]
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+
+use lib60870_sys::cs104::{ConnectionEvent, MasterConnection, SlaveHandler};
+use lib60870_sys::information_object::{InformationObject, SingleCommand};
+use lib60870_sys::plugin::{PluginResult, SlavePlugin};
+use lib60870_sys::{AsduRef, CauseOfTransmission, Error, TypeId};
+
+use common::{free_port, local_client, local_server, TIMEOUT};
+
+/// Answers commands for IOA 1 and leaves the others to the handler.
+#[derive(Default)]
+struct Switch {
+    commands: Arc<AtomicUsize>,
+    tasks: Arc<AtomicUsize>,
+}
+
+impl SlavePlugin for Switch {
+    fn handle_asdu(&mut self, connection: &MasterConnection, asdu: &AsduRef) -> PluginResult {
+        if asdu.type_id() != Some(TypeId::C_SC_NA_1) || asdu.element(0).unwrap().address() != 1 {
+            return PluginResult::NotHandled;
+        }
+        self.commands.fetch_add(1, Ordering::SeqCst);
+        connection
+            .send_act_con(&mut asdu.to_owned(), false)
+            .unwrap();
+        PluginResult::Handled
+    }
+
+    fn run_task(&mut self, _connection: &MasterConnection) {
+        self.tasks.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[derive(Default)]
+struct Rtu {
+    commands: AtomicUsize,
+}
+
+impl SlaveHandler for Rtu {
+    fn asdu(&self, connection: &MasterConnection, asdu: &AsduRef) -> bool {
+        self.commands.fetch_add(1, Ordering::SeqCst);
+        connection.send_act_con(&mut asdu.to_owned(), true).unwrap();
+        true
+    }
+}
+
+fn switch(ioa: u32) -> InformationObject {
+    InformationObject::C_SC_NA_1(SingleCommand {
+        ioa,
+        state: true,
+        select: false,
+        qualifier: 0,
+        timestamp: (),
+    })
+}
+
+#[test]
+fn plugin_sees_asdus_before_handler() {
+    let port = free_port();
+    let mut server = local_server(port).build(Rtu::default()).unwrap();
+    let plugin = Switch::default();
+    let commands = Arc::clone(&plugin.commands);
+    let tasks = Arc::clone(&plugin.tasks);
+    server.add_plugin(plugin).unwrap();
+    server.start();
+
+    let (events, event_rx) = mpsc::channel();
+    let (asdus, asdu_rx) = mpsc::channel();
+    let client = local_client(port)
+        .on_connection_event(move |event| {
+            let _ = events.send(event);
+        })
+        .on_asdu(move |asdu| {
+            let _ = asdus.send(asdu.to_owned());
+        })
+        .build()
+        .unwrap();
+    client.connect().unwrap();
+    client.start_dt();
+    assert_eq!(
+        event_rx.recv_timeout(TIMEOUT),
+        Ok(ConnectionEvent::StartDtConfirmed)
+    );
+
+    client
+        .send_command(CauseOfTransmission::Activation, 1, &switch(1))
+        .unwrap();
+    let con = asdu_rx.recv_timeout(TIMEOUT).unwrap();
+    assert!(!con.is_negative());
+    assert_eq!(commands.load(Ordering::SeqCst), 1);
+    assert_eq!(server.handler().commands.load(Ordering::SeqCst), 0);
+
+    client
+        .send_command(CauseOfTransmission::Activation, 1, &switch(2))
+        .unwrap();
+    let con = asdu_rx.recv_timeout(TIMEOUT).unwrap();
+    assert!(con.is_negative());
+    assert_eq!(commands.load(Ordering::SeqCst), 1);
+    assert_eq!(server.handler().commands.load(Ordering::SeqCst), 1);
+
+    assert!(tasks.load(Ordering::SeqCst) > 0);
+
+    client.close();
+    server.stop();
+}
+
+#[test]
+fn plugins_are_added_before_start() {
+    let port = free_port();
+    let mut server = local_server(port).build(()).unwrap();
+    server.start();
+    assert_eq!(
+        server.add_plugin(Switch::default()),
+        Err(Error::AlreadyStarted)
+    );
+    server.stop();
+    server.add_plugin(Switch::default()).unwrap();
+}
@@ -0,0 +1,68 @@
+//! Bit positions of the quality and protection equipment flags.
+
+use lib60870_sys::quality::OutputCircuitInfo;
+use lib60870_sys::{Quality, StartEvents};
+
+#[test]
+fn quality_bits() {
+    assert_eq!(Quality::OVERFLOW.bits(), 0x01);
+    assert_eq!(Quality::ELAPSED_TIME_INVALID.bits(), 0x08);
+    assert_eq!(Quality::BLOCKED.bits(), 0x10);
+    assert_eq!(Quality::SUBSTITUTED.bits(), 0x20);
+    assert_eq!(Quality::NON_TOPICAL.bits(), 0x40);
+    assert_eq!(Quality::INVALID.bits(), 0x80);
+    assert_eq!(Quality::all().bits(), 0xF9);
+}
+
+#[test]
+fn quality_ignores_reserved_bits() {
+    assert!(Quality::GOOD.is_good());
+    assert_eq!(Quality::GOOD.to_string(), "GOOD");
+
+    let reserved = Quality::from_bits_retain(0x06);
+    assert!(reserved.is_good());
+    assert_eq!(reserved.to_string(), "GOOD");
+    assert_eq!(reserved.bits(), 0x06);
+
+    let quality = Quality::from_bits_retain(0x86);
+    assert!(!quality.is_good());
+    assert_eq!(quality.to_string(), "INVALID");
+    assert_eq!(
+        (Quality::BLOCKED | Quality::INVALID).to_string(),
+        "BLOCKED | INVALID"
+    );
+}
+
+#[test]
+fn start_event_bits() {
+    assert_eq!(StartEvents::GENERAL.bits(), 0x01);
+    assert_eq!(StartEvents::L1.bits(), 0x02);
+    assert_eq!(StartEvents::L2.bits(), 0x04);
+    assert_eq!(StartEvents::L3.bits(), 0x08);
+    assert_eq!(StartEvents::EARTH_CURRENT.bits(), 0x10);
+    assert_eq!(StartEvents::REVERSE_DIRECTION.bits(), 0x20);
+    assert_eq!(StartEvents::all().bits(), 0x3F);
+
+    assert_eq!(StartEvents::empty().to_string(), "NONE");
+    assert_eq!(StartEvents::from_bits_retain(0xC0).to_string(), "NONE");
+    assert_eq!(
+        (StartEvents::GENERAL | StartEvents::L2).to_string(),
+        "GENERAL | L2"
+    );
+}
+
+#[test]
+fn output_circuit_bits() {
+    assert_eq!(OutputCircuitInfo::GENERAL.bits(), 0x01);
+    assert_eq!(OutputCircuitInfo::L1.bits(), 0x02);
+    assert_eq!(OutputCircuitInfo::L2.bits(), 0x04);
+    assert_eq!(OutputCircuitInfo::L3.bits(), 0x08);
+    assert_eq!(OutputCircuitInfo::all().bits(), 0x0F);
+
+    assert_eq!(OutputCircuitInfo::empty().to_string(), "NONE");
+    assert_eq!(
+        OutputCircuitInfo::from_bits_retain(0xF0).to_string(),
+        "NONE"
+    );
+    assert_eq!(OutputCircuitInfo::L3.to_string(), "L3");
+}
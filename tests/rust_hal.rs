@@ -0,0 +1,137 @@
+//! The Rust HAL through the C declarations lib60870 links against.
+#![cfg(feature = "rust-hal")]
+
+mod common;
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_void;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use lib60870_sys::*;
+
+use common::free_port;
+
+fn peer_address(socket: Socket) -> String {
+    let mut buf = [0; 60];
+    unsafe {
+        Socket_getPeerAddressStatic(socket, buf.as_mut_ptr());
+        CStr::from_ptr(buf.as_ptr()).to_str().unwrap().to_owned()
+    }
+}
+
+#[test]
+fn sockets_connect_and_exchange_data() {
+    let port = free_port();
+    let localhost = CString::new("127.0.0.1").unwrap();
+    unsafe {
+        let server = TcpServerSocket_create(localhost.as_ptr(), port.into());
+        assert!(!server.is_null());
+        ServerSocket_listen(server);
+        assert!(ServerSocket_accept(server).is_null());
+
+        let client = TcpSocket_create();
+        Socket_setConnectTimeout(client, 1000);
+        assert!(Socket_connectAsync(client, localhost.as_ptr(), port.into()));
+
+        // the listening socket becomes ready with the pending connection
+        let handleset = Handleset_new();
+        Handleset_addSocket(handleset, server.cast());
+        assert_eq!(Handleset_waitReady(handleset, 1000), 1);
+        let connection = ServerSocket_accept(server);
+        assert!(!connection.is_null());
+
+        let mut state = Socket_checkAsyncConnectState(client);
+        while state == SocketState_SOCKET_STATE_CONNECTING {
+            Thread_sleep(1);
+            state = Socket_checkAsyncConnectState(client);
+        }
+        assert_eq!(state, SocketState_SOCKET_STATE_CONNECTED);
+        assert_eq!(peer_address(client), format!("127.0.0.1:{}", port));
+
+        Handleset_reset(handleset);
+        Handleset_addSocket(handleset, connection);
+        assert_eq!(Handleset_waitReady(handleset, 10), 0);
+        let mut buf = [0u8; 16];
+        assert_eq!(Socket_read(connection, buf.as_mut_ptr(), 16), 0);
+
+        let mut message = *b"hello";
+        assert_eq!(Socket_write(client, message.as_mut_ptr(), 5), 5);
+        assert_eq!(Handleset_waitReady(handleset, 1000), 1);
+        // still ready until the data has been read
+        assert_eq!(Handleset_waitReady(handleset, 0), 1);
+        assert_eq!(Socket_read(connection, buf.as_mut_ptr(), 16), 5);
+        assert_eq!(&buf[..5], b"hello");
+
+        // a closed connection is ready and then fails to read
+        Socket_destroy(client);
+        assert_eq!(Handleset_waitReady(handleset, 1000), 1);
+        assert_eq!(Socket_read(connection, buf.as_mut_ptr(), 16), -1);
+
+        Handleset_destroy(handleset);
+        Socket_destroy(connection);
+        ServerSocket_destroy(server);
+    }
+}
+
+#[test]
+fn connecting_to_closed_port_fails() {
+    let port = free_port();
+    let localhost = CString::new("127.0.0.1").unwrap();
+    unsafe {
+        let client = TcpSocket_create();
+        Socket_setConnectTimeout(client, 1000);
+        assert!(!Socket_connect(client, localhost.as_ptr(), port.into()));
+        assert_eq!(
+            Socket_checkAsyncConnectState(client),
+            SocketState_SOCKET_STATE_FAILED
+        );
+        Socket_destroy(client);
+    }
+}
+
+unsafe extern "C" fn count(parameter: *mut c_void) -> *mut c_void {
+    let counter = &*parameter.cast::<AtomicUsize>();
+    counter.fetch_add(1, Ordering::SeqCst);
+    ptr::null_mut()
+}
+
+unsafe extern "C" fn post(parameter: *mut c_void) -> *mut c_void {
+    Semaphore_post(parameter);
+    ptr::null_mut()
+}
+
+#[test]
+fn threads_run_and_are_joined() {
+    let counter = AtomicUsize::new(0);
+    unsafe {
+        let thread = Thread_create(Some(count), &counter as *const _ as *mut c_void, false);
+        Thread_start(thread);
+        Thread_destroy(thread);
+    }
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn semaphores_wait_for_autodestroy_threads() {
+    unsafe {
+        let semaphore = Semaphore_create(0);
+        Thread_start(Thread_create(Some(post), semaphore, true));
+        Semaphore_wait(semaphore);
+        Semaphore_destroy(semaphore);
+    }
+}
+
+#[test]
+fn time_is_since_epoch() {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    unsafe {
+        assert!(Hal_getTimeInMs().abs_diff(now) < 1000);
+        let monotonic = Hal_getMonotonicTimeInMs();
+        Thread_sleep(5);
+        assert!(Hal_getMonotonicTimeInMs() >= monotonic + 5);
+    }
+}
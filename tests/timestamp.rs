@@ -0,0 +1,153 @@
+//! Conversions between CP time tags and std/chrono/time types.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use lib60870_sys::timestamp::{Cp16Time2a, Cp24Time2a, Cp56Time2a, TimeConfig};
+use lib60870_sys::Error;
+
+// 2024-03-10T12:34:56.789Z, a Sunday
+const SUNDAY_MS: u64 = 1_710_074_096_789;
+// 1999-12-31T23:59:59.999Z, a Friday
+const MILLENNIUM_EVE_MS: u64 = 946_684_799_999;
+
+fn at(ms: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(ms)
+}
+
+#[test]
+fn utc_round_trip() {
+    let time = Cp56Time2a::try_from(at(SUNDAY_MS)).unwrap();
+    assert_eq!(time.day_of_week(), 7);
+    assert!(!time.is_summer_time());
+    assert_eq!(SystemTime::try_from(time).unwrap(), at(SUNDAY_MS));
+}
+
+#[test]
+fn local_time_uses_offset_and_summer_time_bit() {
+    let cet = TimeConfig::local(3600, false);
+    let cest = TimeConfig::local(3600, true);
+
+    let winter = Cp56Time2a::from_system_time(at(SUNDAY_MS), &cet).unwrap();
+    assert!(!winter.is_summer_time());
+    let summer = Cp56Time2a::from_system_time(at(SUNDAY_MS), &cest).unwrap();
+    assert!(summer.is_summer_time());
+
+    // the fields differ by the offset, one hour more in summer time
+    let as_utc = |t: Cp56Time2a| t.to_system_time(&TimeConfig::UTC).unwrap();
+    assert_eq!(
+        as_utc(winter).duration_since(at(SUNDAY_MS)).unwrap(),
+        Duration::from_secs(3600)
+    );
+    assert_eq!(
+        as_utc(summer).duration_since(at(SUNDAY_MS)).unwrap(),
+        Duration::from_secs(7200)
+    );
+
+    // decoding honours the SU bit regardless of the encoding setting
+    assert_eq!(winter.to_system_time(&cest).unwrap(), at(SUNDAY_MS));
+    assert_eq!(summer.to_system_time(&cet).unwrap(), at(SUNDAY_MS));
+}
+
+#[test]
+fn century_window() {
+    // lib60870 only covers 2000 to 2099
+    assert_eq!(
+        Cp56Time2a::try_from(at(MILLENNIUM_EVE_MS)),
+        Err(Error::TimestampOutOfRange)
+    );
+
+    let window = TimeConfig::UTC.with_century_start(1950);
+    let time = Cp56Time2a::from_system_time(at(MILLENNIUM_EVE_MS), &window).unwrap();
+    assert_eq!(time.day_of_week(), 5);
+    assert_eq!(time.to_system_time(&window).unwrap(), at(MILLENNIUM_EVE_MS));
+
+    // the same fields read with the default window are a century later
+    let later = time.to_system_time(&TimeConfig::UTC).unwrap();
+    assert_eq!(
+        later.duration_since(at(MILLENNIUM_EVE_MS)).unwrap(),
+        Duration::from_secs(36_525 * 86_400)
+    );
+}
+
+#[test]
+fn invalid_and_substituted_flags() {
+    let mut time = Cp56Time2a::try_from(at(SUNDAY_MS)).unwrap();
+    time.set_substituted(true);
+    assert!(time.is_substituted());
+    assert_eq!(SystemTime::try_from(time).unwrap(), at(SUNDAY_MS));
+
+    time.set_invalid(true);
+    assert!(time.is_invalid());
+    assert_eq!(SystemTime::try_from(time), Err(Error::InvalidTimestamp));
+}
+
+#[test]
+fn malformed_fields_are_rejected() {
+    // month 13
+    let time = Cp56Time2a::from_bytes([0, 0, 0, 0, 1, 13, 24]);
+    assert_eq!(SystemTime::try_from(time), Err(Error::InvalidTimestamp));
+    // 2023-02-29
+    let time = Cp56Time2a::from_bytes([0, 0, 0, 0, 29, 2, 23]);
+    assert_eq!(SystemTime::try_from(time), Err(Error::InvalidTimestamp));
+}
+
+#[test]
+fn cp24_resolves_nearest_hour() {
+    // 2024-03-10T12:00:00Z
+    const NOON_MS: u64 = 1_710_072_000_000;
+    const MINUTE_MS: u64 = 60_000;
+    let config = TimeConfig::UTC;
+
+    let time = Cp24Time2a::from_system_time(at(SUNDAY_MS), &config);
+    assert_eq!(
+        time.to_system_time(at(SUNDAY_MS), &config).unwrap(),
+        at(SUNDAY_MS)
+    );
+
+    // xx:58 received at 13:02 is 12:58
+    let time = Cp24Time2a::from_system_time(at(NOON_MS + 58 * MINUTE_MS), &config);
+    assert_eq!(
+        time.to_system_time(at(NOON_MS + 62 * MINUTE_MS), &config)
+            .unwrap(),
+        at(NOON_MS + 58 * MINUTE_MS)
+    );
+
+    // xx:02 received at 11:58 is 12:02
+    let time = Cp24Time2a::from_system_time(at(NOON_MS + 2 * MINUTE_MS), &config);
+    assert_eq!(
+        time.to_system_time(at(NOON_MS - 2 * MINUTE_MS), &config)
+            .unwrap(),
+        at(NOON_MS + 2 * MINUTE_MS)
+    );
+}
+
+#[test]
+fn cp16_durations() {
+    let elapsed = Cp16Time2a::try_from(Duration::from_millis(12_345)).unwrap();
+    assert_eq!(Duration::from(elapsed), Duration::from_millis(12_345));
+    assert_eq!(
+        Cp16Time2a::try_from(Duration::from_secs(60)),
+        Err(Error::TimestampOutOfRange)
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_round_trip() {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    let date = Utc.timestamp_millis_opt(SUNDAY_MS as i64).unwrap();
+    let time = Cp56Time2a::try_from(date).unwrap();
+    assert_eq!(DateTime::<Utc>::try_from(time).unwrap(), date);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_round_trip() {
+    use time::OffsetDateTime;
+
+    let date =
+        OffsetDateTime::from_unix_timestamp_nanos(i128::from(SUNDAY_MS) * 1_000_000).unwrap();
+    let time = Cp56Time2a::try_from(date).unwrap();
+    assert_eq!(OffsetDateTime::try_from(time).unwrap(), date);
+}
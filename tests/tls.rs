@@ -0,0 +1,72 @@
+//! Loading TLS configurations.
+#![cfg(feature = "tls")]
+
+use lib60870_sys::tls::{TlsConfig, TlsError, TlsVersion};
+use lib60870_sys::{Cs104Client, Cs104Server, Error};
+
+#[test]
+fn empty_config_builds() {
+    TlsConfig::builder()
+        .chain_validation(false)
+        .time_validation(false)
+        .min_version(TlsVersion::Tls1_2)
+        .max_version(TlsVersion::Tls1_3)
+        .build()
+        .unwrap();
+}
+
+#[test]
+fn failing_step_is_reported() {
+    let garbage = b"-----BEGIN CERTIFICATE-----\nnot base64\n-----END CERTIFICATE-----\n";
+
+    let error = TlsConfig::builder()
+        .own_certificate(garbage)
+        .build()
+        .unwrap_err();
+    assert_eq!(error, Error::Tls(TlsError::OwnCertificate));
+
+    let error = TlsConfig::builder()
+        .ca_certificate(&[0x30, 0x03, 0x02, 0x01, 0x00])
+        .build()
+        .unwrap_err();
+    assert_eq!(error, Error::Tls(TlsError::CaCertificate));
+
+    let error = TlsConfig::builder()
+        .crl_file("/nonexistent/crl.pem")
+        .build()
+        .unwrap_err();
+    assert_eq!(error, Error::Tls(TlsError::Crl));
+
+    let error = TlsConfig::builder()
+        .own_key_file("key\0.pem", None)
+        .build()
+        .unwrap_err();
+    assert_eq!(error, Error::Tls(TlsError::InvalidPath));
+}
+
+#[test]
+fn config_serves_one_side() {
+    let config = TlsConfig::builder().build().unwrap();
+    let _server = Cs104Server::builder()
+        .tls(config.clone())
+        .build(())
+        .unwrap();
+    // another server may share it, a client may not
+    let _other = Cs104Server::builder()
+        .tls(config.clone())
+        .build(())
+        .unwrap();
+    let error = Cs104Client::builder("127.0.0.1")
+        .tls(config)
+        .build()
+        .unwrap_err();
+    assert_eq!(error, Error::TlsRoleMismatch);
+
+    let config = TlsConfig::builder().build().unwrap();
+    let _client = Cs104Client::builder("127.0.0.1")
+        .tls(config.clone())
+        .build()
+        .unwrap();
+    let error = Cs104Server::builder().tls(config).build(()).err();
+    assert_eq!(error, Some(Error::TlsRoleMismatch));
+}
@@ -0,0 +1,93 @@
+//! TLS security events of CS104 connections over loopback.
+#![cfg(all(feature = "tls", not(feature = "no-threads")))]
+
+mod common;
+
+use std::sync::mpsc;
+
+use lib60870_sys::tls::{TlsConfig, TlsEvent, TlsEventCode, TlsEventLevel, TlsVersion};
+
+use common::{free_port, local_client, local_server, TIMEOUT};
+
+#[test]
+fn failed_handshake_is_reported() {
+    // the server has no certificate, so no handshake can succeed
+    let (events, event_rx) = mpsc::channel();
+    let server_config = TlsConfig::builder().event_sender(events).build().unwrap();
+    let port = free_port();
+    let server = local_server(port).tls(server_config).build(()).unwrap();
+    server.start();
+
+    let client_config = TlsConfig::builder()
+        .chain_validation(false)
+        .build()
+        .unwrap();
+    let client = local_client(port).tls(client_config).build().unwrap();
+    let _ = client.connect();
+
+    let event = event_rx.recv_timeout(TIMEOUT).unwrap();
+    assert_eq!(event.level, TlsEventLevel::Incident);
+    assert!(event.peer_address.unwrap().starts_with("127.0.0.1"));
+    assert_eq!(event.peer_certificate, None);
+}
+
+/// Waits for the `SessionEstablished` event of a configuration.
+fn session_established(events: &mpsc::Receiver<TlsEvent>) -> TlsEvent {
+    loop {
+        let event = events.recv_timeout(TIMEOUT).unwrap();
+        if event.code == TlsEventCode::SessionEstablished {
+            return event;
+        }
+    }
+}
+
+#[test]
+fn established_session_is_reported_with_peer_certificate() {
+    // both sides present a certificate signed by the same CA
+    let ca = include_bytes!("certs/ca.pem");
+    let (server_events, server_event_rx) = mpsc::channel();
+    let server_config = TlsConfig::builder()
+        .own_certificate(include_bytes!("certs/server.pem"))
+        .own_key(include_bytes!("certs/server.key"), None)
+        .ca_certificate(ca)
+        .min_version(TlsVersion::Tls1_2)
+        .max_version(TlsVersion::Tls1_2)
+        .event_sender(server_events)
+        .build()
+        .unwrap();
+    let port = free_port();
+    let server = local_server(port).tls(server_config).build(()).unwrap();
+    server.start();
+
+    let (client_events, client_event_rx) = mpsc::channel();
+    let client_config = TlsConfig::builder()
+        .own_certificate(include_bytes!("certs/client.pem"))
+        .own_key(include_bytes!("certs/client.key"), None)
+        .ca_certificate(ca)
+        .min_version(TlsVersion::Tls1_2)
+        .max_version(TlsVersion::Tls1_2)
+        .event_sender(client_events)
+        .build()
+        .unwrap();
+    let client = local_client(port).tls(client_config).build().unwrap();
+    client.connect().unwrap();
+
+    let event = session_established(&server_event_rx);
+    assert_eq!(event.level, TlsEventLevel::Info);
+    assert!(event.peer_address.unwrap().starts_with("127.0.0.1"));
+    assert_eq!(
+        event.peer_certificate.as_deref(),
+        Some(&include_bytes!("certs/client.der")[..])
+    );
+    assert_eq!(event.version, Some(TlsVersion::Tls1_2));
+
+    let event = session_established(&client_event_rx);
+    assert_eq!(event.level, TlsEventLevel::Info);
+    assert_eq!(
+        event.peer_certificate.as_deref(),
+        Some(&include_bytes!("certs/server.der")[..])
+    );
+    assert_eq!(event.version, Some(TlsVersion::Tls1_2));
+
+    client.close();
+}
@@ -0,0 +1,95 @@
+//! Conversions of type identifications and causes of transmission.
+
+use lib60870_sys::types::{UnknownCauseOfTransmission, UnknownTypeId};
+use lib60870_sys::{CauseOfTransmission, TypeId};
+
+#[test]
+fn type_ids_round_trip_every_octet() {
+    let mut known = 0;
+    for value in 0..=u8::MAX {
+        match TypeId::try_from(value) {
+            Ok(type_id) => {
+                known += 1;
+                assert_eq!(u8::from(type_id), value);
+                assert_eq!(type_id.mnemonic(), format!("{:?}", type_id));
+                assert_eq!(type_id.to_string(), type_id.mnemonic());
+            }
+            Err(error) => {
+                assert_eq!(error, UnknownTypeId(value));
+                assert_eq!(error.to_string(), format!("unknown type id {}", value));
+            }
+        }
+    }
+    assert_eq!(known, 81);
+}
+
+#[test]
+fn type_ids_have_standard_values() {
+    assert_eq!(TypeId::try_from(1), Ok(TypeId::M_SP_NA_1));
+    assert_eq!(TypeId::try_from(36), Ok(TypeId::M_ME_TF_1));
+    assert_eq!(TypeId::try_from(45), Ok(TypeId::C_SC_NA_1));
+    assert_eq!(TypeId::try_from(70), Ok(TypeId::M_EI_NA_1));
+    assert_eq!(TypeId::try_from(100), Ok(TypeId::C_IC_NA_1));
+    assert_eq!(TypeId::try_from(107), Ok(TypeId::C_TS_TA_1));
+    assert_eq!(TypeId::try_from(127), Ok(TypeId::F_SC_NB_1));
+
+    // reserved ranges of the standard
+    for value in [0, 22, 29, 42, 44, 52, 57, 65, 69, 108, 128, 255] {
+        assert_eq!(TypeId::try_from(value), Err(UnknownTypeId(value)));
+    }
+}
+
+#[test]
+fn type_ids_fall_into_one_category() {
+    for type_id in (0..=u8::MAX).filter_map(|value| TypeId::try_from(value).ok()) {
+        let categories = [
+            type_id.is_monitor(),
+            type_id.is_command(),
+            type_id.is_parameter(),
+            type_id.is_file_transfer(),
+            type_id.is_security(),
+        ];
+        assert_eq!(
+            categories.iter().filter(|&&is| is).count(),
+            1,
+            "{}",
+            type_id
+        );
+    }
+
+    assert!(TypeId::M_SP_TB_1.has_timestamp());
+    assert!(TypeId::C_SC_TA_1.has_timestamp());
+    assert!(TypeId::F_DR_TA_1.has_timestamp());
+    assert!(!TypeId::M_SP_NA_1.has_timestamp());
+    assert!(!TypeId::C_IC_NA_1.has_timestamp());
+}
+
+#[test]
+fn causes_round_trip_every_octet() {
+    let mut known = 0;
+    for value in 0..=u8::MAX {
+        match CauseOfTransmission::try_from(value) {
+            Ok(cot) => {
+                known += 1;
+                assert_eq!(u8::from(cot), value);
+                assert!(matches!(value, 1..=16 | 20..=41 | 44..=47), "{}", value);
+            }
+            Err(error) => {
+                assert_eq!(error, UnknownCauseOfTransmission(value));
+                assert!(!matches!(value, 1..=16 | 20..=41 | 44..=47), "{}", value);
+            }
+        }
+    }
+    assert_eq!(known, 42);
+}
+
+#[test]
+fn cause_predicates_cover_their_ranges() {
+    for cot in (0..=u8::MAX).filter_map(|value| CauseOfTransmission::try_from(value).ok()) {
+        let value = u8::from(cot);
+        assert_eq!(cot.is_interrogation(), (20..=36).contains(&value));
+        assert_eq!(cot.is_counter_interrogation(), (37..=41).contains(&value));
+        assert_eq!(cot.is_error(), (44..=47).contains(&value));
+    }
+    assert_eq!(CauseOfTransmission::Spontaneous.to_string(), "SPONTANEOUS");
+}